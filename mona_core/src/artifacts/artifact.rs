@@ -1,4 +1,3 @@
-use std::collections::HashMap;
 
 use mona_derive::{ArtifactData, EnumLen};
 use num_derive::FromPrimitive;
@@ -8,7 +7,12 @@ use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter};
-use crate::artifacts::eff::ARTIFACT_EFF5;
+use smallvec::SmallVec;
+use crate::artifacts::eff::get_artifact_eff;
+use crate::artifacts::artifact_generator::{artifact_max_level, ArtifactGenerator, ArtifactSource};
+use crate::artifacts::artifact_stat_vector::ArtifactStatVector;
+use crate::artifacts::main_stat::artifact_main_stat_value;
+use crate::artifacts::roll_analysis::ArtifactRollAnalysis;
 
 use crate::artifacts::effect::ArtifactEffect;
 use crate::attribute::Attribute;
@@ -82,9 +86,12 @@ pub enum ArtifactSetName {
 
 impl ArtifactSetName {
     pub fn random() -> ArtifactSetName {
-        let max = ArtifactSetName::WanderersTroupe as usize;
-        let mut rng = thread_rng();
-        let n: usize = rng.gen::<usize>() % max;
+        ArtifactSetName::random_with_rng(&mut thread_rng())
+    }
+
+    // any set except `Empty`
+    pub fn random_with_rng<R: Rng + ?Sized>(rng: &mut R) -> ArtifactSetName {
+        let n = rng.gen_range(1..ArtifactSetName::LEN);
         num::FromPrimitive::from_usize(n).unwrap()
     }
 }
@@ -153,58 +160,94 @@ impl Artifact {
     }
 
     pub fn is_max_level(&self) -> bool {
-        (self.star == 5 && self.level == 20)
-        || (self.star == 4 && self.level == 16)
-        || (self.star <= 3 && self.level == 12)
+        self.level >= artifact_max_level(self.star)
     }
 
-    pub fn random0(set_name: ArtifactSetName, slot: ArtifactSlotName, main_stat: StatName) -> Artifact {
-        let mut temp = Artifact {
-            set_name,
-            slot,
-            level: 0,
-            star: 5,
-            sub_stats: vec![],
-            main_stat: (main_stat, StatName::artifact_main_stat_max_value(main_stat)),
-            id: thread_rng().gen()
-        };
+    /// main stat value of this artifact at `level`, grown from the current value so imported values keep their precision,
+    /// `None` if the main stat can not be a main stat (flat def)
+    pub fn main_stat_value_at(&self, level: i32) -> Option<f64> {
+        let (stat, value) = self.main_stat;
+        Some(value + artifact_main_stat_value(stat, self.star, level)? - artifact_main_stat_value(stat, self.star, self.level)?)
+    }
+
+    pub fn has_valid_main_stat(&self) -> bool {
+        artifact_main_stat_value(self.main_stat.0, self.star, 0).is_some()
+    }
 
-        let flag = thread_rng().gen::<usize>() % 3;
-        let count = if flag == 0 { 4 } else { 3 };
+    /// sets the level and grows the main stat accordingly, sub stats are left unchanged,
+    /// returns false and keeps the main stat value if the main stat can not be a main stat
+    pub fn project_main_stat(&mut self, level: i32) -> bool {
+        let value = self.main_stat_value_at(level);
+        self.level = level;
+        match value {
+            Some(x) => {
+                self.main_stat.1 = x;
+                true
+            },
+            None => false
+        }
+    }
 
-        for _ in 0..count {
-            temp.upgrade();
+    /// a copy at the max level with the grown main stat, sub stats are left unchanged,
+    /// `None` if the main stat can not be a main stat
+    pub fn projected_to_max(&self) -> Option<Artifact> {
+        let mut result = self.clone();
+        if result.project_main_stat(artifact_max_level(self.star)) {
+            Some(result)
+        } else {
+            None
         }
-        temp.level = 0;
+    }
+
+    pub fn analyze_rolls(&self) -> ArtifactRollAnalysis {
+        ArtifactRollAnalysis::new(self)
+    }
 
-        temp
+    /// a copy with impossible sub stat values replaced by the closest possible ones, `None` if it can not be fixed
+    pub fn fix_rolls(&self) -> Option<Artifact> {
+        ArtifactRollAnalysis::fix(self)
+    }
+
+    /// a level 0 five star artifact, dropped from a domain, `None` if `main_stat` is not a main stat of `slot`
+    pub fn random0(set_name: ArtifactSetName, slot: ArtifactSlotName, main_stat: StatName) -> Option<Artifact> {
+        ArtifactGenerator::from_thread_rng().generate_with_main_stat(set_name, slot, main_stat, 5, ArtifactSource::Domain)
     }
 
     pub fn upgrade(&mut self) {
+        self.upgrade_with_rng(&mut thread_rng());
+    }
+
+    pub fn upgrade_with_rng<R: Rng + ?Sized>(&mut self, rng: &mut R) {
         if self.is_max_level() {
             return;
         }
 
-        self.level = (self.level / 4 + 1) * 4;
+        // an invalid main stat keeps its value, the level still rises so upgrade loops end
+        self.project_main_stat((self.level / 4 + 1) * 4);
 
         if self.sub_stats.len() == 4 {
-            let index = thread_rng().gen::<usize>() % 4;
+            let index = rng.gen_range(0..4);
             let stat_name = self.sub_stats[index].0;
-            let add_value = ARTIFACT_EFF5.get_value(stat_name, thread_rng().gen::<usize>() % 4);
+            let eff = get_artifact_eff(self.star);
+            let add_value = eff.get_value(stat_name, rng.gen_range(0..eff.tier_count));
             self.sub_stats[index].1 += add_value;
         } else {
-            let next_stat_name_dist = self.get_next_stat_name_dist().unwrap();
-            let w = WeightedIndex::new(next_stat_name_dist.iter().map(|x| x.1)).unwrap();
-            let index = w.sample(&mut thread_rng());
-            let selected_main_stat = next_stat_name_dist[index].0;
-
-            // random value;
-            let value_index = thread_rng().gen::<usize>() % 4;
-            let value = ARTIFACT_EFF5.get_value(selected_main_stat, value_index);
-            self.sub_stats.push((selected_main_stat, value));
+            self.add_random_sub_stat(rng);
         }
     }
 
+    pub fn add_random_sub_stat<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let next_stat_name_dist = self.get_next_stat_name_dist().unwrap();
+        let w = WeightedIndex::new(next_stat_name_dist.iter().map(|x| x.1)).unwrap();
+        let index = w.sample(rng);
+        let selected_stat = next_stat_name_dist[index].0;
+
+        // random value;
+        let eff = get_artifact_eff(self.star);
+        let value = eff.get_value(selected_stat, rng.gen_range(0..eff.tier_count));
+        self.sub_stats.push((selected_stat, value));
+    }
+
     // return statname, and the probability of that stat
     pub fn get_next_stat_name_dist(&self) -> Option<Vec<(StatName, f64)>> {
         if self.sub_stats.len() == 4 {
@@ -242,6 +285,8 @@ impl Artifact {
     }
 }
 
+pub type ArtifactSetCounts = SmallVec<[(ArtifactSetName, i32); 5]>;
+
 pub struct ArtifactList<'a> {
     pub artifacts: &'a [&'a Artifact]
     // pub artifacts: &'a SmallVec<[&'b Artifact; 5]>
@@ -249,32 +294,45 @@ pub struct ArtifactList<'a> {
 
 impl<'a> ArtifactList<'a> {
     pub fn apply<T: Attribute>(&self, attribute: &mut T, character: &Character<T>, config: &ArtifactEffectConfig) {
-        let mut attributes_hash: HashMap<StatName, f64> = HashMap::new();
-        let mut set_name_count: HashMap<ArtifactSetName, i32> = HashMap::new();
+        // calculate stats
+        let key = "圣遗物词条";
+        ArtifactStatVector::from_artifacts(self.artifacts).apply(attribute, key);
 
-        for &artifact in self.artifacts.iter() {
-            *set_name_count.entry(artifact.set_name).or_insert(0) += 1;
+        // calculate set effect
+        ArtifactList::apply_set_effects(&self.set_counts(), attribute, character, config);
+    }
 
-            *attributes_hash.entry(artifact.main_stat.0).or_insert(0.0) += artifact.main_stat.1;
-            for sub_stat in artifact.sub_stats.iter() {
-                *attributes_hash.entry(sub_stat.0).or_insert(0.0) += sub_stat.1;
+    /// count of each set, sorted by set name so equal combinations compare equal
+    pub fn set_counts(&self) -> ArtifactSetCounts {
+        let mut result: ArtifactSetCounts = SmallVec::new();
+        for artifact in self.artifacts.iter() {
+            match result.iter_mut().find(|x| x.0 == artifact.set_name) {
+                Some(x) => x.1 += 1,
+                None => result.push((artifact.set_name, 1)),
             }
         }
+        result.sort_by_key(|x| x.0 as usize);
+        result
+    }
 
-        // calculate stats
-        let key = "圣遗物词条";
-        for stats in attributes_hash.iter() {
-            stats.0.apply(attribute, key, *stats.1);
-        }
+    /// the part of `set_counts` that decides the set effects: sets below 2 pieces are dropped, counts are rounded down to 2 or 4.
+    /// only 2 and 4 piece effects exist, so counts with equal active effects give the same attribute
+    pub fn active_set_counts(set_counts: &[(ArtifactSetName, i32)]) -> ArtifactSetCounts {
+        set_counts.iter()
+            .filter(|x| x.1 >= 2)
+            .map(|&(name, count)| (name, if count >= 4 { 4 } else { 2 }))
+            .collect()
+    }
 
-        // calculate set effect
-        for (&set_name, &count) in set_name_count.iter() {
+    pub fn apply_set_effects<T: Attribute>(set_counts: &[(ArtifactSetName, i32)], attribute: &mut T, character: &Character<T>, config: &ArtifactEffectConfig) {
+        for &(set_name, count) in set_counts.iter() {
             let effect = get_effect(set_name, config, character);
             if count >= 5 {
                 effect.effect5(attribute);
             }
+            // 4 piece effects are conditional and do not show on the character panel
             if count >= 4 {
-                effect.effect4(attribute);
+                attribute.with_combat_sources(|attribute| effect.effect4(attribute));
             }
             if count >= 3 {
                 effect.effect3(attribute);
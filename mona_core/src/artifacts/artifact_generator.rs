@@ -0,0 +1,159 @@
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::common::StatName;
+use super::artifact::{Artifact, ArtifactSetName, ArtifactSlotName};
+use super::main_stat::artifact_main_stat_value;
+
+/// where an artifact drops, which decides the chance of the higher initial sub stat count
+#[derive(Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum ArtifactSource {
+    #[default]
+    Domain,
+    Strongbox,
+    Boss,
+}
+
+impl ArtifactSource {
+    pub fn extra_sub_stat_probability(&self) -> f64 {
+        match *self {
+            ArtifactSource::Domain => 0.2,
+            ArtifactSource::Strongbox => 1.0 / 3.0,
+            ArtifactSource::Boss => 1.0 / 3.0,
+        }
+    }
+}
+
+pub fn artifact_max_level(star: i32) -> i32 {
+    match star {
+        5 => 20,
+        4 => 16,
+        3 => 12,
+        _ => 4,
+    }
+}
+
+// the lower initial sub stat count, one more with `ArtifactSource::extra_sub_stat_probability`
+pub fn artifact_min_initial_sub_stat_count(star: i32) -> usize {
+    match star {
+        5 => 3,
+        4 => 2,
+        3 => 1,
+        _ => 0,
+    }
+}
+
+pub fn artifact_max_initial_sub_stat_count(star: i32) -> usize {
+    if star <= 1 {
+        0
+    } else {
+        artifact_min_initial_sub_stat_count(star) + 1
+    }
+}
+
+/// main stat drop weights of each slot
+pub fn artifact_main_stat_weights(slot: ArtifactSlotName) -> &'static [(StatName, f64)] {
+    use StatName::*;
+    match slot {
+        ArtifactSlotName::Flower => &[(HPFixed, 1.0)],
+        ArtifactSlotName::Feather => &[(ATKFixed, 1.0)],
+        ArtifactSlotName::Sand => &[
+            (HPPercentage, 0.2668), (ATKPercentage, 0.2666), (DEFPercentage, 0.2666),
+            (Recharge, 0.1), (ElementalMastery, 0.1),
+        ],
+        ArtifactSlotName::Goblet => &[
+            (HPPercentage, 0.1925), (ATKPercentage, 0.1925), (DEFPercentage, 0.19),
+            (PyroBonus, 0.05), (ElectroBonus, 0.05), (CryoBonus, 0.05), (HydroBonus, 0.05),
+            (DendroBonus, 0.05), (AnemoBonus, 0.05), (GeoBonus, 0.05), (PhysicalBonus, 0.05),
+            (ElementalMastery, 0.025),
+        ],
+        ArtifactSlotName::Head => &[
+            (HPPercentage, 0.22), (ATKPercentage, 0.22), (DEFPercentage, 0.22),
+            (CriticalRate, 0.1), (CriticalDamage, 0.1), (HealingBonus, 0.1), (ElementalMastery, 0.04),
+        ],
+    }
+}
+
+/// random artifact generation and upgrade, all randomness comes from `rng`, so a seeded rng gives reproducible results
+pub struct ArtifactGenerator<R> {
+    pub rng: R,
+}
+
+impl ArtifactGenerator<ThreadRng> {
+    pub fn from_thread_rng() -> ArtifactGenerator<ThreadRng> {
+        ArtifactGenerator {
+            rng: thread_rng()
+        }
+    }
+}
+
+impl<R: Rng> ArtifactGenerator<R> {
+    pub fn new(rng: R) -> ArtifactGenerator<R> {
+        ArtifactGenerator {
+            rng
+        }
+    }
+
+    pub fn random_set_name(&mut self) -> ArtifactSetName {
+        ArtifactSetName::random_with_rng(&mut self.rng)
+    }
+
+    pub fn random_main_stat(&mut self, slot: ArtifactSlotName) -> StatName {
+        StatName::random_artifact_main_stat_with_rng(slot, &mut self.rng)
+    }
+
+    pub fn random_initial_sub_stat_count(&mut self, star: i32, source: ArtifactSource) -> usize {
+        let min = artifact_min_initial_sub_stat_count(star);
+        if artifact_max_initial_sub_stat_count(star) > min && self.rng.gen_bool(source.extra_sub_stat_probability()) {
+            min + 1
+        } else {
+            min
+        }
+    }
+
+    /// a level 0 artifact with a random main stat
+    pub fn generate(&mut self, set_name: ArtifactSetName, slot: ArtifactSlotName, star: i32, source: ArtifactSource) -> Artifact {
+        let main_stat = self.random_main_stat(slot);
+        // random main stats are always valid main stats of the slot
+        let value = artifact_main_stat_value(main_stat, star, 0).unwrap_or(0.0);
+        self.generate_with_main_stat_value(set_name, slot, (main_stat, value), star, source)
+    }
+
+    /// `None` if `main_stat` is not a main stat of `slot`
+    pub fn generate_with_main_stat(&mut self, set_name: ArtifactSetName, slot: ArtifactSlotName, main_stat: StatName, star: i32, source: ArtifactSource) -> Option<Artifact> {
+        if !StatName::get_slot_main_stats()[slot as usize].contains(&main_stat) {
+            return None;
+        }
+        let value = artifact_main_stat_value(main_stat, star, 0)?;
+        Some(self.generate_with_main_stat_value(set_name, slot, (main_stat, value), star, source))
+    }
+
+    fn generate_with_main_stat_value(&mut self, set_name: ArtifactSetName, slot: ArtifactSlotName, main_stat: (StatName, f64), star: i32, source: ArtifactSource) -> Artifact {
+        let mut artifact = Artifact {
+            set_name,
+            slot,
+            level: 0,
+            star,
+            sub_stats: vec![],
+            main_stat,
+            id: self.rng.gen()
+        };
+
+        let count = self.random_initial_sub_stat_count(star, source);
+        for _ in 0..count {
+            artifact.add_random_sub_stat(&mut self.rng);
+        }
+
+        artifact
+    }
+
+    pub fn upgrade(&mut self, artifact: &mut Artifact) {
+        artifact.upgrade_with_rng(&mut self.rng);
+    }
+
+    pub fn upgrade_to_max(&mut self, artifact: &mut Artifact) {
+        while !artifact.is_max_level() {
+            self.upgrade(artifact);
+        }
+    }
+}
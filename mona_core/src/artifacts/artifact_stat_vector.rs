@@ -0,0 +1,93 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use strum::EnumCount;
+use crate::attribute::Attribute;
+use crate::common::StatName;
+use super::artifact::Artifact;
+
+/// dense stats of one or more artifacts, indexed by `StatName as usize`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ArtifactStatVector {
+    pub values: [f64; StatName::COUNT],
+}
+
+impl Default for ArtifactStatVector {
+    fn default() -> Self {
+        ArtifactStatVector {
+            values: [0.0; StatName::COUNT]
+        }
+    }
+}
+
+impl ArtifactStatVector {
+    pub fn from_artifact(artifact: &Artifact) -> ArtifactStatVector {
+        let mut result = ArtifactStatVector::default();
+        result.add_artifact(artifact);
+        result
+    }
+
+    pub fn from_artifacts(artifacts: &[&Artifact]) -> ArtifactStatVector {
+        let mut result = ArtifactStatVector::default();
+        for &artifact in artifacts.iter() {
+            result.add_artifact(artifact);
+        }
+        result
+    }
+
+    pub fn add_artifact(&mut self, artifact: &Artifact) {
+        self.values[artifact.main_stat.0 as usize] += artifact.main_stat.1;
+        for sub_stat in artifact.sub_stats.iter() {
+            self.values[sub_stat.0 as usize] += sub_stat.1;
+        }
+    }
+
+    pub fn get(&self, stat: StatName) -> f64 {
+        self.values[stat as usize]
+    }
+
+    /// non-zero stats
+    pub fn iter(&self) -> impl Iterator<Item = (StatName, f64)> + '_ {
+        self.values.iter().enumerate()
+            .filter(|(_, &v)| v != 0.0)
+            .map(|(i, &v)| (num::FromPrimitive::from_usize(i).unwrap(), v))
+    }
+
+    pub fn apply<T: Attribute>(&self, attribute: &mut T, key: &str) {
+        for (stat, value) in self.iter() {
+            stat.apply(attribute, key, value);
+        }
+    }
+}
+
+impl AddAssign<&ArtifactStatVector> for ArtifactStatVector {
+    fn add_assign(&mut self, rhs: &ArtifactStatVector) {
+        for i in 0..StatName::COUNT {
+            self.values[i] += rhs.values[i];
+        }
+    }
+}
+
+impl SubAssign<&ArtifactStatVector> for ArtifactStatVector {
+    fn sub_assign(&mut self, rhs: &ArtifactStatVector) {
+        for i in 0..StatName::COUNT {
+            self.values[i] -= rhs.values[i];
+        }
+    }
+}
+
+impl Add<&ArtifactStatVector> for ArtifactStatVector {
+    type Output = ArtifactStatVector;
+
+    fn add(mut self, rhs: &ArtifactStatVector) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl Sub<&ArtifactStatVector> for ArtifactStatVector {
+    type Output = ArtifactStatVector;
+
+    fn sub(mut self, rhs: &ArtifactStatVector) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
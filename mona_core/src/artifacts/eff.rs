@@ -12,6 +12,8 @@ pub struct ArtifactEff {
     pub recharge: [f64; 4],
     pub elemental_mastery: [f64; 4],
     pub healing_bonus: [f64; 4],
+    // number of possible roll values, the rest of each array repeats the last value
+    pub tier_count: usize,
 }
 
 impl ArtifactEff {
@@ -34,8 +36,72 @@ impl ArtifactEff {
             // _ => unreachable!()
         }
     }
+
+    pub fn max_value(&self, stat: StatName) -> f64 {
+        self.get_value(stat, self.tier_count - 1)
+    }
+
+    /// expected value of one roll, every tier is equally likely
+    pub fn mean_value(&self, stat: StatName) -> f64 {
+        (0..self.tier_count).map(|i| self.get_value(stat, i)).sum::<f64>() / self.tier_count as f64
+    }
+}
+
+pub fn get_artifact_eff(star: i32) -> &'static ArtifactEff {
+    match star {
+        5 => &ARTIFACT_EFF5,
+        4 => &ARTIFACT_EFF4,
+        3 => &ARTIFACT_EFF3,
+        2 => &ARTIFACT_EFF2,
+        _ => &ARTIFACT_EFF1,
+    }
 }
 
+pub const ARTIFACT_EFF1: ArtifactEff = ArtifactEff {
+    atk: [1.56, 1.95, 1.95, 1.95],
+    atk_percentage: [0.0117, 0.0146, 0.0146, 0.0146],
+    def: [1.85, 2.31, 2.31, 2.31],
+    def_percentage: [0.0146, 0.0182, 0.0182, 0.0182],
+    hp: [23.9, 29.88, 29.88, 29.88],
+    hp_percentage: [0.0117, 0.0146, 0.0146, 0.0146],
+    critical_rate: [0.0078, 0.0097, 0.0097, 0.0097],
+    critical_damage: [0.0155, 0.0194, 0.0194, 0.0194],
+    recharge: [0.013, 0.0162, 0.0162, 0.0162],
+    elemental_mastery: [4.66, 5.83, 5.83, 5.83],
+    healing_bonus: [0.009, 0.0112, 0.0112, 0.0112],
+    tier_count: 2,
+};
+
+pub const ARTIFACT_EFF2: ArtifactEff = ArtifactEff {
+    atk: [3.27, 3.97, 4.67, 4.67],
+    atk_percentage: [0.0163, 0.0198, 0.0233, 0.0233],
+    def: [3.89, 4.72, 5.56, 5.56],
+    def_percentage: [0.0204, 0.0248, 0.0291, 0.0291],
+    hp: [50.19, 60.95, 71.7, 71.7],
+    hp_percentage: [0.0163, 0.0198, 0.0233, 0.0233],
+    critical_rate: [0.0109, 0.0132, 0.0155, 0.0155],
+    critical_damage: [0.0218, 0.0264, 0.0311, 0.0311],
+    recharge: [0.0181, 0.022, 0.0259, 0.0259],
+    elemental_mastery: [6.53, 7.93, 9.33, 9.33],
+    healing_bonus: [0.0126, 0.0153, 0.018, 0.018],
+    tier_count: 3,
+};
+
+pub const ARTIFACT_EFF3: ArtifactEff = ArtifactEff {
+    atk: [6.54, 7.47, 8.4, 9.34],
+    atk_percentage: [0.0245, 0.028, 0.0315, 0.035],
+    def: [7.78, 8.89, 10.0, 11.11],
+    def_percentage: [0.0306, 0.035, 0.0393, 0.0437],
+    hp: [100.38, 114.72, 129.06, 143.4],
+    hp_percentage: [0.0245, 0.028, 0.0315, 0.035],
+    critical_rate: [0.0163, 0.0186, 0.021, 0.0233],
+    critical_damage: [0.0326, 0.0373, 0.042, 0.0466],
+    recharge: [0.0272, 0.0311, 0.035, 0.0389],
+    elemental_mastery: [9.79, 11.19, 12.59, 13.99],
+    healing_bonus: [0.0188, 0.0215, 0.0242, 0.0269],
+    tier_count: 4,
+};
+
 pub const ARTIFACT_EFF4: ArtifactEff = ArtifactEff {
     atk: [11.0, 12.0, 14.0, 16.0],
     atk_percentage: [0.033, 0.037, 0.042, 0.047],
@@ -48,6 +114,7 @@ pub const ARTIFACT_EFF4: ArtifactEff = ArtifactEff {
     recharge: [0.036, 0.041, 0.047, 0.052],
     elemental_mastery: [13.0, 15.0, 17.0, 19.0],
     healing_bonus: [0.025, 0.029, 0.032, 0.036],
+    tier_count: 4,
 };
 
 pub const ARTIFACT_EFF5: ArtifactEff = ArtifactEff {
@@ -62,4 +129,5 @@ pub const ARTIFACT_EFF5: ArtifactEff = ArtifactEff {
     recharge: [0.045, 0.052, 0.058, 0.065],
     elemental_mastery: [16.0, 19.0, 21.0, 23.0],
     healing_bonus: [0.031, 0.036, 0.04, 0.045],
+    tier_count: 4,
 };
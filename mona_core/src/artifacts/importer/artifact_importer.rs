@@ -0,0 +1,120 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::artifacts::Artifact;
+use crate::artifacts::artifact_generator::artifact_max_level;
+use super::good_importer::GoodImporter;
+use super::mona_importer::MonaImporter;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImportedArtifact {
+    pub artifact: Artifact,
+    // character key of the equipping character as written by the scanner
+    pub location: Option<String>,
+    pub lock: bool,
+    // excluded from optimization, this is the `omit` flag of mona json and is unrelated to the in-game lock
+    pub omit: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ArtifactSkipReason {
+    UnknownSet(String),
+    UnknownSlot(String),
+    UnknownStat(String),
+    InvalidStar(i32),
+    InvalidLevel(i32),
+    Malformed(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SkippedArtifact {
+    // position of the piece in the source document
+    pub index: usize,
+    pub reasons: Vec<ArtifactSkipReason>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ArtifactImportReport {
+    pub imported: usize,
+    pub skipped: Vec<SkippedArtifact>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ArtifactImportResult {
+    pub artifacts: Vec<ImportedArtifact>,
+    pub report: ArtifactImportReport,
+}
+
+impl ArtifactImportResult {
+    pub fn push(&mut self, index: usize, artifact: Result<ImportedArtifact, Vec<ArtifactSkipReason>>) {
+        match artifact.and_then(check_star_and_level) {
+            Ok(x) => {
+                self.artifacts.push(x);
+                self.report.imported += 1;
+            },
+            Err(reasons) => self.report.skipped.push(SkippedArtifact { index, reasons })
+        }
+    }
+}
+
+fn check_star_and_level(imported: ImportedArtifact) -> Result<ImportedArtifact, Vec<ArtifactSkipReason>> {
+    let artifact = &imported.artifact;
+    if artifact.star < 1 || artifact.star > 5 {
+        Err(vec![ArtifactSkipReason::InvalidStar(artifact.star)])
+    } else if artifact.level < 0 || artifact.level > artifact_max_level(artifact.star) {
+        Err(vec![ArtifactSkipReason::InvalidLevel(artifact.level)])
+    } else {
+        Ok(imported)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArtifactImportError {
+    Json(String),
+    // no importer recognizes the document
+    UnknownFormat,
+    // the document is recognized, but its structure is broken
+    InvalidDocument(String),
+}
+
+impl Display for ArtifactImportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArtifactImportError::Json(e) => write!(f, "Artifact Import Error: invalid json, {}", e),
+            ArtifactImportError::UnknownFormat => write!(f, "Artifact Import Error: unknown format"),
+            ArtifactImportError::InvalidDocument(e) => write!(f, "Artifact Import Error: {}", e),
+        }
+    }
+}
+
+impl Error for ArtifactImportError {}
+
+/// converts the artifacts of one scanner output format into normalized `Artifact`s.
+/// pieces that can not be mapped are skipped and listed in the report instead of failing the whole import
+pub trait ArtifactImporter {
+    fn name(&self) -> &'static str;
+
+    /// whether `document` looks like this format
+    fn detect(&self, document: &Value) -> bool;
+
+    fn import(&self, document: &Value) -> Result<ArtifactImportResult, ArtifactImportError>;
+}
+
+pub fn default_importers() -> Vec<Box<dyn ArtifactImporter>> {
+    vec![
+        Box::new(GoodImporter),
+        Box::new(MonaImporter),
+    ]
+}
+
+/// imports with the first of `default_importers` detecting the format, returns its name along with the result
+pub fn import_artifacts(json: &str) -> Result<(&'static str, ArtifactImportResult), ArtifactImportError> {
+    let document: Value = serde_json::from_str(json).map_err(|e| ArtifactImportError::Json(e.to_string()))?;
+    for importer in default_importers() {
+        if importer.detect(&document) {
+            return importer.import(&document).map(|x| (importer.name(), x));
+        }
+    }
+    Err(ArtifactImportError::UnknownFormat)
+}
@@ -0,0 +1,59 @@
+use serde_json::Value;
+use crate::good::{GoodArtifact, GoodError, GoodKeyKind};
+use super::artifact_importer::{ArtifactImporter, ArtifactImportError, ArtifactImportResult, ArtifactSkipReason, ImportedArtifact};
+
+/// GOOD documents, written by most inventory scanners
+pub struct GoodImporter;
+
+fn skip_reasons(e: GoodError) -> Vec<ArtifactSkipReason> {
+    match e {
+        GoodError::UnknownKeys(keys) => keys.into_iter().map(|k| match k.kind {
+            GoodKeyKind::Set => ArtifactSkipReason::UnknownSet(k.key),
+            GoodKeyKind::Slot => ArtifactSkipReason::UnknownSlot(k.key),
+            GoodKeyKind::Stat => ArtifactSkipReason::UnknownStat(k.key),
+            GoodKeyKind::Character | GoodKeyKind::Weapon => ArtifactSkipReason::Malformed(k.key),
+        }).collect(),
+        e => vec![ArtifactSkipReason::Malformed(e.to_string())]
+    }
+}
+
+fn import_one(index: usize, value: &Value) -> Result<ImportedArtifact, Vec<ArtifactSkipReason>> {
+    let mut good: GoodArtifact = serde_json::from_value(value.clone())
+        .map_err(|e| vec![ArtifactSkipReason::Malformed(e.to_string())])?;
+
+    // the location is kept as written, an unknown character does not make the piece unusable
+    let location = std::mem::take(&mut good.location);
+    let entry = good.to_artifact(index as u64).map_err(skip_reasons)?;
+
+    Ok(ImportedArtifact {
+        artifact: entry.artifact,
+        location: if location.is_empty() { None } else { Some(location) },
+        lock: entry.lock,
+        omit: false,
+    })
+}
+
+impl ArtifactImporter for GoodImporter {
+    fn name(&self) -> &'static str {
+        "GOOD"
+    }
+
+    fn detect(&self, document: &Value) -> bool {
+        document.get("format").and_then(|x| x.as_str()) == Some("GOOD")
+    }
+
+    fn import(&self, document: &Value) -> Result<ArtifactImportResult, ArtifactImportError> {
+        let mut result = ArtifactImportResult::default();
+        let artifacts = match document.get("artifacts") {
+            None | Some(Value::Null) => return Ok(result),
+            Some(Value::Array(x)) => x,
+            Some(_) => return Err(ArtifactImportError::InvalidDocument(String::from("artifacts is not an array")))
+        };
+
+        for (index, value) in artifacts.iter().enumerate() {
+            result.push(index, import_one(index, value));
+        }
+
+        Ok(result)
+    }
+}
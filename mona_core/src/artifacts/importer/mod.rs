@@ -0,0 +1,10 @@
+pub use artifact_importer::{
+    ArtifactImporter, ArtifactImportError, ArtifactImportResult, ArtifactImportReport,
+    ImportedArtifact, SkippedArtifact, ArtifactSkipReason, default_importers, import_artifacts
+};
+pub use mona_importer::MonaImporter;
+pub use good_importer::GoodImporter;
+
+mod artifact_importer;
+mod mona_importer;
+mod good_importer;
@@ -0,0 +1,199 @@
+use serde::Deserialize;
+use serde_json::Value;
+use crate::artifacts::{Artifact, ArtifactSetName, ArtifactSlotName};
+use crate::common::StatName;
+use super::artifact_importer::{ArtifactImporter, ArtifactImportError, ArtifactImportResult, ArtifactSkipReason, ImportedArtifact};
+
+// legacy set names of the mona format, newer sets use the variant names
+pub const MONA_SET_NAMES: [(&str, ArtifactSetName); 37] = [
+    ("adventurer", ArtifactSetName::Adventurer),
+    ("archaicPetra", ArtifactSetName::ArchaicPetra),
+    ("berserker", ArtifactSetName::Berserker),
+    ("blizzardStrayer", ArtifactSetName::BlizzardStrayer),
+    ("bloodstainedChivalry", ArtifactSetName::BloodstainedChivalry),
+    ("braveHeart", ArtifactSetName::BraveHeart),
+    ("crimsonWitch", ArtifactSetName::CrimsonWitchOfFlames),
+    ("defenderWill", ArtifactSetName::DefendersWill),
+    ("emblemOfSeveredFate", ArtifactSetName::EmblemOfSeveredFate),
+    ("gambler", ArtifactSetName::Gambler),
+    ("gladiatorFinale", ArtifactSetName::GladiatorsFinale),
+    ("heartOfDepth", ArtifactSetName::HeartOfDepth),
+    ("huskOfOpulentDreams", ArtifactSetName::HuskOfOpulentDreams),
+    ("instructor", ArtifactSetName::Instructor),
+    ("lavaWalker", ArtifactSetName::Lavawalker),
+    ("luckyDog", ArtifactSetName::LuckyDog),
+    ("maidenBeloved", ArtifactSetName::MaidenBeloved),
+    ("martialArtist", ArtifactSetName::MartialArtist),
+    ("noblesseOblige", ArtifactSetName::NoblesseOblige),
+    ("oceanHuedClam", ArtifactSetName::OceanHuedClam),
+    ("paleFlame", ArtifactSetName::PaleFlame),
+    ("prayersForDestiny", ArtifactSetName::PrayersForDestiny),
+    ("prayersForIllumination", ArtifactSetName::PrayersForIllumination),
+    ("prayersForWisdom", ArtifactSetName::PrayersForWisdom),
+    ("prayersToSpringtime", ArtifactSetName::PrayersToSpringtime),
+    ("resolutionOfSojourner", ArtifactSetName::ResolutionOfSojourner),
+    ("retracingBolide", ArtifactSetName::RetracingBolide),
+    ("scholar", ArtifactSetName::Scholar),
+    ("shimenawaReminiscence", ArtifactSetName::ShimenawasReminiscence),
+    ("tenacityOfTheMillelith", ArtifactSetName::TenacityOfTheMillelith),
+    ("exile", ArtifactSetName::TheExile),
+    ("thunderingFury", ArtifactSetName::ThunderingFury),
+    ("thunderSmoother", ArtifactSetName::Thundersoother),
+    ("tinyMiracle", ArtifactSetName::TinyMiracle),
+    ("travelingDoctor", ArtifactSetName::TravelingDoctor),
+    ("viridescentVenerer", ArtifactSetName::ViridescentVenerer),
+    ("wandererTroupe", ArtifactSetName::WanderersTroupe),
+];
+
+pub const MONA_STAT_NAMES: [(&str, StatName); 19] = [
+    ("cureEffect", StatName::HealingBonus),
+    ("lifeStatic", StatName::HPFixed),
+    ("lifePercentage", StatName::HPPercentage),
+    ("attackStatic", StatName::ATKFixed),
+    ("attackPercentage", StatName::ATKPercentage),
+    ("defendStatic", StatName::DEFFixed),
+    ("defendPercentage", StatName::DEFPercentage),
+    ("critical", StatName::CriticalRate),
+    ("criticalDamage", StatName::CriticalDamage),
+    ("elementalMastery", StatName::ElementalMastery),
+    ("recharge", StatName::Recharge),
+    ("thunderBonus", StatName::ElectroBonus),
+    ("fireBonus", StatName::PyroBonus),
+    ("waterBonus", StatName::HydroBonus),
+    ("iceBonus", StatName::CryoBonus),
+    ("windBonus", StatName::AnemoBonus),
+    ("rockBonus", StatName::GeoBonus),
+    ("physicalBonus", StatName::PhysicalBonus),
+    ("dendroBonus", StatName::DendroBonus),
+];
+
+// keys of the artifact arrays, with the aliases accepted by the web importer
+pub const MONA_SLOT_NAMES: [(&str, ArtifactSlotName); 9] = [
+    ("flower", ArtifactSlotName::Flower),
+    ("feather", ArtifactSlotName::Feather),
+    ("plume", ArtifactSlotName::Feather),
+    ("sand", ArtifactSlotName::Sand),
+    ("sands", ArtifactSlotName::Sand),
+    ("cup", ArtifactSlotName::Goblet),
+    ("goblet", ArtifactSlotName::Goblet),
+    ("head", ArtifactSlotName::Head),
+    ("circlet", ArtifactSlotName::Head),
+];
+
+fn mona_set_name(name: &str) -> Option<ArtifactSetName> {
+    MONA_SET_NAMES.iter().find(|x| x.0 == name).map(|x| x.1)
+        .or_else(|| serde_json::from_value(Value::String(name.to_string())).ok())
+        .filter(|&x| x != ArtifactSetName::Empty)
+}
+
+fn mona_stat_name(name: &str) -> Option<StatName> {
+    MONA_STAT_NAMES.iter().find(|x| x.0 == name).map(|x| x.1)
+}
+
+#[derive(Deserialize)]
+struct MonaTag {
+    name: String,
+    value: f64,
+}
+
+fn default_star() -> i32 {
+    5
+}
+
+fn default_level() -> i32 {
+    20
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MonaArtifact {
+    set_name: String,
+    main_tag: MonaTag,
+    #[serde(default)]
+    normal_tags: Vec<MonaTag>,
+    #[serde(default = "default_star")]
+    star: i32,
+    #[serde(default = "default_level")]
+    level: i32,
+    // pieces excluded from optimization
+    #[serde(default)]
+    omit: bool,
+    #[serde(default)]
+    equip: Option<String>,
+    #[serde(default)]
+    id: Option<u64>,
+}
+
+fn import_one(index: usize, slot: ArtifactSlotName, value: &Value) -> Result<ImportedArtifact, Vec<ArtifactSkipReason>> {
+    let mona: MonaArtifact = serde_json::from_value(value.clone())
+        .map_err(|e| vec![ArtifactSkipReason::Malformed(e.to_string())])?;
+
+    let mut reasons = Vec::new();
+    let set_name = mona_set_name(&mona.set_name);
+    if set_name.is_none() {
+        reasons.push(ArtifactSkipReason::UnknownSet(mona.set_name.clone()));
+    }
+    let mut stat = |tag: &MonaTag| {
+        let stat = mona_stat_name(&tag.name);
+        if stat.is_none() {
+            reasons.push(ArtifactSkipReason::UnknownStat(tag.name.clone()));
+        }
+        stat.map(|x| (x, tag.value))
+    };
+    let main_stat = stat(&mona.main_tag);
+    let sub_stats: Vec<Option<(StatName, f64)>> = mona.normal_tags.iter().map(&mut stat).collect();
+
+    if !reasons.is_empty() {
+        return Err(reasons);
+    }
+
+    Ok(ImportedArtifact {
+        artifact: Artifact {
+            set_name: set_name.unwrap(),
+            slot,
+            level: mona.level,
+            star: mona.star,
+            sub_stats: sub_stats.into_iter().flatten().collect(),
+            main_stat: main_stat.unwrap(),
+            id: mona.id.unwrap_or(index as u64),
+        },
+        location: mona.equip.filter(|x| !x.is_empty()),
+        // mona json has no lock state
+        lock: false,
+        omit: mona.omit,
+    })
+}
+
+/// the legacy mona json, one array of artifacts for each slot, percentages are fractions
+pub struct MonaImporter;
+
+impl ArtifactImporter for MonaImporter {
+    fn name(&self) -> &'static str {
+        "mona"
+    }
+
+    fn detect(&self, document: &Value) -> bool {
+        document.get("format").is_none() && MONA_SLOT_NAMES.iter().any(|x| document.get(x.0).map_or(false, |v| v.is_array()))
+    }
+
+    fn import(&self, document: &Value) -> Result<ArtifactImportResult, ArtifactImportError> {
+        let mut result = ArtifactImportResult::default();
+
+        // index counts pieces over all slots, in the order of `MONA_SLOT_NAMES`
+        let mut index = 0;
+        for &(key, slot) in MONA_SLOT_NAMES.iter() {
+            let artifacts = match document.get(key) {
+                None | Some(Value::Null) => continue,
+                Some(Value::Array(x)) => x,
+                Some(_) => return Err(ArtifactImportError::InvalidDocument(format!("{} is not an array", key)))
+            };
+
+            for value in artifacts.iter() {
+                result.push(index, import_one(index, slot, value));
+                index += 1;
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,121 @@
+use crate::common::StatName;
+use super::artifact_generator::artifact_max_level;
+
+// main stat values at level 0 and at the max level, values in between grow linearly with level.
+// the in-game per level tables are this linear progression rounded to the shown precision
+// (e.g. 5 star atk% 7.0%, 9.0%, 11.0%, 12.9%, ... 46.6%, each step is (46.6% - 7.0%) / 20),
+// so interpolating reproduces every level up to that rounding and no per level table is stored
+pub struct ArtifactMainStatTable {
+    pub hp: [f64; 2],
+    pub atk: [f64; 2],
+    // also atk percentage and elemental bonus
+    pub hp_percentage: [f64; 2],
+    // also physical bonus
+    pub def_percentage: [f64; 2],
+    pub elemental_mastery: [f64; 2],
+    pub recharge: [f64; 2],
+    pub critical_rate: [f64; 2],
+    pub critical_damage: [f64; 2],
+    pub healing_bonus: [f64; 2],
+}
+
+impl ArtifactMainStatTable {
+    // `None` for flat def, which is never a main stat but may still come from imported data
+    fn get_range(&self, stat: StatName) -> Option<[f64; 2]> {
+        use StatName::*;
+        let range = match stat {
+            HPFixed => self.hp,
+            ATKFixed => self.atk,
+            HPPercentage | ATKPercentage => self.hp_percentage,
+            PyroBonus | ElectroBonus | HydroBonus | CryoBonus | AnemoBonus | GeoBonus | DendroBonus => self.hp_percentage,
+            DEFPercentage | PhysicalBonus => self.def_percentage,
+            ElementalMastery => self.elemental_mastery,
+            Recharge => self.recharge,
+            CriticalRate => self.critical_rate,
+            CriticalDamage => self.critical_damage,
+            HealingBonus => self.healing_bonus,
+            DEFFixed => return None,
+        };
+        Some(range)
+    }
+}
+
+pub const ARTIFACT_MAIN_STAT5: ArtifactMainStatTable = ArtifactMainStatTable {
+    hp: [717.0, 4780.0],
+    atk: [47.0, 311.0],
+    hp_percentage: [0.07, 0.466],
+    def_percentage: [0.087, 0.583],
+    elemental_mastery: [28.0, 187.0],
+    recharge: [0.078, 0.518],
+    critical_rate: [0.047, 0.311],
+    critical_damage: [0.093, 0.622],
+    healing_bonus: [0.054, 0.359],
+};
+
+pub const ARTIFACT_MAIN_STAT4: ArtifactMainStatTable = ArtifactMainStatTable {
+    hp: [645.0, 3571.0],
+    atk: [42.0, 232.0],
+    hp_percentage: [0.063, 0.348],
+    def_percentage: [0.079, 0.435],
+    elemental_mastery: [25.0, 139.0],
+    recharge: [0.07, 0.387],
+    critical_rate: [0.042, 0.232],
+    critical_damage: [0.084, 0.464],
+    healing_bonus: [0.048, 0.268],
+};
+
+pub const ARTIFACT_MAIN_STAT3: ArtifactMainStatTable = ArtifactMainStatTable {
+    hp: [430.0, 1893.0],
+    atk: [28.0, 123.0],
+    hp_percentage: [0.052, 0.231],
+    def_percentage: [0.066, 0.288],
+    elemental_mastery: [21.0, 92.0],
+    recharge: [0.058, 0.256],
+    critical_rate: [0.035, 0.154],
+    critical_damage: [0.07, 0.308],
+    healing_bonus: [0.04, 0.177],
+};
+
+pub const ARTIFACT_MAIN_STAT2: ArtifactMainStatTable = ArtifactMainStatTable {
+    hp: [258.0, 551.0],
+    atk: [17.0, 36.0],
+    hp_percentage: [0.042, 0.09],
+    def_percentage: [0.052, 0.112],
+    elemental_mastery: [17.0, 36.0],
+    recharge: [0.047, 0.099],
+    critical_rate: [0.028, 0.06],
+    critical_damage: [0.056, 0.119],
+    healing_bonus: [0.032, 0.069],
+};
+
+pub const ARTIFACT_MAIN_STAT1: ArtifactMainStatTable = ArtifactMainStatTable {
+    hp: [129.0, 324.0],
+    atk: [8.0, 21.0],
+    hp_percentage: [0.031, 0.074],
+    def_percentage: [0.039, 0.092],
+    elemental_mastery: [13.0, 30.0],
+    recharge: [0.035, 0.082],
+    critical_rate: [0.021, 0.049],
+    critical_damage: [0.042, 0.099],
+    healing_bonus: [0.024, 0.057],
+};
+
+pub fn get_artifact_main_stat_table(star: i32) -> &'static ArtifactMainStatTable {
+    match star {
+        5 => &ARTIFACT_MAIN_STAT5,
+        4 => &ARTIFACT_MAIN_STAT4,
+        3 => &ARTIFACT_MAIN_STAT3,
+        2 => &ARTIFACT_MAIN_STAT2,
+        _ => &ARTIFACT_MAIN_STAT1,
+    }
+}
+
+/// main stat value of an artifact with `star` at `level`, levels above the max level are treated as the max level,
+/// `None` if `stat` can not be a main stat
+pub fn artifact_main_stat_value(stat: StatName, star: i32, level: i32) -> Option<f64> {
+    let [start, end] = get_artifact_main_stat_table(star).get_range(stat)?;
+    let max_level = artifact_max_level(star);
+    let level = level.clamp(0, max_level);
+
+    Some(start + (end - start) * level as f64 / max_level as f64)
+}
@@ -5,5 +5,14 @@ pub mod effects;
 pub mod artifact_trait;
 pub mod eff;
 pub mod artifact_set_type;
+pub mod artifact_stat_vector;
+pub mod artifact_generator;
+pub mod main_stat;
+pub mod roll_analysis;
+pub mod importer;
 
-pub use artifact::{Artifact, ArtifactSetName, ArtifactSlotName, ArtifactList};
\ No newline at end of file
+pub use artifact::{Artifact, ArtifactSetName, ArtifactSlotName, ArtifactList, ArtifactSetCounts};
+pub use artifact_stat_vector::ArtifactStatVector;
+pub use artifact_generator::{ArtifactGenerator, ArtifactSource};
+pub use main_stat::artifact_main_stat_value;
+pub use roll_analysis::{ArtifactRollAnalysis, ArtifactRollIssue, SubStatRolls};
\ No newline at end of file
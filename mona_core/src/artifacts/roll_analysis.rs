@@ -0,0 +1,283 @@
+use serde::{Deserialize, Serialize};
+use crate::common::StatName;
+use super::artifact::Artifact;
+use super::artifact_generator::{artifact_max_initial_sub_stat_count, artifact_max_level, artifact_min_initial_sub_stat_count};
+use super::eff::{get_artifact_eff, ArtifactEff};
+
+pub fn is_sub_stat(stat: StatName) -> bool {
+    use StatName::*;
+    matches!(
+        stat,
+        HPFixed | HPPercentage | ATKFixed | ATKPercentage | DEFFixed | DEFPercentage
+        | CriticalRate | CriticalDamage | ElementalMastery | Recharge
+    )
+}
+
+// flat stats are shown as integers, the others to 0.1%
+fn display_unit(stat: StatName) -> f64 {
+    use StatName::*;
+    match stat {
+        HPFixed | ATKFixed | DEFFixed | ElementalMastery => 1.0,
+        _ => 0.001
+    }
+}
+
+// both the shown value and every roll in the eff table are rounded to the display unit
+fn roll_tolerance(stat: StatName, roll_count: usize) -> f64 {
+    display_unit(stat) * 0.5 * (roll_count + 1) as f64 + 1e-9
+}
+
+pub fn artifact_upgrade_count(star: i32, level: i32) -> usize {
+    (level.clamp(0, artifact_max_level(star)) / 4) as usize
+}
+
+/// possible total number of sub stat rolls of an artifact, initial sub stats included
+pub fn artifact_roll_count_range(star: i32, level: i32) -> (usize, usize) {
+    let upgrades = artifact_upgrade_count(star, level);
+    (
+        artifact_min_initial_sub_stat_count(star) + upgrades,
+        artifact_max_initial_sub_stat_count(star) + upgrades,
+    )
+}
+
+/// reconstructed rolls of one sub stat
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SubStatRolls {
+    pub stat: StatName,
+    pub value: f64,
+    /// tier of each roll in the eff table of the artifact's rarity, ascending
+    pub tiers: Vec<usize>,
+    /// sum of the rolls, differs from `value` by the rounding of the shown value
+    pub rolled_value: f64,
+}
+
+impl SubStatRolls {
+    pub fn roll_count(&self) -> usize {
+        self.tiers.len()
+    }
+
+    fn error(&self) -> f64 {
+        (self.rolled_value - self.value).abs() / display_unit(self.stat)
+    }
+}
+
+// closest combination of `count` rolls to `value`
+fn closest_rolls(eff: &ArtifactEff, stat: StatName, value: f64, count: usize) -> SubStatRolls {
+    // tiers are enumerated as non-decreasing sequences, the order of rolls does not matter
+    let mut tiers = vec![0; count];
+    let mut best_tiers = tiers.clone();
+    let mut best_sum = f64::NAN;
+    loop {
+        let sum: f64 = tiers.iter().map(|&t| eff.get_value(stat, t)).sum();
+        if best_sum.is_nan() || (sum - value).abs() < (best_sum - value).abs() {
+            best_sum = sum;
+            best_tiers.copy_from_slice(&tiers);
+        }
+
+        let mut i = count;
+        while i > 0 && tiers[i - 1] == eff.tier_count - 1 {
+            i -= 1;
+        }
+        if i == 0 {
+            break;
+        }
+        let next = tiers[i - 1] + 1;
+        for t in tiers[i - 1..].iter_mut() {
+            *t = next;
+        }
+    }
+
+    SubStatRolls {
+        stat,
+        value,
+        tiers: best_tiers,
+        rolled_value: best_sum,
+    }
+}
+
+/// the closest roll combination for each roll count up to `max_roll_count`, only those matching `value` up to rounding
+pub fn sub_stat_roll_candidates(stat: StatName, value: f64, star: i32, max_roll_count: usize) -> Vec<SubStatRolls> {
+    let eff = get_artifact_eff(star);
+    (1..=max_roll_count)
+        .map(|count| closest_rolls(eff, stat, value, count))
+        .filter(|rolls| (rolls.rolled_value - value).abs() <= roll_tolerance(stat, rolls.roll_count()))
+        .collect()
+}
+
+// picks one candidate of each sub stat so that the total roll count is within `range`, preferring the smallest error
+fn choose_rolls(candidates: &[Vec<SubStatRolls>], range: (usize, usize)) -> Option<Vec<usize>> {
+    fn search(candidates: &[Vec<SubStatRolls>], range: (usize, usize), current: &mut Vec<usize>, rolls: usize, error: f64, best: &mut Option<(f64, Vec<usize>)>) {
+        let depth = current.len();
+        if depth == candidates.len() {
+            if rolls >= range.0 && rolls <= range.1 && best.as_ref().map_or(true, |b| error < b.0) {
+                *best = Some((error, current.clone()));
+            }
+            return;
+        }
+
+        for (index, rolls_of_stat) in candidates[depth].iter().enumerate() {
+            // every remaining sub stat takes at least one roll
+            if rolls + rolls_of_stat.roll_count() + candidates.len() - depth - 1 > range.1 {
+                continue;
+            }
+            current.push(index);
+            search(candidates, range, current, rolls + rolls_of_stat.roll_count(), error + rolls_of_stat.error(), best);
+            current.pop();
+        }
+    }
+
+    let mut best = None;
+    search(candidates, range, &mut Vec::new(), 0, 0.0, &mut best);
+    best.map(|x| x.1)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ArtifactRollIssue {
+    InvalidLevel { level: i32 },
+    NotASubStat(StatName),
+    SameAsMainStat(StatName),
+    DuplicateSubStat(StatName),
+    SubStatCount { min: usize, max: usize, actual: usize },
+    ImpossibleValue { stat: StatName, value: f64 },
+    RollCount { min: usize, max: usize },
+}
+
+/// reconstructs how many rolls went into each sub stat and which tiers were hit,
+/// and reports what is impossible for the rarity and level of the artifact
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ArtifactRollAnalysis {
+    pub id: u64,
+    /// one for each sub stat, `None` if the value can not be rolled
+    pub sub_stats: Vec<Option<SubStatRolls>>,
+    pub issues: Vec<ArtifactRollIssue>,
+}
+
+impl ArtifactRollAnalysis {
+    pub fn new(artifact: &Artifact) -> ArtifactRollAnalysis {
+        let mut issues = Vec::new();
+
+        let star = artifact.star;
+        if artifact.level < 0 || artifact.level > artifact_max_level(star) {
+            issues.push(ArtifactRollIssue::InvalidLevel { level: artifact.level });
+        }
+
+        for (i, &(stat, _)) in artifact.sub_stats.iter().enumerate() {
+            if !is_sub_stat(stat) {
+                issues.push(ArtifactRollIssue::NotASubStat(stat));
+            } else if stat == artifact.main_stat.0 {
+                issues.push(ArtifactRollIssue::SameAsMainStat(stat));
+            } else if artifact.sub_stats[..i].iter().any(|x| x.0 == stat) {
+                issues.push(ArtifactRollIssue::DuplicateSubStat(stat));
+            }
+        }
+
+        let range = Self::roll_count_range(artifact);
+        let count_range = (range.0.min(4), range.1.min(4));
+        let sub_stat_count = artifact.sub_stats.len();
+        if sub_stat_count < count_range.0 || sub_stat_count > count_range.1 {
+            issues.push(ArtifactRollIssue::SubStatCount { min: count_range.0, max: count_range.1, actual: sub_stat_count });
+        }
+
+        let max_roll_count = artifact_upgrade_count(star, artifact.level) + 1;
+        let candidates: Vec<Vec<SubStatRolls>> = artifact.sub_stats.iter()
+            .map(|&(stat, value)| if is_sub_stat(stat) {
+                sub_stat_roll_candidates(stat, value, star, max_roll_count)
+            } else {
+                Vec::new()
+            })
+            .collect();
+
+        let mut sub_stats: Vec<Option<SubStatRolls>> = vec![None; sub_stat_count];
+        let mut all_possible = true;
+        for (i, c) in candidates.iter().enumerate() {
+            let (stat, value) = artifact.sub_stats[i];
+            if c.is_empty() && is_sub_stat(stat) {
+                issues.push(ArtifactRollIssue::ImpossibleValue { stat, value });
+            }
+            all_possible &= !c.is_empty();
+        }
+
+        // the roll count can only be checked when every value can be rolled
+        if all_possible {
+            match choose_rolls(&candidates, range) {
+                Some(chosen) => {
+                    for (i, index) in chosen.into_iter().enumerate() {
+                        sub_stats[i] = Some(candidates[i][index].clone());
+                    }
+                },
+                None => {
+                    issues.push(ArtifactRollIssue::RollCount { min: range.0, max: range.1 });
+                    for (i, c) in candidates.iter().enumerate() {
+                        sub_stats[i] = c.iter().min_by(|x, y| x.error().partial_cmp(&y.error()).unwrap()).cloned();
+                    }
+                }
+            }
+        } else {
+            for (i, c) in candidates.iter().enumerate() {
+                sub_stats[i] = c.first().cloned();
+            }
+        }
+
+        ArtifactRollAnalysis {
+            id: artifact.id,
+            sub_stats,
+            issues,
+        }
+    }
+
+    // sub stats are added before any of them is rolled again, so with less than 4 sub stats each has exactly one roll
+    fn roll_count_range(artifact: &Artifact) -> (usize, usize) {
+        let (min, max) = artifact_roll_count_range(artifact.star, artifact.level);
+        let count = artifact.sub_stats.len();
+        if count < 4 {
+            (min.max(count), max.min(count))
+        } else {
+            (min, max)
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    pub fn total_roll_count(&self) -> usize {
+        self.sub_stats.iter().flatten().map(|x| x.roll_count()).sum()
+    }
+
+    /// an artifact whose impossible sub stat values are replaced by the closest values that can be rolled,
+    /// `None` if the artifact has other issues or can not be fixed
+    pub fn fix(artifact: &Artifact) -> Option<Artifact> {
+        let analysis = ArtifactRollAnalysis::new(artifact);
+        if analysis.is_valid() {
+            return Some(artifact.clone());
+        }
+        if !analysis.issues.iter().all(|x| matches!(x, ArtifactRollIssue::ImpossibleValue { .. })) {
+            return None;
+        }
+
+        let eff = get_artifact_eff(artifact.star);
+        let max_roll_count = artifact_upgrade_count(artifact.star, artifact.level) + 1;
+        let candidates: Vec<Vec<SubStatRolls>> = artifact.sub_stats.iter().enumerate()
+            .map(|(i, &(stat, value))| match analysis.sub_stats[i] {
+                Some(ref rolls) => vec![rolls.clone()],
+                None => (1..=max_roll_count).map(|count| closest_rolls(eff, stat, value, count)).collect()
+            })
+            .collect();
+
+        let chosen = choose_rolls(&candidates, Self::roll_count_range(artifact))?;
+        let mut result = artifact.clone();
+        for (i, index) in chosen.into_iter().enumerate() {
+            if analysis.sub_stats[i].is_none() {
+                let rolls = &candidates[i][index];
+                let unit = display_unit(rolls.stat);
+                result.sub_stats[i].1 = (rolls.rolled_value / unit).round() * unit;
+            }
+        }
+
+        if ArtifactRollAnalysis::new(&result).is_valid() {
+            Some(result)
+        } else {
+            None
+        }
+    }
+}
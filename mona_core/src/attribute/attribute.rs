@@ -1,4 +1,5 @@
 use crate::attribute::typing::EdgeFunctionBwd;
+use super::edge_order::{AttributeGraphError, EdgeInfo, EDGE_PRIORITY_DEFAULT, edge_order, validate_edges};
 use super::attribute_name::AttributeName;
 use super::typing::EdgeFunctionFwd;
 use crate::common::{Element, SkillType};
@@ -23,9 +24,50 @@ pub trait Attribute: Default {
         fwd: EdgeFunctionFwd,
         bwd: EdgeFunctionBwd,
         key: &str
-    ) -> Self::EdgeHandle;
+    ) -> Result<Self::EdgeHandle, AttributeGraphError> {
+        self.add_edge_with_priority(from1, from2, to, fwd, bwd, key, EDGE_PRIORITY_DEFAULT)
+    }
+
+    // edges with a higher priority are evaluated later, and must not change the inputs of lower priority edges.
+    // fails if the graph has no room left for the edge
+    fn add_edge_with_priority(
+        &mut self,
+        from1: usize,
+        from2: usize,
+        to: usize,
+        fwd: EdgeFunctionFwd,
+        bwd: EdgeFunctionBwd,
+        key: &str,
+        priority: usize
+    ) -> Result<Self::EdgeHandle, AttributeGraphError>;
 
     fn remove_edge(&mut self, handle: Self::EdgeHandle);
+
+    fn edges(&self) -> Vec<EdgeInfo>;
+
+    /// edge indices in evaluation order, fails if the edges form a cycle
+    fn edge_order(&self) -> Result<Vec<usize>, AttributeGraphError> {
+        edge_order(&self.edges())
+    }
+
+    /// checks edge capacity, cycles and priorities
+    fn validate_edges(&self) -> Result<(), AttributeGraphError> {
+        validate_edges(&self.edges())
+    }
+
+    // tag a source of a panel node as combat-only, only tracked by graphs that keep compositions
+    fn mark_combat_source(&mut self, _name: AttributeName, _key: &str) {}
+
+    /// like `set_value_by`, but the value does not show on the character panel
+    fn set_combat_value_by(&mut self, name: AttributeName, key: &str, value: f64) {
+        self.set_value_by(name, key, value);
+        self.mark_combat_source(name, key);
+    }
+
+    /// values set and edges added in `f` apply in combat only, e.g. buffs from the team
+    fn with_combat_sources<F: FnOnce(&mut Self)>(&mut self, f: F) {
+        f(self);
+    }
 }
 
 pub trait AttributeCommon<T> {
@@ -283,8 +325,9 @@ impl<T: Attribute> AttributeCommon<T> for T {
         temp
     }
 
+    // the graph remembers edges it has no room for, `validate_edges` reports them
     fn add_edge1(&mut self, from: AttributeName, to: AttributeName, fwd: EdgeFunctionFwd, bwd: EdgeFunctionBwd, key: &str) {
-        self.add_edge(
+        let _ = self.add_edge(
             from as usize,
             usize::MAX,
             to as usize,
@@ -295,7 +338,7 @@ impl<T: Attribute> AttributeCommon<T> for T {
     }
 
     fn add_edge2(&mut self, from1: AttributeName, from2: AttributeName, to: AttributeName, fwd: EdgeFunctionFwd, bwd: EdgeFunctionBwd, key: &str) {
-        self.add_edge(
+        let _ = self.add_edge(
             from1 as usize,
             from2 as usize,
             to as usize,
@@ -306,7 +349,7 @@ impl<T: Attribute> AttributeCommon<T> for T {
     }
 
     fn add_atk_percentage(&mut self, key: &str, value: f64) {
-        self.add_edge(
+        let _ = self.add_edge(
             AttributeName::ATKBase as usize,
             usize::MAX,
             AttributeName::ATKPercentage as usize,
@@ -317,7 +360,7 @@ impl<T: Attribute> AttributeCommon<T> for T {
     }
 
     fn add_def_percentage(&mut self, key: &str, value: f64) {
-        self.add_edge(
+        let _ = self.add_edge(
             AttributeName::DEFBase as usize,
             usize::MAX,
             AttributeName::DEFPercentage as usize,
@@ -328,7 +371,7 @@ impl<T: Attribute> AttributeCommon<T> for T {
     }
 
     fn add_hp_percentage(&mut self, key: &str, value: f64) {
-        self.add_edge(
+        let _ = self.add_edge(
             AttributeName::HPBase as usize,
             usize::MAX,
             AttributeName::HPPercentage as usize,
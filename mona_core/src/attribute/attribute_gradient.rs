@@ -0,0 +1,130 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use strum::EnumCount;
+use crate::common::StatName;
+use super::attribute::Attribute;
+use super::edge_order::AttributeGraphError;
+
+/// nodes read through `Attribute::get_value` while recording, see `AttributeGradient::nodes_read_by`
+#[derive(Default)]
+pub struct ReadRecorder {
+    recording: Cell<bool>,
+    nodes: RefCell<Vec<usize>>,
+}
+
+impl ReadRecorder {
+    pub fn record(&self, index: usize) {
+        if self.recording.get() {
+            self.nodes.borrow_mut().push(index);
+        }
+    }
+
+    fn run<F: FnOnce()>(&self, f: F) -> Vec<usize> {
+        self.nodes.borrow_mut().clear();
+        self.recording.set(true);
+        f();
+        self.recording.set(false);
+
+        let mut nodes = self.nodes.take();
+        nodes.sort_unstable();
+        nodes.dedup();
+        nodes
+    }
+}
+
+/// reverse mode differentiation over the attribute graph, using the `EdgeFunctionBwd` of each edge
+pub trait AttributeGradient: Attribute {
+    fn node_count(&self) -> usize;
+
+    fn read_recorder(&self) -> &ReadRecorder;
+
+    /// distinct nodes `f` reads through `get_value`, reads of edges evaluating other nodes are not included
+    fn nodes_read_by<F: FnOnce(&Self)>(&self, f: F) -> Vec<usize> where Self: Sized {
+        self.read_recorder().run(|| f(self))
+    }
+
+    // grad of the two inputs of an edge, given the grad of its output
+    fn edge_backward(&self, edge: usize, grad: f64) -> (f64, f64);
+
+    fn node_value(&self, index: usize) -> f64;
+
+    // change the value of a node without updating the nodes depending on it
+    fn shift_node_value(&self, index: usize, delta: f64);
+
+    /// `grad` holds d(target)/d(node value) read directly by the target,
+    /// it is turned into the total derivative of each node, including the paths through edges
+    fn backward(&self, grad: &mut [f64]) -> Result<(), AttributeGraphError> {
+        let edges = self.edges();
+        // every edge writing to a node comes after the edges reading it in reverse order,
+        // so the grad of a node is complete before it is propagated
+        for &i in self.edge_order()?.iter().rev() {
+            let edge = &edges[i];
+            let g = grad[edge.to];
+            if g == 0.0 {
+                continue;
+            }
+            let (g1, g2) = self.edge_backward(i, g);
+            grad[edge.from1] += g1;
+            if edge.from2 != usize::MAX {
+                grad[edge.from2] += g2;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// d(target)/d(stat) for every stat, percentage stats are scaled by the base value they multiply.
+///
+/// target functions are black boxes over the attribute and give no analytic gradient to start from,
+/// so the backward pass is seeded with central finite differences of the target by the nodes it reads,
+/// usually a few final values such as atk, crit and bonus rather than one per stat.
+/// every stat then gets its derivative from this single backward pass through the edges
+pub fn stat_gradient<A: AttributeGradient, F: Fn(&A) -> f64>(attribute: &A, target: F) -> Result<HashMap<StatName, f64>, AttributeGraphError> {
+    let n = attribute.node_count();
+    for i in 0..n {
+        attribute.node_value(i);
+    }
+
+    let stats: Vec<StatName> = (0..StatName::COUNT).map(|i| num::FromPrimitive::from_usize(i).unwrap()).collect();
+
+    // only stat nodes and the nodes depending on them can change the gradient
+    let mut affected = vec![false; n];
+    for &stat in stats.iter() {
+        affected[stat.attribute_name() as usize] = true;
+    }
+    let edges = attribute.edges();
+    for &i in attribute.edge_order()?.iter() {
+        let edge = &edges[i];
+        if affected[edge.from1] || (edge.from2 != usize::MAX && affected[edge.from2]) {
+            affected[edge.to] = true;
+        }
+    }
+
+    // the target does not depend on nodes it never reads
+    let read = attribute.nodes_read_by(|x| { target(x); });
+
+    let mut grad = vec![0.0; n];
+    for i in read.into_iter().filter(|&i| affected[i]) {
+        let h = 1e-4 * attribute.node_value(i).abs().max(1.0);
+        attribute.shift_node_value(i, h);
+        let plus = target(attribute);
+        attribute.shift_node_value(i, -2.0 * h);
+        let minus = target(attribute);
+        attribute.shift_node_value(i, h);
+        grad[i] = (plus - minus) / (2.0 * h);
+    }
+
+    attribute.backward(&mut grad)?;
+
+    let mut result = HashMap::new();
+    for stat in stats {
+        let g = grad[stat.attribute_name() as usize];
+        let value = match stat.percentage_base() {
+            Some(base) => g * attribute.get_value(base),
+            None => g
+        };
+        result.insert(stat, value);
+    }
+
+    Ok(result)
+}
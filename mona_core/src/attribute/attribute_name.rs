@@ -1,6 +1,10 @@
+use serde::{Serialize, Deserialize};
+use strum_macros::EnumIter;
 use crate::common::{SkillType, Element};
+use crate::common::reaction_type::TransformativeType;
 
-#[derive(Hash, Eq, PartialEq, Clone, Copy, Debug)]
+#[derive(Hash, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+#[derive(Serialize, Deserialize, EnumIter)]
 pub enum AttributeName {
     // 自定义数据，应当只用在角色的特定的Effect中，否则容易使用不当，产生冲突
     USER1,
@@ -112,6 +116,31 @@ pub enum AttributeName {
     EnhanceMelt,
     EnhanceAggravate,
     EnhanceSpread,
+    // 月曜反应伤害提升，与精通加成同一个乘区
+    EnhanceLunarBase,
+    EnhanceLunarCharged,
+    EnhanceLunarBloom,
+    // 月曜反应基础伤害提升，例如月兆·满辉
+    LunarBaseDmgBonus,
+
+    CriticalLunarCharged,
+    CriticalLunarBloom,
+    CriticalDamageLunarCharged,
+    CriticalDamageLunarBloom,
+
+    // 剧变反应暴击，例如纳西妲命座2，不受角色暴击影响
+    CriticalTransformative,
+    CriticalBloom,
+    CriticalHyperbloom,
+    CriticalBurgeon,
+    CriticalBurning,
+    CriticalDamageTransformative,
+    CriticalDamageBloom,
+    CriticalDamageHyperbloom,
+    CriticalDamageBurgeon,
+    CriticalDamageBurning,
+    // 剧变反应基础伤害提升
+    TransformativeExtraDmg,
 
     HPRatioBase,
     HPRatioNormalAttack,
@@ -179,6 +208,22 @@ pub enum AttributeName {
 }
 
 impl AttributeName {
+    /// whether the node is shown on the in-game character panel, other nodes only apply in combat
+    pub fn is_panel(&self) -> bool {
+        use AttributeName::*;
+        match *self {
+            HPBase | HPFixed | HPPercentage | HP
+            | ATKBase | ATKFixed | ATKPercentage | ATK
+            | DEFBase | DEFFixed | DEFPercentage | DEF
+            | ElementalMastery | ElementalMasteryExtra | Recharge
+            | HealingBonus | IncomingHealingBonus | ShieldStrength
+            | CriticalBase | CriticalDamageBase
+            | BonusElectro | BonusPyro | BonusHydro | BonusCryo
+            | BonusAnemo | BonusGeo | BonusDendro | BonusPhysical => true,
+            _ => false
+        }
+    }
+
     pub fn bonus_name_by_element(element: Element) -> AttributeName {
         match element {
             Element::Electro => AttributeName::BonusElectro,
@@ -347,6 +392,69 @@ impl AttributeName {
         }
     }
 
+    pub fn enhance_name_by_lunar_type(t: TransformativeType) -> Option<AttributeName> {
+        match t {
+            TransformativeType::LunarCharged => Some(AttributeName::EnhanceLunarCharged),
+            TransformativeType::LunarBloom => Some(AttributeName::EnhanceLunarBloom),
+            _ => None
+        }
+    }
+
+    pub fn critical_rate_name_by_lunar_type(t: TransformativeType) -> Option<AttributeName> {
+        match t {
+            TransformativeType::LunarCharged => Some(AttributeName::CriticalLunarCharged),
+            TransformativeType::LunarBloom => Some(AttributeName::CriticalLunarBloom),
+            _ => None
+        }
+    }
+
+    pub fn critical_damage_name_by_lunar_type(t: TransformativeType) -> Option<AttributeName> {
+        match t {
+            TransformativeType::LunarCharged => Some(AttributeName::CriticalDamageLunarCharged),
+            TransformativeType::LunarBloom => Some(AttributeName::CriticalDamageLunarBloom),
+            _ => None
+        }
+    }
+
+    /// swirl reactions also use EnhanceSwirlBase, lunar reactions are handled by `enhance_name_by_lunar_type`
+    pub fn enhance_name_by_transformative_type(t: TransformativeType) -> Option<AttributeName> {
+        match t {
+            TransformativeType::SwirlCryo => Some(AttributeName::EnhanceSwirlCryo),
+            TransformativeType::SwirlHydro => Some(AttributeName::EnhanceSwirlHydro),
+            TransformativeType::SwirlElectro => Some(AttributeName::EnhanceSwirlElectro),
+            TransformativeType::SwirlPyro => Some(AttributeName::EnhanceSwirlPyro),
+            TransformativeType::Superconduct => Some(AttributeName::EnhanceSuperconduct),
+            TransformativeType::Overload => Some(AttributeName::EnhanceOverload),
+            TransformativeType::Burning => Some(AttributeName::EnhanceBurning),
+            TransformativeType::ElectroCharged => Some(AttributeName::EnhanceElectroCharged),
+            TransformativeType::Shatter => Some(AttributeName::EnhanceShatter),
+            TransformativeType::Bloom => Some(AttributeName::EnhanceBloom),
+            TransformativeType::Burgeon => Some(AttributeName::EnhanceBurgeon),
+            TransformativeType::Hyperbloom => Some(AttributeName::EnhanceHyperbloom),
+            _ => None
+        }
+    }
+
+    pub fn critical_rate_name_by_transformative_type(t: TransformativeType) -> Option<AttributeName> {
+        match t {
+            TransformativeType::Bloom => Some(AttributeName::CriticalBloom),
+            TransformativeType::Hyperbloom => Some(AttributeName::CriticalHyperbloom),
+            TransformativeType::Burgeon => Some(AttributeName::CriticalBurgeon),
+            TransformativeType::Burning => Some(AttributeName::CriticalBurning),
+            _ => None
+        }
+    }
+
+    pub fn critical_damage_name_by_transformative_type(t: TransformativeType) -> Option<AttributeName> {
+        match t {
+            TransformativeType::Bloom => Some(AttributeName::CriticalDamageBloom),
+            TransformativeType::Hyperbloom => Some(AttributeName::CriticalDamageHyperbloom),
+            TransformativeType::Burgeon => Some(AttributeName::CriticalDamageBurgeon),
+            TransformativeType::Burning => Some(AttributeName::CriticalDamageBurning),
+            _ => None
+        }
+    }
+
     pub fn res_minus_name_by_element(element: Element) -> AttributeName {
         match element {
             Element::Cryo => AttributeName::ResMinusCryo,
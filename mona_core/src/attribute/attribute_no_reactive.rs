@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use serde::{Serialize, Deserialize};
 use super::complicated_attribute_graph::ComplicatedAttributeGraph;
+use super::attribute_view::AttributeView;
 
 use super::attribute_name::AttributeName;
 
@@ -45,49 +46,59 @@ impl AttributeNoReactive {
     }
 }
 
-impl From<&ComplicatedAttributeGraph> for AttributeNoReactive {
-    fn from(graph: &ComplicatedAttributeGraph) -> Self {
+impl AttributeNoReactive {
+    pub fn from_view(graph: &ComplicatedAttributeGraph, view: AttributeView) -> AttributeNoReactive {
         let mut attribute = AttributeNoReactive::new();
 
-        attribute.atk = graph.get_composition_merge(&vec![
+        attribute.atk = graph.get_view_composition_merge(&vec![
             AttributeName::ATKBase,
             AttributeName::ATKPercentage,
             AttributeName::ATKFixed
-        ]).0;
+        ], view).0;
 
-        attribute.def = graph.get_composition_merge(&vec![
+        attribute.def = graph.get_view_composition_merge(&vec![
             AttributeName::DEFBase,
             AttributeName::DEFPercentage,
             AttributeName::DEFFixed
-        ]).0;
+        ], view).0;
 
-        attribute.hp = graph.get_composition_merge(&vec![
+        attribute.hp = graph.get_view_composition_merge(&vec![
             AttributeName::HPBase,
             AttributeName::HPPercentage,
             AttributeName::HPFixed
-        ]).0;
+        ], view).0;
 
-        attribute.healing_bonus = graph.get_attribute_composition(AttributeName::HealingBonus).0;
-        attribute.elemental_mastery = graph.get_composition_merge(&vec![
+        attribute.healing_bonus = graph.get_view_composition(AttributeName::HealingBonus, view).0;
+        attribute.elemental_mastery = graph.get_view_composition_merge(&vec![
             AttributeName::ElementalMastery,
             AttributeName::ElementalMasteryExtra
-        ]).0;
-        attribute.recharge = graph.get_attribute_composition(AttributeName::Recharge).0;
-        attribute.shield_strength = graph.get_attribute_composition(AttributeName::ShieldStrength).0;
-        attribute.critical = graph.get_attribute_composition(AttributeName::CriticalBase).0;
-        attribute.critical_damage = graph.get_attribute_composition(AttributeName::CriticalDamageBase).0;
-
-        attribute.bonus_electro = graph.get_attribute_composition(AttributeName::BonusElectro).0;
-        attribute.bonus_pyro = graph.get_attribute_composition(AttributeName::BonusPyro).0;
-        attribute.bonus_anemo = graph.get_attribute_composition(AttributeName::BonusAnemo).0;
-        attribute.bonus_cryo = graph.get_attribute_composition(AttributeName::BonusCryo).0;
-        attribute.bonus_hydro = graph.get_attribute_composition(AttributeName::BonusHydro).0;
-        attribute.bonus_geo = graph.get_attribute_composition(AttributeName::BonusGeo).0;
-        attribute.bonus_dendro = graph.get_attribute_composition(AttributeName::BonusDendro).0;
-        attribute.bonus_physical = graph.get_attribute_composition(AttributeName::BonusPhysical).0;
+        ], view).0;
+        attribute.recharge = graph.get_view_composition(AttributeName::Recharge, view).0;
+        attribute.shield_strength = graph.get_view_composition(AttributeName::ShieldStrength, view).0;
+        // bonuses when attacking, e.g. royal series, are not shown on the panel
+        attribute.critical = graph.get_view_composition_merge(&vec![
+            AttributeName::CriticalBase,
+            AttributeName::CriticalAttacking
+        ], view).0;
+        attribute.critical_damage = graph.get_view_composition(AttributeName::CriticalDamageBase, view).0;
+
+        attribute.bonus_electro = graph.get_view_composition(AttributeName::BonusElectro, view).0;
+        attribute.bonus_pyro = graph.get_view_composition(AttributeName::BonusPyro, view).0;
+        attribute.bonus_anemo = graph.get_view_composition(AttributeName::BonusAnemo, view).0;
+        attribute.bonus_cryo = graph.get_view_composition(AttributeName::BonusCryo, view).0;
+        attribute.bonus_hydro = graph.get_view_composition(AttributeName::BonusHydro, view).0;
+        attribute.bonus_geo = graph.get_view_composition(AttributeName::BonusGeo, view).0;
+        attribute.bonus_dendro = graph.get_view_composition(AttributeName::BonusDendro, view).0;
+        attribute.bonus_physical = graph.get_view_composition(AttributeName::BonusPhysical, view).0;
 
         // todo other attributes
 
         attribute
     }
-}
\ No newline at end of file
+}
+
+impl From<&ComplicatedAttributeGraph> for AttributeNoReactive {
+    fn from(graph: &ComplicatedAttributeGraph) -> Self {
+        AttributeNoReactive::from_view(graph, AttributeView::Panel)
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+use serde::{Serialize, Deserialize};
+use strum::IntoEnumIterator;
+use super::attribute::Attribute;
+use super::attribute_name::AttributeName;
+use super::complicated_attribute_graph::ComplicatedAttributeGraph;
+
+// changes smaller than this are treated as rounding noise
+const EPS: f64 = 1e-9;
+
+// bitwise equal values, including equal infinities whose difference is NaN, are unchanged
+fn unchanged(before: f64, after: f64) -> bool {
+    before.to_bits() == after.to_bits() || (after - before).abs() <= EPS
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct AttributeNodeSnapshot {
+    #[serde(with = "non_finite")]
+    pub value: f64,
+    // source key -> value, including values from edges
+    #[serde(with = "non_finite_map")]
+    pub sources: BTreeMap<String, f64>,
+}
+
+/// json has no inf or NaN, they are written as the strings "inf", "-inf" and "NaN" instead of null
+mod non_finite {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(untagged)]
+    pub enum Repr {
+        Number(f64),
+        Text(String),
+    }
+
+    impl Repr {
+        pub fn new(value: f64) -> Repr {
+            if value.is_finite() {
+                Repr::Number(value)
+            } else if value.is_nan() {
+                Repr::Text(String::from("NaN"))
+            } else if value > 0.0 {
+                Repr::Text(String::from("inf"))
+            } else {
+                Repr::Text(String::from("-inf"))
+            }
+        }
+
+        pub fn value<E: serde::de::Error>(self) -> Result<f64, E> {
+            match self {
+                Repr::Number(x) => Ok(x),
+                Repr::Text(x) => match x.as_str() {
+                    "inf" => Ok(f64::INFINITY),
+                    "-inf" => Ok(f64::NEG_INFINITY),
+                    "NaN" => Ok(f64::NAN),
+                    _ => Err(E::custom(format!("invalid number {}", x)))
+                },
+            }
+        }
+    }
+
+    pub fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        Repr::new(*value).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        Repr::deserialize(deserializer)?.value()
+    }
+}
+
+mod non_finite_map {
+    use std::collections::BTreeMap;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use super::non_finite::Repr;
+
+    pub fn serialize<S: Serializer>(value: &BTreeMap<String, f64>, serializer: S) -> Result<S::Ok, S::Error> {
+        let map: BTreeMap<&String, Repr> = value.iter().map(|(k, v)| (k, Repr::new(*v))).collect();
+        map.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BTreeMap<String, f64>, D::Error> {
+        let map: BTreeMap<String, Repr> = BTreeMap::deserialize(deserializer)?;
+        map.into_iter().map(|(k, v)| Ok((k, v.value()?))).collect()
+    }
+}
+
+/// every node of a `ComplicatedAttributeGraph` with its sources
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct AttributeSnapshot {
+    pub nodes: BTreeMap<AttributeName, AttributeNodeSnapshot>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AttributeSourceChange {
+    pub source: String,
+    pub before: f64,
+    pub after: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AttributeNodeDiff {
+    pub name: AttributeName,
+    pub before: f64,
+    pub after: f64,
+    pub sources: Vec<AttributeSourceChange>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AttributeDiff {
+    // only nodes with a changed value or changed sources
+    pub nodes: Vec<AttributeNodeDiff>,
+}
+
+impl AttributeSnapshot {
+    pub fn from_graph(attribute: &ComplicatedAttributeGraph) -> AttributeSnapshot {
+        let mut nodes = BTreeMap::new();
+        for name in AttributeName::iter() {
+            let value = attribute.get_value(name);
+            let sources = attribute.get_attribute_composition(name).0
+                .into_iter()
+                .filter(|(_, v)| v.abs() > EPS)
+                .collect();
+            nodes.insert(name, AttributeNodeSnapshot {
+                value,
+                sources
+            });
+        }
+
+        AttributeSnapshot {
+            nodes
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    pub fn from_json(s: &str) -> serde_json::Result<AttributeSnapshot> {
+        serde_json::from_str(s)
+    }
+
+    /// changes from `self` to `other`, nodes missing in one snapshot are treated as zero
+    pub fn diff(&self, other: &AttributeSnapshot) -> AttributeDiff {
+        let empty = AttributeNodeSnapshot::default();
+        let mut names: Vec<AttributeName> = self.nodes.keys().chain(other.nodes.keys()).cloned().collect();
+        names.sort();
+        names.dedup();
+
+        let mut nodes = Vec::new();
+        for name in names {
+            let before = self.nodes.get(&name).unwrap_or(&empty);
+            let after = other.nodes.get(&name).unwrap_or(&empty);
+
+            let mut keys: Vec<&String> = before.sources.keys().chain(after.sources.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            let sources: Vec<AttributeSourceChange> = keys.into_iter()
+                .map(|key| AttributeSourceChange {
+                    source: key.clone(),
+                    before: before.sources.get(key).cloned().unwrap_or(0.0),
+                    after: after.sources.get(key).cloned().unwrap_or(0.0),
+                })
+                .filter(|x| !unchanged(x.before, x.after))
+                .collect();
+
+            if sources.is_empty() && unchanged(before.value, after.value) {
+                continue;
+            }
+            nodes.push(AttributeNodeDiff {
+                name,
+                before: before.value,
+                after: after.value,
+                sources
+            });
+        }
+
+        AttributeDiff {
+            nodes
+        }
+    }
+}
+
+impl AttributeDiff {
+    /// source -> (node, change) of every node the source changed
+    pub fn by_source(&self) -> BTreeMap<String, Vec<(AttributeName, f64)>> {
+        let mut result: BTreeMap<String, Vec<(AttributeName, f64)>> = BTreeMap::new();
+        for node in self.nodes.iter() {
+            for change in node.sources.iter() {
+                result.entry(change.source.clone())
+                    .or_default()
+                    .push((node.name, change.after - change.before));
+            }
+        }
+        result
+    }
+}
+
+impl ComplicatedAttributeGraph {
+    pub fn snapshot(&self) -> AttributeSnapshot {
+        AttributeSnapshot::from_graph(self)
+    }
+}
@@ -4,6 +4,7 @@ use crate::weapon::weapon::Weapon;
 use crate::buffs::Buff;
 use crate::artifacts::effect_config::ArtifactEffectConfig;
 use super::attribute::{Attribute, AttributeCommon};
+use super::edge_order::AttributeGraphError;
 use crate::common::ChangeAttribute;
 
 pub struct AttributeUtils {}
@@ -13,16 +14,21 @@ impl AttributeUtils {
         character: &Character<T>,
         weapon: &Weapon<T>,
         buffs: &Vec<Box<dyn Buff<T>>>
-    ) -> T {
+    ) -> Result<T, AttributeGraphError> {
         let mut attribute = T::new_with_base_edge();
 
         character.change_attribute(&mut attribute);
         weapon.change_attribute(&mut attribute);
-        for buff in buffs.iter() {
-            buff.change_attribute(&mut attribute);
-        }
+        attribute.with_combat_sources(|attribute| {
+            for buff in buffs.iter() {
+                buff.change_attribute(attribute);
+            }
+        });
 
-        attribute
+        // a cycle means some conversion feeds back into its own input
+        attribute.validate_edges()?;
+
+        Ok(attribute)
     }
 
     pub fn create_attribute_from_big_config<T: Attribute>(
@@ -31,7 +37,7 @@ impl AttributeUtils {
         character: &Character<T>,
         weapon: &Weapon<T>,
         buffs: &[Box<dyn Buff<T>>],
-    ) -> T {
+    ) -> Result<T, AttributeGraphError> {
         let mut attribute = T::new_with_base_edge();
 
         character.change_attribute(&mut attribute);
@@ -39,10 +45,15 @@ impl AttributeUtils {
         weapon.change_attribute(&mut attribute);
         artifacts.apply(&mut attribute, character, artifact_config);
 
-        for buff in buffs.iter() {
-            buff.change_attribute(&mut attribute);
-        }
+        attribute.with_combat_sources(|attribute| {
+            for buff in buffs.iter() {
+                buff.change_attribute(attribute);
+            }
+        });
+
+        // a cycle means some conversion feeds back into its own input
+        attribute.validate_edges()?;
 
-        attribute
+        Ok(attribute)
     }
 }
\ No newline at end of file
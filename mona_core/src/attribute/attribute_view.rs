@@ -0,0 +1,26 @@
+use serde::{Serialize, Deserialize};
+use super::attribute_no_reactive::AttributeNoReactive;
+use super::complicated_attribute_graph::ComplicatedAttributeGraph;
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AttributeView {
+    // values shown on the in-game character panel
+    Panel,
+    // values used when dealing damage, panel values included
+    Combat,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AttributeViews {
+    pub panel: AttributeNoReactive,
+    pub combat: AttributeNoReactive,
+}
+
+impl From<&ComplicatedAttributeGraph> for AttributeViews {
+    fn from(graph: &ComplicatedAttributeGraph) -> Self {
+        AttributeViews {
+            panel: AttributeNoReactive::from_view(graph, AttributeView::Panel),
+            combat: AttributeNoReactive::from_view(graph, AttributeView::Combat),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use strum::EnumCount;
+use crate::artifacts::{Artifact, ArtifactList, ArtifactSetCounts, ArtifactStatVector};
+use crate::artifacts::effect_config::ArtifactEffectConfig;
+use crate::buffs::Buff;
+use crate::character::Character;
+use crate::common::{ChangeAttribute, StatName};
+use crate::weapon::Weapon;
+use super::attribute::{Attribute, AttributeCommon};
+use super::attribute_name::AttributeName;
+use super::edge_order::AttributeGraphError;
+use super::simple_attribute_graph2::SimpleAttributeGraph2;
+
+struct BaseAttribute {
+    attribute: SimpleAttributeGraph2,
+    // converts each stat into the value of its node, the base of percentage stats is constant here
+    factors: [f64; StatName::COUNT],
+}
+
+/// evaluates many artifact combinations for one character/weapon/buffs setup.
+///
+/// the attribute without artifact stats is built once for each combination of active set effects,
+/// each candidate only adds its summed stats to the cached attribute and restores it afterwards
+pub struct ArtifactBatchEvaluator<'a> {
+    character: &'a Character<SimpleAttributeGraph2>,
+    weapon: &'a Weapon<SimpleAttributeGraph2>,
+    buffs: &'a [Box<dyn Buff<SimpleAttributeGraph2>>],
+    artifact_config: ArtifactEffectConfig,
+    bases: RefCell<HashMap<ArtifactSetCounts, BaseAttribute>>,
+}
+
+impl<'a> ArtifactBatchEvaluator<'a> {
+    pub fn new(
+        character: &'a Character<SimpleAttributeGraph2>,
+        weapon: &'a Weapon<SimpleAttributeGraph2>,
+        buffs: &'a [Box<dyn Buff<SimpleAttributeGraph2>>],
+        artifact_config: &ArtifactEffectConfig,
+    ) -> ArtifactBatchEvaluator<'a> {
+        ArtifactBatchEvaluator {
+            character,
+            weapon,
+            buffs,
+            artifact_config: artifact_config.clone(),
+            bases: RefCell::new(HashMap::new()),
+        }
+    }
+
+    fn create_base(&self, set_counts: &ArtifactSetCounts) -> Result<BaseAttribute, AttributeGraphError> {
+        let mut attribute = SimpleAttributeGraph2::new_with_base_edge();
+
+        self.character.change_attribute(&mut attribute);
+        self.weapon.change_attribute(&mut attribute);
+        ArtifactList::apply_set_effects(set_counts, &mut attribute, self.character, &self.artifact_config);
+        for buff in self.buffs.iter() {
+            buff.change_attribute(&mut attribute);
+        }
+        attribute.validate_edges()?;
+        attribute.set_dirty_on_set_value = true;
+
+        let mut factors = [1.0; StatName::COUNT];
+        for (i, factor) in factors.iter_mut().enumerate() {
+            let stat: StatName = num::FromPrimitive::from_usize(i).unwrap();
+            if let Some(base) = stat.percentage_base() {
+                *factor = attribute.get_value(base);
+            }
+        }
+
+        Ok(BaseAttribute {
+            attribute,
+            factors,
+        })
+    }
+
+    fn evaluate_with_base<R, F: FnMut(&SimpleAttributeGraph2) -> R>(base: &mut BaseAttribute, stats: &ArtifactStatVector, f: &mut F) -> R {
+        let mut saved: [(AttributeName, f64); StatName::COUNT] = [(AttributeName::ATKFixed, 0.0); StatName::COUNT];
+        let mut saved_count = 0;
+        for (stat, value) in stats.iter() {
+            // same nodes as `StatName::apply`
+            let node = stat.attribute_name();
+            saved[saved_count] = (node, base.attribute.get_value_self(node));
+            saved_count += 1;
+            base.attribute.set_value_by(node, "", value * base.factors[stat as usize]);
+        }
+
+        let result = f(&base.attribute);
+
+        // restore exactly instead of subtracting, so rounding errors do not pile up over millions of candidates
+        for &(node, value) in saved[..saved_count].iter().rev() {
+            base.attribute.set_value_to(node, "", value);
+        }
+
+        result
+    }
+
+    /// `f` is called with the attribute of `arts`, whose summed stats are `stats`.
+    /// fails if the edges added by the set effects of `arts` are invalid
+    pub fn evaluate<R, F: FnMut(&SimpleAttributeGraph2) -> R>(&self, arts: &[&Artifact], stats: &ArtifactStatVector, mut f: F) -> Result<R, AttributeGraphError> {
+        let set_counts = ArtifactList::active_set_counts(&ArtifactList { artifacts: arts }.set_counts());
+        let mut bases = self.bases.borrow_mut();
+        if !bases.contains_key(&set_counts) {
+            let base = self.create_base(&set_counts)?;
+            bases.insert(set_counts.clone(), base);
+        }
+        let base = bases.get_mut(&set_counts).unwrap();
+
+        Ok(Self::evaluate_with_base(base, stats, &mut f))
+    }
+
+    pub fn evaluate_artifacts<R, F: FnMut(&SimpleAttributeGraph2) -> R>(&self, arts: &[&Artifact], f: F) -> Result<R, AttributeGraphError> {
+        let stats = ArtifactStatVector::from_artifacts(arts);
+        self.evaluate(arts, &stats, f)
+    }
+
+    /// evaluates `prefix` completed by each of `candidates`, the stats of `prefix` are summed only once.
+    /// `f` receives the index of the candidate and the attribute
+    pub fn evaluate_batch<R, F: FnMut(usize, &SimpleAttributeGraph2) -> R>(&self, prefix: &[&Artifact], candidates: &[&Artifact], mut f: F) -> Result<Vec<R>, AttributeGraphError> {
+        if candidates.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let prefix_stats = ArtifactStatVector::from_artifacts(prefix);
+        let mut arts: Vec<&Artifact> = prefix.to_vec();
+        arts.push(candidates[0]);
+        let last = arts.len() - 1;
+
+        let mut bases = self.bases.borrow_mut();
+        let mut result = Vec::with_capacity(candidates.len());
+        let mut current_sets: Option<ArtifactSetCounts> = None;
+        for (index, &candidate) in candidates.iter().enumerate() {
+            arts[last] = candidate;
+            let set_counts = ArtifactList::active_set_counts(&ArtifactList { artifacts: &arts }.set_counts());
+            if current_sets.as_ref() != Some(&set_counts) {
+                if !bases.contains_key(&set_counts) {
+                    let base = self.create_base(&set_counts)?;
+                    bases.insert(set_counts.clone(), base);
+                }
+                current_sets = Some(set_counts);
+            }
+            let base = bases.get_mut(current_sets.as_ref().unwrap()).unwrap();
+
+            let mut stats = prefix_stats;
+            stats.add_artifact(candidate);
+            result.push(Self::evaluate_with_base(base, &stats, &mut |attribute| f(index, attribute)));
+        }
+
+        Ok(result)
+    }
+}
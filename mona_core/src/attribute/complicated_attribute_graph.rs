@@ -1,5 +1,5 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use crate::attribute::typing::EdgeFunctionBwd;
 
 use rand::Rng;
@@ -8,6 +8,9 @@ use crate::common::{Element, EntryType, SkillType};
 use super::attribute_name::AttributeName;
 use super::typing::EdgeFunctionFwd;
 use super::attribute::Attribute;
+use super::attribute_view::AttributeView;
+use super::attribute_gradient::{AttributeGradient, ReadRecorder};
+use super::edge_order::{AttributeGraphError, EdgeInfo};
 
 pub struct MyEdge {
     pub from1: usize,
@@ -17,6 +20,7 @@ pub struct MyEdge {
     pub fwd: EdgeFunctionFwd,
     pub bwd: EdgeFunctionBwd,
     pub id: usize,
+    pub priority: usize,
 }
 
 pub struct MyNode {
@@ -54,6 +58,11 @@ const MAX_ATTRIBUTE_ENTRY: usize = 200;
 pub struct ComplicatedAttributeGraph {
     pub attributes: RefCell<[MyNode; MAX_ATTRIBUTE_ENTRY]>,
     pub edges: Vec<MyEdge>,
+    // (node, key) of sources that apply in combat but not on the character panel
+    pub combat_sources: HashSet<(usize, String)>,
+    // inside `with_combat_sources`
+    combat_scope: bool,
+    reads: ReadRecorder,
 }
 
 impl Default for ComplicatedAttributeGraph {
@@ -66,6 +75,9 @@ impl Default for ComplicatedAttributeGraph {
                 cache_value: 0.0
             })),
             edges: Vec::new(),
+            combat_sources: HashSet::new(),
+            combat_scope: false,
+            reads: ReadRecorder::default(),
         };
 
         let data = ret.attributes.as_ptr();
@@ -83,6 +95,7 @@ impl Attribute for ComplicatedAttributeGraph {
     type EdgeHandle = usize;
 
     fn get_value(&self, key: AttributeName) -> f64 {
+        self.reads.record(key as usize);
         self.my_get_value(key as usize)
     }
 
@@ -92,6 +105,9 @@ impl Attribute for ComplicatedAttributeGraph {
             .value_self
             .entry(String::from(key))
             .or_insert(0.0) = value;
+        if self.combat_scope {
+            self.mark_combat_source(name, key);
+        }
     }
 
     fn set_value_by(&mut self, name: AttributeName, key: &str, value: f64) {
@@ -100,17 +116,21 @@ impl Attribute for ComplicatedAttributeGraph {
             .value_self
             .entry(String::from(key))
             .or_insert(0.0) += value;
+        if self.combat_scope {
+            self.mark_combat_source(name, key);
+        }
     }
 
-    fn add_edge(
+    fn add_edge_with_priority(
         &mut self,
         from1: usize,
         from2: usize,
         to: usize,
         fwd: EdgeFunctionFwd,
         bwd: EdgeFunctionBwd,
-        key: &str
-    ) -> Self::EdgeHandle {
+        key: &str,
+        priority: usize
+    ) -> Result<Self::EdgeHandle, AttributeGraphError> {
         let mut rng = rand::thread_rng();
         let id: usize = rng.gen();
         let edge = MyEdge {
@@ -121,10 +141,14 @@ impl Attribute for ComplicatedAttributeGraph {
             fwd,
             bwd,
             id,
+            priority,
         };
 
+        if self.combat_scope {
+            self.combat_sources.insert((to, String::from(key)));
+        }
         self.edges.push(edge);
-        id
+        Ok(id)
     }
 
     fn remove_edge(&mut self, handle: Self::EdgeHandle) {
@@ -138,6 +162,52 @@ impl Attribute for ComplicatedAttributeGraph {
 
         self.edges.remove(index);
     }
+
+    fn edges(&self) -> Vec<EdgeInfo> {
+        self.edges.iter().map(|e| EdgeInfo {
+            from1: e.from1,
+            from2: e.from2,
+            to: e.to,
+            priority: e.priority,
+            key: e.key.clone()
+        }).collect()
+    }
+
+    fn mark_combat_source(&mut self, name: AttributeName, key: &str) {
+        self.combat_sources.insert((name as usize, String::from(key)));
+    }
+
+    fn with_combat_sources<F: FnOnce(&mut Self)>(&mut self, f: F) {
+        let outer = self.combat_scope;
+        self.combat_scope = true;
+        f(self);
+        self.combat_scope = outer;
+    }
+}
+
+impl AttributeGradient for ComplicatedAttributeGraph {
+    fn node_count(&self) -> usize {
+        MAX_ATTRIBUTE_ENTRY
+    }
+
+    fn read_recorder(&self) -> &ReadRecorder {
+        &self.reads
+    }
+
+    fn edge_backward(&self, edge: usize, grad: f64) -> (f64, f64) {
+        let edge = &self.edges[edge];
+        (edge.bwd)(grad, self.get_from_value(edge.from1), self.get_from_value(edge.from2))
+    }
+
+    fn node_value(&self, index: usize) -> f64 {
+        self.my_get_value(index)
+    }
+
+    fn shift_node_value(&self, index: usize, delta: f64) {
+        // the cached value is returned as long as the node is not dirty
+        self.my_get_value(index);
+        self.attributes.borrow_mut()[index].cache_value += delta;
+    }
 }
 
 impl ComplicatedAttributeGraph {
@@ -180,6 +250,32 @@ impl ComplicatedAttributeGraph {
         EntryType(self.attributes.borrow()[name as usize].composition())
     }
 
+    pub fn source_view(&self, name: AttributeName, key: &str) -> AttributeView {
+        if name.is_panel() && !self.combat_sources.contains(&(name as usize, String::from(key))) {
+            AttributeView::Panel
+        } else {
+            AttributeView::Combat
+        }
+    }
+
+    /// sources of a node visible in the view, the combat view contains every source
+    pub fn get_view_composition(&self, name: AttributeName, view: AttributeView) -> EntryType {
+        let comp = self.get_attribute_composition(name);
+        match view {
+            AttributeView::Combat => comp,
+            AttributeView::Panel => EntryType(comp.0.into_iter().filter(|(k, _)| self.source_view(name, k) == AttributeView::Panel).collect())
+        }
+    }
+
+    pub fn get_view_composition_merge(&self, names: &[AttributeName], view: AttributeView) -> EntryType {
+        let mut temp = EntryType::new();
+        for name in names.iter() {
+            temp.merge(&self.get_view_composition(*name, view));
+        }
+
+        temp
+    }
+
     pub fn get_composition_merge(&self, names: &[AttributeName]) -> EntryType {
         let mut temp = EntryType::new();
         for name in names.iter() {
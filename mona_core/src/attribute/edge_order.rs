@@ -0,0 +1,134 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use strum::IntoEnumIterator;
+use super::attribute_name::AttributeName;
+
+// default priority of edges, higher priority edges are evaluated later
+pub const EDGE_PRIORITY_DEFAULT: usize = 0;
+
+#[derive(Debug, Clone)]
+pub struct EdgeInfo {
+    pub from1: usize,
+    // usize::MAX for edges with a single input
+    pub from2: usize,
+    pub to: usize,
+    pub priority: usize,
+    pub key: String,
+}
+
+impl EdgeInfo {
+    fn reads(&self, node: usize) -> bool {
+        self.from1 == node || (self.from2 != usize::MAX && self.from2 == node)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttributeGraphError {
+    // nodes and keys of the edges that can never be evaluated
+    Cycle { nodes: Vec<AttributeName>, keys: Vec<String> },
+    // `key` is the first edge that did not fit
+    EdgeCapacityExceeded { key: String, capacity: usize },
+    // `edge` reads `node`, which is changed by `writer` with a higher priority
+    PriorityInversion { edge: String, node: AttributeName, writer: String },
+}
+
+impl Display for AttributeGraphError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttributeGraphError::Cycle { nodes, keys } => write!(f, "Attribute Graph Error: cycle through {:?}, edges {:?}", nodes, keys),
+            AttributeGraphError::EdgeCapacityExceeded { key, capacity } => write!(f, "Attribute Graph Error: edge {} exceeds the capacity {}", key, capacity),
+            AttributeGraphError::PriorityInversion { edge, node, writer } => write!(f, "Attribute Graph Error: edge {} reads {:?}, which is changed later by {}", edge, node, writer),
+        }
+    }
+}
+
+impl Error for AttributeGraphError {}
+
+pub fn attribute_name_from_index(index: usize) -> Option<AttributeName> {
+    AttributeName::iter().nth(index)
+}
+
+/// edge indices in evaluation order, every edge comes after the edges writing its inputs.
+/// independent edges are ordered by priority, then by insertion order
+pub fn edge_order(edges: &[EdgeInfo]) -> Result<Vec<usize>, AttributeGraphError> {
+    let node_count = edges.iter()
+        .flat_map(|e| [e.from1, e.from2, e.to])
+        .filter(|&x| x != usize::MAX)
+        .max()
+        .map_or(0, |x| x + 1);
+
+    // edges not yet evaluated writing each node
+    let mut pending = vec![0_usize; node_count];
+    for e in edges.iter() {
+        pending[e.to] += 1;
+    }
+    let is_ready = |e: &EdgeInfo, pending: &[usize]| {
+        pending[e.from1] == 0 && (e.from2 == usize::MAX || pending[e.from2] == 0)
+    };
+
+    let mut heap = BinaryHeap::new();
+    for (i, e) in edges.iter().enumerate() {
+        if is_ready(e, &pending) {
+            heap.push(Reverse((e.priority, i)));
+        }
+    }
+
+    let mut order = Vec::with_capacity(edges.len());
+    let mut done = vec![false; edges.len()];
+    while let Some(Reverse((_, i))) = heap.pop() {
+        if done[i] {
+            continue;
+        }
+        done[i] = true;
+        order.push(i);
+
+        let to = edges[i].to;
+        pending[to] -= 1;
+        if pending[to] == 0 {
+            for (j, e) in edges.iter().enumerate() {
+                if !done[j] && e.reads(to) && is_ready(e, &pending) {
+                    heap.push(Reverse((e.priority, j)));
+                }
+            }
+        }
+    }
+
+    if order.len() < edges.len() {
+        let mut nodes: Vec<AttributeName> = Vec::new();
+        let mut keys = Vec::new();
+        for (i, e) in edges.iter().enumerate() {
+            if !done[i] {
+                if let Some(name) = attribute_name_from_index(e.to) {
+                    if !nodes.contains(&name) {
+                        nodes.push(name);
+                    }
+                }
+                keys.push(e.key.clone());
+            }
+        }
+        return Err(AttributeGraphError::Cycle { nodes, keys });
+    }
+
+    Ok(order)
+}
+
+/// checks cycles and priorities
+pub fn validate_edges(edges: &[EdgeInfo]) -> Result<(), AttributeGraphError> {
+    edge_order(edges)?;
+
+    for e in edges.iter() {
+        for writer in edges.iter() {
+            if writer.priority > e.priority && e.reads(writer.to) {
+                return Err(AttributeGraphError::PriorityInversion {
+                    edge: e.key.clone(),
+                    node: attribute_name_from_index(writer.to).unwrap(),
+                    writer: writer.key.clone()
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
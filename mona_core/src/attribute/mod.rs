@@ -6,6 +6,11 @@ pub mod attribute_name;
 pub mod simple_attribute_graph2;
 pub mod typing;
 pub mod complicated_attribute_graph;
+pub mod attribute_gradient;
+pub mod attribute_snapshot;
+pub mod attribute_view;
+pub mod edge_order;
+pub mod batch_evaluator;
 // pub mod edge_priority;
 
 pub use attribute::{AttributeCommon, Attribute};
@@ -15,4 +20,9 @@ pub use attribute_name::AttributeName;
 pub use simple_attribute_graph2::SimpleAttributeGraph2;
 pub use complicated_attribute_graph::ComplicatedAttributeGraph;
 pub use attribute_utils::AttributeUtils;
+pub use attribute_gradient::{AttributeGradient, stat_gradient};
+pub use attribute_snapshot::{AttributeSnapshot, AttributeDiff};
+pub use attribute_view::{AttributeView, AttributeViews};
+pub use edge_order::{AttributeGraphError, EdgeInfo};
+pub use batch_evaluator::ArtifactBatchEvaluator;
 // pub use edge_priority::EdgePriority;
@@ -3,6 +3,8 @@ use std::cell::RefCell;
 use crate::attribute::typing::EdgeFunctionBwd;
 
 use super::attribute::Attribute;
+use super::attribute_gradient::{AttributeGradient, ReadRecorder};
+use super::edge_order::{AttributeGraphError, EdgeInfo, validate_edges};
 use super::typing::EdgeFunctionFwd;
 
 use super::attribute_name::AttributeName;
@@ -20,10 +22,12 @@ struct SimpleEntry {
 
 struct SimpleEdge {
     pub func: EdgeFunctionFwd,
+    pub bwd: EdgeFunctionBwd,
     pub from1: usize,
     pub from2: usize,
     pub to: usize,
     pub key: String,
+    pub priority: usize,
 }
 
 pub struct SimpleAttributeGraph2 {
@@ -31,6 +35,9 @@ pub struct SimpleAttributeGraph2 {
     // edges: Vec<Rc<SimpleEdge>>,
     // edges: Vec<SimpleEdge>,
     edges: SmallVec<[SimpleEdge; MAX_EDGE_COUNT]>,
+    // first edge that did not fit into `edges`
+    rejected_edge: Option<String>,
+    reads: ReadRecorder,
     pub set_dirty_on_set_value: bool,
 
     // atk_percentage: f64,
@@ -49,6 +56,8 @@ impl Default for SimpleAttributeGraph2 {
             }; MAX_NODE_COUNT]),
             // edges: Vec::new(),
             edges: SmallVec::new(),
+            rejected_edge: None,
+            reads: ReadRecorder::default(),
             set_dirty_on_set_value: false,
             // atk_percentage: 0.0,
             // def_percentage: 0.0,
@@ -71,6 +80,7 @@ impl Attribute for SimpleAttributeGraph2 {
     type EdgeHandle = ();
 
     fn get_value(&self, key: AttributeName) -> f64 {
+        self.reads.record(key as usize);
         self.my_get_value(key as usize)
     }
 
@@ -94,31 +104,89 @@ impl Attribute for SimpleAttributeGraph2 {
         }
     }
 
-    fn add_edge(
+    fn add_edge_with_priority(
         &mut self,
         from1: usize,
         from2: usize,
         to: usize,
         fwd: EdgeFunctionFwd,
-        _bwd: EdgeFunctionBwd,
-        key: &str
-    ) -> Self::EdgeHandle {
+        bwd: EdgeFunctionBwd,
+        key: &str,
+        priority: usize
+    ) -> Result<Self::EdgeHandle, AttributeGraphError> {
+        if self.edges.len() >= MAX_EDGE_COUNT {
+            if self.rejected_edge.is_none() {
+                self.rejected_edge = Some(String::from(key));
+            }
+            return Err(AttributeGraphError::EdgeCapacityExceeded { key: String::from(key), capacity: MAX_EDGE_COUNT });
+        }
+
         let edge = SimpleEdge {
             func: fwd,
+            bwd,
             from1,
             from2,
             to,
-            key: String::from(key)
+            key: String::from(key),
+            priority
         };
         // self.edges.push(Rc::new(edge));
         self.edges.push(edge);
+        Ok(())
     }
 
     fn remove_edge(&mut self, _handle: Self::EdgeHandle) {
     }
+
+    fn edges(&self) -> Vec<EdgeInfo> {
+        self.edges.iter().map(|e| EdgeInfo {
+            from1: e.from1,
+            from2: e.from2,
+            to: e.to,
+            priority: e.priority,
+            key: e.key.clone()
+        }).collect()
+    }
+
+    fn validate_edges(&self) -> Result<(), AttributeGraphError> {
+        if let Some(key) = &self.rejected_edge {
+            return Err(AttributeGraphError::EdgeCapacityExceeded { key: key.clone(), capacity: MAX_EDGE_COUNT });
+        }
+        validate_edges(&self.edges())
+    }
+}
+
+impl AttributeGradient for SimpleAttributeGraph2 {
+    fn node_count(&self) -> usize {
+        MAX_NODE_COUNT
+    }
+
+    fn read_recorder(&self) -> &ReadRecorder {
+        &self.reads
+    }
+
+    fn edge_backward(&self, edge: usize, grad: f64) -> (f64, f64) {
+        let edge = &self.edges[edge];
+        (edge.bwd)(grad, self.get_from_value(edge.from1), self.get_from_value(edge.from2))
+    }
+
+    fn node_value(&self, index: usize) -> f64 {
+        self.my_get_value(index)
+    }
+
+    fn shift_node_value(&self, index: usize, delta: f64) {
+        self.attributes.borrow_mut()[index].value_self += delta;
+    }
 }
 
 impl SimpleAttributeGraph2 {
+    // value set directly on the node, without the values from edges
+    pub fn get_value_self(&self, name: AttributeName) -> f64 {
+        self.attributes.borrow()[name as usize].value_self
+    }
+
+    // pulls values lazily through the edges, for graphs passing `validate_edges` this gives
+    // the same values as evaluating the edges in `edge_order`
     fn my_get_value(&self, index: usize) -> f64 {
         // println!("get value: {}", index);
         let data = self.attributes.as_ptr();
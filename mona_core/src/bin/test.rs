@@ -66,14 +66,15 @@ impl ValueFunction {
             &self.character,
             &self.weapon,
             &self.buffs
-        );
+        ).unwrap();
 
         self.tf.target(
             &attribute,
             &self.character,
             &self.weapon,
             artifacts,
-            &Default::default()
+            &Default::default(),
+            &[]
         )
     }
 
@@ -178,14 +179,14 @@ impl Default for ValueFunction {
     }
 }
 
-fn generate_artifacts(set_type: ArtifactSetType, main_stats: &[StatName], upgrader: &ArtifactUpgrader) -> (usize, SmallVec<[Artifact; 5]>) {
+fn generate_artifacts(set_type: ArtifactSetType, main_stats: &[StatName], upgrader: &ArtifactUpgrader) -> Option<(usize, SmallVec<[Artifact; 5]>)> {
     let mut artifacts: SmallVec<[Artifact; 5]> = SmallVec::new();
     let mut tries = 0;
 
     let mut i = 0;
     while i < 5 {
         tries += 1;
-        let mut a = Artifact::random0(ArtifactSetName::BlizzardStrayer, num::FromPrimitive::from_usize(i).unwrap(), main_stats[i]);
+        let mut a = Artifact::random0(ArtifactSetName::BlizzardStrayer, num::FromPrimitive::from_usize(i).unwrap(), main_stats[i])?;
         if upgrader.upgrade(&mut a) {
             artifacts.push(a);
             i += 1;
@@ -217,7 +218,7 @@ fn generate_artifacts(set_type: ArtifactSetType, main_stats: &[StatName], upgrad
         ArtifactSetType::Misc => ()
     }
 
-    (tries, artifacts)
+    Some((tries, artifacts))
 }
 
 #[derive(Debug)]
@@ -298,7 +299,11 @@ fn main() {
                     let mut total_value = 0.0;
                     let mut max_value = 0.0;
                     for _ in 0..sim_count {
-                        let (tries, a) = generate_artifacts(set_type, &[HPFixed, ATKFixed, s1, s2, s3], &upgrader);
+                        // main stats not allowed in their slot give no artifacts
+                        let (tries, a) = match generate_artifacts(set_type, &[HPFixed, ATKFixed, s1, s2, s3], &upgrader) {
+                            Some(x) => x,
+                            None => break
+                        };
                         let a2: SmallVec<[&Artifact; 5]> = a.iter().map(|x| x).collect();
                         let value = vf.call(&a2);
 
@@ -361,7 +366,11 @@ fn main() {
                         let mut total_value = 0.0;
                         let mut max_value = 0.0;
                         for _ in 0..sim_count {
-                            let (tries, a) = generate_artifacts(set_type, &[HPFixed, ATKFixed, s1, s2, s3], &upgrader);
+                            // main stats not allowed in their slot give no artifacts
+                            let (tries, a) = match generate_artifacts(set_type, &[HPFixed, ATKFixed, s1, s2, s3], &upgrader) {
+                                Some(x) => x,
+                                None => break
+                            };
                             let a2: SmallVec<[&Artifact; 5]> = a.iter().map(|x| x).collect();
                             let value = vf.call(&a2);
 
@@ -74,12 +74,13 @@ print(result)
         &WeaponConfig::MistsplitterReforged { emblem_level: 2 },
         &character
     );
-    let attribute = AttributeUtils::create_attribute_from_c_w_bs(&character, &weapon, &Vec::new());
+    let attribute = AttributeUtils::create_attribute_from_c_w_bs(&character, &weapon, &Vec::new()).unwrap();
     let enemy = Enemy::default();
     let context = DamageContext {
         character_common_data: &character.common_data,
         attribute: &attribute,
-        enemy: &enemy
+        enemy: &enemy,
+        other_enemies: &[]
     };
 
     let mut vm = MonaEnv::new(code);
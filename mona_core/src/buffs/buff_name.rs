@@ -93,6 +93,7 @@ pub enum BuffName {
     CandaceQ,
     CandaceTalent2,
     NahidaTalent1,
+    NahidaC2,
     FaruzanQ,
     Mika,
     KavehQ,
@@ -130,6 +131,7 @@ pub enum BuffName {
     ResonanceGeo2,
     ResonanceHydro2,
     ResonanceDendro2,
+    MoonsignAscendantGleam,
 
     // artifact
     Instructor4,
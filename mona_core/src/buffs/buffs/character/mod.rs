@@ -38,7 +38,7 @@ pub use tighnari::BuffTighnariC4;
 pub use dori::BuffDoriC4;
 pub use nilou::{BuffNilouTalent2, BuffNilouTalent1};
 pub use candace::{BuffCandaceQ, BuffCandaceTalent2};
-pub use nahida::BuffNahidaTalent1;
+pub use nahida::{BuffNahidaTalent1, BuffNahidaC2};
 pub use faruzan::BuffFaruzanQ;
 pub use mika::BuffMika;
 pub use kaveh::BuffKavehQ;
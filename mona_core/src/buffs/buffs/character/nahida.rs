@@ -5,6 +5,7 @@ use crate::buffs::buff::BuffMeta;
 use crate::buffs::buff_meta::{BuffFrom, BuffGenre, BuffImage, BuffMetaData};
 use crate::buffs::buff_name::BuffName;
 use crate::character::CharacterName;
+use crate::character::characters::dendro::nahida::NahidaEffect;
 use crate::common::i18n::locale;
 use crate::common::item_config_type::{ItemConfig, ItemConfigType};
 use crate::enemies::Enemy;
@@ -59,3 +60,33 @@ impl BuffMeta for BuffNahidaTalent1 {
         })
     }
 }
+
+pub struct BuffNahidaC2;
+
+impl<A: Attribute> Buff<A> for BuffNahidaC2 {
+    fn change_attribute(&self, attribute: &mut A) {
+        NahidaEffect::set_c2_critical(attribute);
+    }
+}
+
+impl BuffMeta for BuffNahidaC2 {
+    #[cfg(not(target_family = "wasm"))]
+    const META_DATA: BuffMetaData = BuffMetaData {
+        name: BuffName::NahidaC2,
+        name_locale: crate::common::i18n::locale!(
+            zh_cn: "纳西妲-「正等善见之根」",
+            en: "Nahida-「The Root of All Fullness」",
+        ),
+        image: BuffImage::Avatar(CharacterName::Nahida),
+        genre: BuffGenre::Character,
+        description: Some(locale!(
+            zh_cn: "纳西妲命座2：处于所闻遍计的蕴种印状态下的敌人，受到的燃烧、绽放、超绽放、烈绽放反应伤害能够造成暴击，暴击率固定为20%，暴击伤害固定为100%。",
+            en: "Nahida C2: Opponents that are marked by Seeds of Skandha applied by Nahida herself will be affected by the following effects: Burning, Bloom, Hyperbloom, and Burgeon Reaction DMG can score CRIT Hits. CRIT Rate and CRIT DMG are fixed at 20% and 100% respectively."
+        )),
+        from: BuffFrom::Character(CharacterName::Nahida)
+    };
+
+    fn create<A: Attribute>(_b: &BuffConfig) -> Box<dyn Buff<A>> {
+        Box::new(BuffNahidaC2)
+    }
+}
@@ -3,6 +3,7 @@ use crate::buffs::{Buff, BuffConfig};
 use crate::buffs::buff::BuffMeta;
 use crate::buffs::buff_meta::{BuffFrom, BuffGenre, BuffImage, BuffMetaData};
 use crate::buffs::buff_name::BuffName;
+use crate::common::Element;
 use crate::common::item_config_type::{ItemConfig, ItemConfigType};
 use crate::enemies::Enemy;
 
@@ -242,3 +243,70 @@ impl BuffMeta for BuffResonanceDendro2 {
         })
     }
 }
+
+pub struct BuffMoonsignAscendantGleam {
+    pub element: Element,
+    pub value: f64,
+}
+
+impl<A: Attribute> Buff<A> for BuffMoonsignAscendantGleam {
+    fn change_attribute(&self, attribute: &mut A) {
+        let bonus = match self.element {
+            Element::Pyro | Element::Electro | Element::Cryo => self.value / 100.0 * 0.009,
+            Element::Hydro => self.value / 1000.0 * 0.006,
+            Element::Geo => self.value / 100.0 * 0.01,
+            Element::Anemo | Element::Dendro => self.value / 100.0 * 0.0225,
+            Element::Physical => 0.0,
+        };
+        attribute.set_value_by(AttributeName::LunarBaseDmgBonus, "月兆·满辉", bonus.min(0.36));
+    }
+}
+
+impl BuffMeta for BuffMoonsignAscendantGleam {
+    #[cfg(not(target_family = "wasm"))]
+    const META_DATA: BuffMetaData = BuffMetaData {
+        name: BuffName::MoonsignAscendantGleam,
+        name_locale: crate::common::i18n::locale!(
+            zh_cn: "月兆·满辉",
+            en: "Moonsign: Ascendant Gleam",
+        ),
+        image: BuffImage::Misc("electro"),
+        genre: BuffGenre::Resonance,
+        description: Some(crate::common::i18n::locale!(
+            zh_cn: "队伍中存在两名及以上月兆角色时，非月兆角色施放元素战技或元素爆发后，根据该角色的元素类型提升队伍中角色的月曜反应基础伤害：火、雷、冰元素角色每100点攻击力提升0.9%；水元素角色每1000点生命值上限提升0.6%；岩元素角色每100点防御力提升1%；风、草元素角色每100点元素精通提升2.25%。至多提升36%。",
+            en: "With two or more Moonsign characters in the party, after a non-Moonsign character uses an Elemental Skill or Burst, party members' Lunar Reaction Base DMG is increased based on that character's element: every 100 ATK for Pyro, Electro and Cryo (0.9%), every 1,000 Max HP for Hydro (0.6%), every 100 DEF for Geo (1%), every 100 EM for Anemo and Dendro (2.25%). Max 36%.",
+        )),
+        from: BuffFrom::Resonance,
+    };
+
+    #[cfg(not(target_family = "wasm"))]
+    const CONFIG: Option<&'static [ItemConfig]> = Some(&[
+        ItemConfig {
+            name: "element",
+            title: crate::common::i18n::locale!(
+                zh_cn: "非月兆角色元素",
+                en: "Non-Moonsign Character Element",
+            ),
+            config: ItemConfigType::Element8 { default: Element::Pyro }
+        },
+        ItemConfig {
+            name: "value",
+            title: crate::common::i18n::locale!(
+                zh_cn: "对应属性值",
+                en: "Corresponding Stat",
+            ),
+            config: ItemConfigType::FloatInput { default: 2000.0 }
+        }
+    ]);
+
+    fn create<A: Attribute>(b: &BuffConfig) -> Box<dyn Buff<A>> {
+        let (element, value) = match *b {
+            BuffConfig::MoonsignAscendantGleam { element, value } => (element, value),
+            _ => (Element::Pyro, 0.0)
+        };
+
+        Box::new(BuffMoonsignAscendantGleam {
+            element, value
+        })
+    }
+}
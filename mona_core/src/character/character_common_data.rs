@@ -5,6 +5,9 @@ use crate::attribute::{AttributeName, Attribute};
 use crate::common::ChangeAttribute;
 use super::characters::get_static_data;
 
+// 0-based talent levels, 10 without constellations and 15 at most
+const MAX_BASE_SKILL: usize = 9;
+const MAX_SKILL: usize = 14;
 
 pub struct CharacterCommonData {
     pub name: CharacterName,
@@ -16,6 +19,11 @@ pub struct CharacterCommonData {
     pub base_hp: f64,
     pub has_talent1: bool,
     pub has_talent2: bool,
+    // talent levels (0-based) without constellation bonus, at most 9
+    pub base_skill1: usize,
+    pub base_skill2: usize,
+    pub base_skill3: usize,
+    // effective talent levels used in skill table lookups
     pub skill1: usize,
     pub skill2: usize,
     pub skill3: usize,
@@ -39,6 +47,12 @@ impl CharacterCommonData {
         let base_def = CharacterCommonData::base_value(&data.def, level, ascend);
         let base_hp = CharacterCommonData::base_value(&data.hp, level, ascend);
 
+        // skill levels are base levels without the constellation bonus,
+        // levels including it are converted by the caller, see `CharacterStaticData::base_talent_levels`
+        let base_skills = [skill1.min(MAX_BASE_SKILL), skill2.min(MAX_BASE_SKILL), skill3.min(MAX_BASE_SKILL)];
+        let bonus = data.talent_level_bonus(constellation);
+        let effective = |i: usize| (base_skills[i] + bonus[i]).min(MAX_SKILL);
+
         CharacterCommonData {
             name,
             level, ascend, constellation,
@@ -46,9 +60,12 @@ impl CharacterCommonData {
             static_data: data,
             has_talent1: (level == 20 && ascend) || level > 20,
             has_talent2: (level == 60 && ascend) || level > 60,
-            skill1,
-            skill2,
-            skill3,
+            base_skill1: base_skills[0],
+            base_skill2: base_skills[1],
+            base_skill3: base_skills[2],
+            skill1: effective(0),
+            skill2: effective(1),
+            skill3: effective(2),
         }
     }
 
@@ -73,6 +90,10 @@ impl CharacterCommonData {
     pub fn get_3_skill(&self) -> (usize, usize, usize) {
         (self.skill1, self.skill2, self.skill3)
     }
+
+    pub fn get_3_base_skill(&self) -> (usize, usize, usize) {
+        (self.base_skill1, self.base_skill2, self.base_skill3)
+    }
 }
 
 impl<T: Attribute> ChangeAttribute<T> for CharacterCommonData {
@@ -3,6 +3,24 @@ use crate::common::{Element, WeaponType};
 use crate::common::i18n::I18nLocale;
 use super::character_sub_stat::CharacterSubStatFamily;
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CharacterTalent {
+    NormalAttack,
+    ElementalSkill,
+    ElementalBurst,
+}
+
+impl CharacterTalent {
+    // index of the talent in skill1, skill2, skill3
+    pub fn index(&self) -> usize {
+        match *self {
+            CharacterTalent::NormalAttack => 0,
+            CharacterTalent::ElementalSkill => 1,
+            CharacterTalent::ElementalBurst => 2,
+        }
+    }
+}
+
 pub struct CharacterStaticData {
     pub name: CharacterName,
     pub internal_name: &'static str,
@@ -18,4 +36,31 @@ pub struct CharacterStaticData {
     pub skill_name1: I18nLocale,
     pub skill_name2: I18nLocale,
     pub skill_name3: I18nLocale,
+    // talents boosted by 3 levels at C3 and C5
+    pub c3_talent: CharacterTalent,
+    pub c5_talent: CharacterTalent,
+}
+
+impl CharacterStaticData {
+    /// extra levels of the three talents at the given constellation
+    pub fn talent_level_bonus(&self, constellation: i32) -> [usize; 3] {
+        let mut bonus = [0; 3];
+        if constellation >= 3 {
+            bonus[self.c3_talent.index()] += 3;
+        }
+        if constellation >= 5 {
+            bonus[self.c5_talent.index()] += 3;
+        }
+        bonus
+    }
+
+    /// talent levels without the constellation bonus from levels including it, e.g. as shown in game
+    pub fn base_talent_levels(&self, constellation: i32, levels: [usize; 3]) -> [usize; 3] {
+        let bonus = self.talent_level_bonus(constellation);
+        [
+            levels[0].saturating_sub(bonus[0]),
+            levels[1].saturating_sub(bonus[1]),
+            levels[2].saturating_sub(bonus[2]),
+        ]
+    }
 }
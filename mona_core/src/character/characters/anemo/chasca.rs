@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -141,6 +141,8 @@ impl CharacterTrait for Chasca {
             zh_cn: "索魂命袭",
             en: "Soul Reaper's Fatal Round"
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = ChascaSkillType;
     const SKILL: Self::SkillType = CHASCA_SKILL;
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -94,7 +94,7 @@ pub struct FaruzanEffect {
 impl<A: Attribute> ChangeAttribute<A> for FaruzanEffect {
     fn change_attribute(&self, attribute: &mut A) {
         let bonus = FARUZAN_SKILL.q_bonus[self.q_level];
-        attribute.set_value_by(AttributeName::BonusAnemo, "珐露珊Q技能加成", bonus * self.q_ratio);
+        attribute.set_combat_value_by(AttributeName::BonusAnemo, "珐露珊Q技能加成", bonus * self.q_ratio);
     }
 }
 
@@ -123,6 +123,8 @@ impl CharacterTrait for Faruzan {
             zh_cn: "抟风秘道",
             en: "The Wind’s Secret Ways",
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
         name_locale: locale!(
             zh_cn: "珐露珊",
             en: "Faruzan",
@@ -2,7 +2,7 @@ use num_derive::FromPrimitive;
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
 use crate::common::{ChangeAttribute, Element, SkillType, WeaponType};
@@ -79,6 +79,8 @@ pub const KAEDEHARA_KAZUHA_STATIC_DATA: CharacterStaticData = CharacterStaticDat
         zh_cn: "万叶之一刀",
         en: "Kazuha Slash",
     ),
+    c3_talent: CharacterTalent::ElementalSkill,
+    c5_talent: CharacterTalent::ElementalBurst,
     name_locale: locale!(
         zh_cn: "枫原万叶",
         en: "Kaedehara Kazuha",
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::characters::anemo::chasca::ChascaDamageEnum;
@@ -125,6 +125,8 @@ impl CharacterTrait for Lanyan {
             zh_cn: "鹍弦踏月出",
             en: "Lustrous Moonrise"
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = LanyanSkillType;
     const SKILL: Self::SkillType = LANYAN_SKILL;
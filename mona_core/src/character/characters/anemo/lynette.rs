@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -121,7 +121,7 @@ impl<A: Attribute> ChangeAttribute<A> for LynetteEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.has_talent1 {
             let rate_base: f64 = 0.04 * self.talent1_count as f64 + 0.04;
-            attribute.add_atk_percentage("天赋1「巧施协同」", rate_base * self.talent1_rate);
+            attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("天赋1「巧施协同」", rate_base * self.talent1_rate));
         }
 
         if self.has_talent2 {
@@ -158,7 +158,9 @@ impl CharacterTrait for Lynette {
         skill_name3: locale!(
             zh_cn: "魔术·运变惊奇",
             en: "Magic Trick: Astonishing Shift",
-        )
+        ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst
     };
     type SkillType = LynetteSkillType;
     const SKILL: Self::SkillType = LYNETTE_SKILL;
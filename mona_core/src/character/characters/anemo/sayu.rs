@@ -2,7 +2,7 @@ use num_derive::FromPrimitive;
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
 use crate::common::{ChangeAttribute, Element, SkillType, WeaponType};
@@ -89,6 +89,8 @@ pub const SAYU_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "呜呼流·影貉缭乱",
         en: "Yoohoo Art: Mujina Flurry",
     ),
+    c3_talent: CharacterTalent::ElementalBurst,
+    c5_talent: CharacterTalent::ElementalSkill,
     name_locale: locale!(
         zh_cn: "早柚",
         en: "Sayu",
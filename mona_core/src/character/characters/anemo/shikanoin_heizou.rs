@@ -1,6 +1,6 @@
 use crate::attribute::Attribute;
 use crate::character::character_common_data::CharacterCommonData;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
@@ -139,6 +139,8 @@ impl CharacterTrait for ShikanoinHeizou {
             zh_cn: "聚风蹴",
             en: "Windmuster Kick",
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
         name_locale: locale!(
             zh_cn: "鹿野院平藏",
             en: "Shikanoin Heizou",
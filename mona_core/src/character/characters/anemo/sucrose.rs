@@ -2,7 +2,7 @@ use num_derive::FromPrimitive;
 use crate::attribute::Attribute;
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
 use crate::common::{ChangeAttribute, Element, SkillType, StatName, WeaponType};
@@ -69,6 +69,8 @@ pub const SUCROSE_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "禁·风灵作成·柒伍同构贰型",
         en: "Forbidden Creation - Isomer 75 / Type II",
     ),
+    c3_talent: CharacterTalent::ElementalSkill,
+    c5_talent: CharacterTalent::ElementalBurst,
     name_locale: locale!(
         zh_cn: "砂糖",
         en: "Sucrose",
@@ -2,7 +2,7 @@ use num_derive::FromPrimitive;
 use crate::attribute::Attribute;
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
 use crate::common::{ChangeAttribute, Element, SkillType, WeaponType};
@@ -81,6 +81,8 @@ pub const VENTI_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "风神之诗",
         en: "Wind's Grand Ode",
     ),
+    c3_talent: CharacterTalent::ElementalBurst,
+    c5_talent: CharacterTalent::ElementalSkill,
     name_locale: locale!(
         zh_cn: "温迪",
         en: "Venti",
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -85,10 +85,10 @@ impl<A: Attribute> ChangeAttribute<A> for WandererEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.talent1 {
             if self.e_pyro {
-                attribute.add_atk_percentage("天赋「拾玉得花」染火加成", 0.3);
+                attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("天赋「拾玉得花」染火加成", 0.3));
             }
             if self.e_cryo {
-                attribute.set_value_by(AttributeName::CriticalBase, "天赋「拾玉得花」染冰加成", 0.2);
+                attribute.set_combat_value_by(AttributeName::CriticalBase, "天赋「拾玉得花」染冰加成", 0.2);
             }
         }
     }
@@ -119,6 +119,8 @@ impl CharacterTrait for Wanderer {
             zh_cn: "狂言·式乐五番",
             en: "Kyougen: Shikiraku Gobandate",
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
         name_locale: locale!(
             zh_cn: "流浪者",
             en: "Wanderer",
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -126,14 +126,20 @@ impl<A: Attribute> ChangeAttribute<A> for XianyunEffect {
             AttributeName::ATK,
             AttributeName::ExtraDmgPlungingAttackLowHigh,
             Box::new(move |atk, _| (9000.0_f64 * factor).min(atk * 2.0 * rate * factor)),
-            Box::new(|_x, _y, _z| (0.0, 0.0)),
+            Box::new(move |grad, atk, _| {
+                if atk * 2.0 * rate < 9000.0 {
+                    (grad * 2.0 * rate * factor, 0.0)
+                } else {
+                    (0.0, 0.0)
+                }
+            }),
             "天赋「细想应是洞中仙」"
         );
 
         // c2 atk bonus
         if self.constellation >= 2 {
             let bonus_atk = if self.butianti_count > 0 { 0.2 } else { 0.0 };
-            attribute.add_atk_percentage("C2「鹤唳远人间」", bonus_atk);
+            attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("C2「鹤唳远人间」", bonus_atk));
         }
 
         // c6 bonus
@@ -177,7 +183,9 @@ impl CharacterTrait for Xianyun {
         skill_name3: locale!(
             zh_cn: "暮集竹星",
             en: "Stars Gather at Dusk"
-        )
+        ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst
     };
     type SkillType = XianyunSkillType;
     const SKILL: Self::SkillType = XIANYUN_SKILL;
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::characters::arlecchino::ArlecchinoDamageEnum;
@@ -59,7 +59,7 @@ struct YumemizukiMizukiEffect {
 impl<A: Attribute> ChangeAttribute<A> for YumemizukiMizukiEffect {
     fn change_attribute(&self, attribute: &mut A) {
         let em_bonus = self.talent2_rate * 100.0;
-        attribute.set_value_by(AttributeName::ElementalMastery, "天赋「昼想夜梦」", em_bonus);
+        attribute.set_combat_value_by(AttributeName::ElementalMastery, "天赋「昼想夜梦」", em_bonus);
     }
 }
 
@@ -122,6 +122,8 @@ impl CharacterTrait for YumemizukiMizuki {
             zh_cn: "安乐秘汤疗法",
             en: "Anraku Secret Spring Therapy"
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = YumemizukiMizukiSkillType;
     const SKILL: Self::SkillType = YUMEMIZUKI_MIZUKI_SKILL;
@@ -1,7 +1,7 @@
 use num_derive::FromPrimitive;
 use crate::attribute::Attribute;
 use crate::character::character_common_data::CharacterCommonData;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::common::{ChangeAttribute, Element, SkillType, StatName, WeaponType};
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::skill_config::CharacterSkillConfig;
@@ -83,6 +83,8 @@ pub const ALOY_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "曙光预言",
         en: "Prophecies of Dawn",
     ),
+    c3_talent: CharacterTalent::ElementalSkill,
+    c5_talent: CharacterTalent::ElementalBurst,
     name_locale: locale!(
         zh_cn: "埃洛伊",
         en: "Aloy",
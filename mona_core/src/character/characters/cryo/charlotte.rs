@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -107,9 +107,9 @@ pub struct CharlotteEffect {
 
 impl<A: Attribute> ChangeAttribute<A> for CharlotteEffect {
     fn change_attribute(&self, attribute: &mut A) {
-        attribute.set_value_by(AttributeName::HealingBonus, "天赋「多样性调查」", 0.05 * (self.talent2_fontaine_count as f64));
-        attribute.set_value_by(AttributeName::BonusCryo, "天赋「多样性调查」", 0.05 * (self.talent2_non_fontaine_count as f64));
-        attribute.add_atk_percentage("C2「以求真为职守」", (0.1 * self.c2_count as f64) * self.c2_rate);
+        attribute.set_combat_value_by(AttributeName::HealingBonus, "天赋「多样性调查」", 0.05 * (self.talent2_fontaine_count as f64));
+        attribute.set_combat_value_by(AttributeName::BonusCryo, "天赋「多样性调查」", 0.05 * (self.talent2_non_fontaine_count as f64));
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("C2「以求真为职守」", (0.1 * self.c2_count as f64) * self.c2_rate));
     }
 }
 
@@ -141,7 +141,9 @@ impl CharacterTrait for Charlotte {
         skill_name3: locale!(
             zh_cn: "定格·全方位确证",
             en: "Still Photo: Comprehensive Confirmation"
-        )
+        ),
+        c3_talent: CharacterTalent::ElementalBurst,
+        c5_talent: CharacterTalent::ElementalSkill
     };
     type SkillType = CharlotteSkillType;
     const SKILL: Self::SkillType = CHARLOTTE_SKILL;
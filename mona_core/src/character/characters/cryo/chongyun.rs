@@ -2,7 +2,7 @@ use num_derive::FromPrimitive;
 use crate::attribute::Attribute;
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
 use crate::common::{ChangeAttribute, Element, SkillType, WeaponType};
@@ -68,6 +68,8 @@ pub const CHONGYUN_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "灵刃·云开星落",
         en: "Spirit Blade: Cloud-Parting Star",
     ),
+    c3_talent: CharacterTalent::ElementalBurst,
+    c5_talent: CharacterTalent::ElementalSkill,
     name_locale: locale!(
         zh_cn: "重云",
         en: "Chongyun",
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::characters::arlecchino::ArlecchinoDamageEnum;
@@ -140,6 +140,8 @@ impl CharacterTrait for Citlali {
             zh_cn: "诸曜饬令",
             en: "Edict of Entwined Splendor"
         ),
+        c3_talent: CharacterTalent::ElementalBurst,
+        c5_talent: CharacterTalent::ElementalSkill,
     };
     type SkillType = CitlaliSkillType;
     const SKILL: Self::SkillType = CITLALI_SKILL;
@@ -2,7 +2,7 @@ use num_derive::FromPrimitive;
 use crate::attribute::Attribute;
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
 use crate::common::{ChangeAttribute, Element, SkillType, StatName, WeaponType};
@@ -80,6 +80,8 @@ pub const DIONA_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "最烈特调",
         en: "Signature Mix",
     ),
+    c3_talent: CharacterTalent::ElementalBurst,
+    c5_talent: CharacterTalent::ElementalSkill,
     name_locale: locale!(
         zh_cn: "迪奥娜",
         en: "Diona",
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -133,11 +133,11 @@ impl<A: Attribute> ChangeAttribute<A> for FreminetEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.constellation >= 4 {
             let rate = self.c4_stack * 0.09;
-            attribute.add_atk_percentage("命座4「雪月与芦笛之舞」", rate);
+            attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("命座4「雪月与芦笛之舞」", rate));
         }
         if self.constellation >= 6 {
             let rate = self.c6_stack * 0.12;
-            attribute.set_value_by(AttributeName::CriticalDamageBase, "命座6「梦晓与决意之刻」", rate);
+            attribute.set_combat_value_by(AttributeName::CriticalDamageBase, "命座6「梦晓与决意之刻」", rate);
         }
     }
 }
@@ -170,7 +170,9 @@ impl CharacterTrait for Freminet {
         skill_name3: locale!(
             zh_cn: "猎影潜袭",
             en: "Shadowhunter's Ambush"
-        )
+        ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = FreminetSkillType;
     const SKILL: Self::SkillType = FREMINET_SKILL;
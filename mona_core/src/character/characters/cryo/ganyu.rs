@@ -3,7 +3,7 @@ use strum::EnumCount;
 use strum_macros::{EnumCount as EnumCountMacro, EnumString};
 
 use crate::attribute::{Attribute, AttributeName};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::skill_config::CharacterSkillConfig;
@@ -80,6 +80,8 @@ pub const GANYU_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "降众天华",
         en: "Celestial Shower",
     ),
+    c3_talent: CharacterTalent::ElementalBurst,
+    c5_talent: CharacterTalent::ElementalSkill,
     name_locale: locale!(
         zh_cn: "甘雨",
         en: "Ganyu",
@@ -94,7 +96,7 @@ pub struct GanyuEffect {
 impl<T: Attribute> ChangeAttribute<T> for GanyuEffect {
     fn change_attribute(&self, attribute: &mut T) {
         // attribute.set_value_by(AttributeName::CriticalBase, "甘雨天赋：唯此一心", 0.2 * self.talent1_rate);
-        attribute.set_value_by(AttributeName::BonusCryo, "甘雨天赋：天地交泰", 0.2 * self.talent2_rate);
+        attribute.set_combat_value_by(AttributeName::BonusCryo, "甘雨天赋：天地交泰", 0.2 * self.talent2_rate);
     }
 }
 
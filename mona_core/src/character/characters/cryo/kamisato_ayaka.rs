@@ -2,7 +2,7 @@ use num_derive::FromPrimitive;
 use crate::attribute::{Attribute, AttributeName};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
 use crate::common::{ChangeAttribute, Element, SkillType, WeaponType};
@@ -71,6 +71,8 @@ pub const KAMISATO_AYAKA_STATIC_DATA: CharacterStaticData = CharacterStaticData
         zh_cn: "神里流·霜灭",
         en: "Kamisato Art: Soumetsu",
     ),
+    c3_talent: CharacterTalent::ElementalBurst,
+    c5_talent: CharacterTalent::ElementalSkill,
     name_locale: locale!(
         zh_cn: "神里绫华",
         en: "Kamisato Ayaka",
@@ -106,7 +108,7 @@ impl<A: Attribute> ChangeAttribute<A> for KamisatoAyakaEffect {
             attribute.set_value_by(AttributeName::BonusChargedAttack, "神里绫华天赋：天罪国罪镇词", 0.3 * self.rate1);
         }
         if self.has_talent2 {
-            attribute.set_value_by(AttributeName::BonusCryo, "神里绫华天赋：寒天宣命祝词", 0.18 * self.rate2);
+            attribute.set_combat_value_by(AttributeName::BonusCryo, "神里绫华天赋：寒天宣命祝词", 0.18 * self.rate2);
         }
     }
 }
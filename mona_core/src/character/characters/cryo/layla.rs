@@ -1,6 +1,6 @@
 use crate::attribute::{Attribute, AttributeName};
 use crate::character::character_common_data::CharacterCommonData;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterTrait, CharacterSkillMapItem};
@@ -110,6 +110,8 @@ impl CharacterTrait for Layla {
             zh_cn: "星流摇床之梦",
             en: "Dream of the Star-Stream Shaker",
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
         name_locale: locale!(
             zh_cn: "莱依拉",
             en: "Layla",
@@ -3,7 +3,7 @@ use strum::EnumCount;
 use strum_macros::{EnumCount as EnumCountMacro, EnumString};
 
 use crate::attribute::{Attribute, AttributeName};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::skill_config::CharacterSkillConfig;
@@ -127,6 +127,8 @@ pub const MIKA_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "苍翎的颂愿",
         en: "Skyfeather Song",
     ),
+    c3_talent: CharacterTalent::ElementalSkill,
+    c5_talent: CharacterTalent::ElementalBurst,
     name_locale: locale!(
         zh_cn: "米卡",
         en: "Mika",
@@ -3,7 +3,7 @@ use strum::EnumCount;
 use strum_macros::{EnumCount as EnumCountMacro, EnumString};
 
 use crate::attribute::{Attribute, AttributeName};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::skill_config::CharacterSkillConfig;
@@ -78,6 +78,8 @@ pub const ROSARIA_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "终命的圣礼",
         en: "Rites of Termination",
     ),
+    c3_talent: CharacterTalent::ElementalSkill,
+    c5_talent: CharacterTalent::ElementalBurst,
     name_locale: locale!(
         zh_cn: "罗莎莉亚",
         en: "Rosaria",
@@ -106,7 +108,7 @@ impl RosariaEffect {
 impl<T: Attribute> ChangeAttribute<T> for RosariaEffect {
     fn change_attribute(&self, attribute: &mut T) {
         if self.has_talent1 && self.e_from_behind {
-            attribute.set_value_by(AttributeName::CriticalBase, "罗莎莉亚天赋：聆听忏悔的幽影", 0.12);
+            attribute.set_combat_value_by(AttributeName::CriticalBase, "罗莎莉亚天赋：聆听忏悔的幽影", 0.12);
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -84,7 +84,7 @@ impl<A: Attribute> ChangeAttribute<A> for WriothesleyEffect {
     fn change_attribute(&self, attribute: &mut A) {
         let value = 0.06 * self.talent2_stack;
         if self.has_talent2 {
-            attribute.add_atk_percentage("天赋「罪业终有报偿之时」", value);
+            attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("天赋「罪业终有报偿之时」", value));
             attribute.set_value_to(AttributeName::USER1, "", self.talent2_stack);
         }
     }
@@ -118,7 +118,9 @@ impl CharacterTrait for Wriothesley {
         skill_name3: locale!(
             zh_cn: "黑金狼噬",
             en: "Darkgold Wolfbite"
-        )
+        ),
+        c3_talent: CharacterTalent::NormalAttack,
+        c5_talent: CharacterTalent::ElementalBurst
     };
     type SkillType = WriothesleySkillType;
     const SKILL: Self::SkillType = WRIOTHESLEY_SKILL;
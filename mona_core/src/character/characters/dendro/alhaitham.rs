@@ -5,7 +5,7 @@ use crate::character::traits::CharacterTrait;
 use crate::common::{StatName, WeaponType, Element, SkillType};
 use crate::{character::macros::damage_enum, common::ChangeAttribute, attribute::Attribute};
 use crate::attribute::AttributeName;
-use crate::character::character_static_data::CharacterStaticData;
+use crate::character::character_static_data::{CharacterStaticData, CharacterTalent};
 use crate::common::item_config_type::{ItemConfig, ItemConfigType};
 use crate::attribute::{AttributeCommon};
 use crate::character::traits::{CharacterSkillMapItem, CharacterSkillMap};
@@ -107,17 +107,17 @@ impl<A: Attribute> ChangeAttribute<A> for AlhaithamEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.c >= 2 {
             let em = self.c2_stack * 50.0;
-            attribute.set_value_by(AttributeName::ElementalMastery, "二命「辩章」", em);
+            attribute.set_combat_value_by(AttributeName::ElementalMastery, "二命「辩章」", em);
         }
         if self.c >= 4 {
             let value = self.c4_stack * 0.1;
-            attribute.set_value_by(AttributeName::BonusDendro, "四命「义贯」", value);
+            attribute.set_combat_value_by(AttributeName::BonusDendro, "四命「义贯」", value);
         }
         if self.c >= 6 {
             let crit = self.c6_rate * 0.1;
             let cd = self.c6_rate * 0.7;
-            attribute.set_value_by(AttributeName::CriticalBase, "六命「正理」", crit);
-            attribute.set_value_by(AttributeName::CriticalDamageBase, "六命「正理」", cd);
+            attribute.set_combat_value_by(AttributeName::CriticalBase, "六命「正理」", crit);
+            attribute.set_combat_value_by(AttributeName::CriticalDamageBase, "六命「正理」", cd);
         }
     }
 }
@@ -147,6 +147,8 @@ impl CharacterTrait for Alhaitham {
             zh_cn: "殊境·显象缚结",
             en: "Particular Field: Fetters of Phenomena",
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
         name_locale: locale!(
             zh_cn: "艾尔海森",
             en: "Alhaitham",
@@ -5,7 +5,7 @@ use crate::character::traits::CharacterTrait;
 use crate::common::{StatName, WeaponType, Element, SkillType};
 use crate::{character::macros::damage_enum, common::ChangeAttribute, attribute::Attribute};
 use crate::attribute::AttributeName;
-use crate::character::character_static_data::CharacterStaticData;
+use crate::character::character_static_data::{CharacterStaticData, CharacterTalent};
 use crate::common::item_config_type::{ItemConfig, ItemConfigType};
 use crate::attribute::{AttributeCommon};
 use crate::character::character_common_data::CharacterCommonData;
@@ -98,9 +98,9 @@ impl<A: Attribute> ChangeAttribute<A> for BaizhuEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.has_talent1 {
             if self.hp_below_50 {
-                attribute.set_value_by(AttributeName::HealingBonus, "白术天赋1「五运终天」", 0.2);
+                attribute.set_combat_value_by(AttributeName::HealingBonus, "白术天赋1「五运终天」", 0.2);
             } else {
-                attribute.set_value_by(AttributeName::BonusDendro, "白术天赋1「五运终天」", 0.25);
+                attribute.set_combat_value_by(AttributeName::BonusDendro, "白术天赋1「五运终天」", 0.25);
             }
         }
     }
@@ -123,6 +123,8 @@ impl CharacterTrait for Baizhu {
         skill_name1: locale!(zh_cn: "普通攻击·金匮针解", en: "Normal Attack: The Classics of Acupuncture"),
         skill_name2: locale!(zh_cn: "太素诊要", en: "Universal Diagnosis"),
         skill_name3: locale!(zh_cn: "愈气全形论", en: "Holistic Revivification"),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = BaizhuSkillType;
     const SKILL: Self::SkillType = BAIZHU_SKILL;
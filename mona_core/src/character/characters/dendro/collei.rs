@@ -3,7 +3,7 @@ use num_derive::FromPrimitive;
 use strum::EnumCount;
 use crate::attribute::{Attribute, AttributeName};
 use crate::character::character_common_data::CharacterCommonData;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
@@ -57,7 +57,7 @@ pub struct ColleiEffect {
 impl<A: Attribute> ChangeAttribute<A> for ColleiEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.c1 {
-            attribute.set_value_by(AttributeName::Recharge, "柯莱1命", 0.2);
+            attribute.set_combat_value_by(AttributeName::Recharge, "柯莱1命", 0.2);
         }
     }
 }
@@ -132,6 +132,8 @@ impl CharacterTrait for Collei {
             zh_cn: "猫猫秘宝",
             en: "Trump-Card Kitty",
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
         name_locale: locale!(
             zh_cn: "柯莱",
             en: "Collei",
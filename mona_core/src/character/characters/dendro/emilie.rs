@@ -1,6 +1,6 @@
 use crate::{attribute::Attribute, character::macros::damage_enum, common::ChangeAttribute, attribute::AttributeCommon};
 use crate::attribute::AttributeName;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::characters::kirara::KiraraDamageEnum;
@@ -130,6 +130,8 @@ impl CharacterTrait for Emilie {
             zh_cn: "香氛演绎",
             en: "Aromatic Explication"
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = EmilieSkillType;
     const SKILL: Self::SkillType = EMILIE_SKILL;
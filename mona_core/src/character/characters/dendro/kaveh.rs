@@ -1,6 +1,6 @@
 use crate::{attribute::Attribute, character::macros::damage_enum, common::ChangeAttribute};
 use crate::attribute::AttributeName;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_ratio, skill_map, skill_type};
@@ -101,7 +101,7 @@ impl<A: Attribute> ChangeAttribute<A> for KavehEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.has_talent2 {
             let em = 25.0 * self.talent2_stack;
-            attribute.set_value_by(AttributeName::ElementalMastery, "卡维天赋「工艺家的奇想」", em);
+            attribute.set_combat_value_by(AttributeName::ElementalMastery, "卡维天赋「工艺家的奇想」", em);
         }
         if self.constellation >= 2 {
             let speed = 0.25 * self.c2_rate;
@@ -127,6 +127,8 @@ impl CharacterTrait for Kaveh {
         skill_name1: locale!(zh_cn: "普通攻击·旋规设矩", en: "Normal Attack: Schematic Setup"),
         skill_name2: locale!(zh_cn: "画则巧施", en: "Artistic Ingenuity"),
         skill_name3: locale!(zh_cn: "繁绘隅穹", en: "Painted Dome"),
+        c3_talent: CharacterTalent::ElementalBurst,
+        c5_talent: CharacterTalent::ElementalSkill,
     };
     type SkillType = KavehSkillType;
     const SKILL: Self::SkillType = KAVEH_SKILL;
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::characters::mualani::MualaniDamageEnum;
@@ -122,6 +122,8 @@ impl CharacterTrait for Kinich {
             zh_cn: "向伟大圣龙致意",
             en: "Hail to the Almighty Dragonlord"
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = KinichSkillType;
     const SKILL: Self::SkillType = KINICH_SKILL;
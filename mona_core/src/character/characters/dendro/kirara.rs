@@ -1,6 +1,6 @@
 use crate::{attribute::Attribute, character::macros::damage_enum, common::ChangeAttribute, attribute::AttributeCommon};
 use crate::attribute::AttributeName;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_ratio, skill_map, skill_type};
@@ -123,7 +123,9 @@ impl CharacterTrait for Kirara {
         star: 4,
         skill_name1: locale!(zh_cn: "普通攻击·箱纸切削术", en: "Normal Attack: Boxcutter"),
         skill_name2: locale!(zh_cn: "呜喵町飞足", en: "Meow-teor Kick"),
-        skill_name3: locale!(zh_cn: "秘法·惊喜特派", en: "Secret Art: Surprise Dispatch")
+        skill_name3: locale!(zh_cn: "秘法·惊喜特派", en: "Secret Art: Surprise Dispatch"),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = KiraraSkillType;
     const SKILL: Self::SkillType = KIRARA_SKILL;
@@ -1,6 +1,6 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
 use crate::character::character_common_data::CharacterCommonData;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterTrait, CharacterSkillMapItem};
@@ -80,15 +80,32 @@ impl NahidaDamageEnum {
 }
 
 pub struct NahidaEffect {
+    pub c2: bool,
     pub c4: bool,
     pub e_count: usize,
 }
 
+impl NahidaEffect {
+    /// C2 fixes the crit of these reactions instead of adding to it, so this and BuffNahidaC2 on Nahida herself do not stack
+    pub fn set_c2_critical<A: Attribute>(attribute: &mut A) {
+        for name in [AttributeName::CriticalBloom, AttributeName::CriticalHyperbloom, AttributeName::CriticalBurgeon, AttributeName::CriticalBurning] {
+            attribute.set_value_to(name, "C2: 正等善见之根", 0.2);
+        }
+        for name in [AttributeName::CriticalDamageBloom, AttributeName::CriticalDamageHyperbloom, AttributeName::CriticalDamageBurgeon, AttributeName::CriticalDamageBurning] {
+            attribute.set_value_to(name, "C2: 正等善见之根", 1.0);
+        }
+    }
+}
+
 impl<A: Attribute> ChangeAttribute<A> for NahidaEffect {
     fn change_attribute(&self, attribute: &mut A) {
+        // the party-wide part of C2 is BuffNahidaC2
+        if self.c2 {
+            NahidaEffect::set_c2_critical(attribute);
+        }
         if self.c4 {
             let em = 20.0 * self.e_count as f64 + 80.0;
-            attribute.set_value_by(AttributeName::ElementalMastery, "C4: 比量现行之茎", em);
+            attribute.set_combat_value_by(AttributeName::ElementalMastery, "C4: 比量现行之茎", em);
         }
     }
 }
@@ -118,6 +135,8 @@ impl CharacterTrait for Nahida {
             zh_cn: "心景幻成",
             en: "Illusory Heart",
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
         name_locale: locale!(
             zh_cn: "纳西妲",
             en: "Nahida",
@@ -253,6 +272,7 @@ impl CharacterTrait for Nahida {
             _ => 0
         };
         Some(Box::new(NahidaEffect {
+            c2: common_data.constellation >= 2,
             c4: common_data.constellation >= 4,
             e_count
         }))
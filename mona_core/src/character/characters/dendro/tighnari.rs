@@ -4,7 +4,7 @@ use crate::common::{ChangeAttribute, Element, SkillType, StatName, WeaponType};
 // use num_derive::FromPrimitive;
 // use strum::EnumCount;
 use crate::character::character_common_data::CharacterCommonData;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
@@ -72,11 +72,11 @@ impl<A: Attribute> ChangeAttribute<A> for TighnariEffect {
         }
 
         if self.has_talent1 {
-            attribute.set_value_by(AttributeName::ElementalMastery, "提纳里天赋", 50.0 * self.talent1_ratio);
+            attribute.set_combat_value_by(AttributeName::ElementalMastery, "提纳里天赋", 50.0 * self.talent1_ratio);
         }
 
         if self.c2 {
-            attribute.set_value_by(AttributeName::BonusDendro, "提纳里2命", 0.2 * self.c2_ratio);
+            attribute.set_combat_value_by(AttributeName::BonusDendro, "提纳里2命", 0.2 * self.c2_ratio);
         }
     }
 }
@@ -149,6 +149,8 @@ impl CharacterTrait for Tighnari {
             zh_cn: "造生缠藤箭",
             en: "Fashioner’s Tanglevine Shaft",
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
         name_locale: locale!(
             zh_cn: "提纳里",
             en: "Tighnari",
@@ -1,6 +1,6 @@
 use crate::attribute::{Attribute, AttributeCommon, AttributeName};
 use crate::character::character_common_data::CharacterCommonData;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{skill_type, damage_enum, skill_map, damage_ratio};
 use crate::character::prelude::CharacterTrait;
@@ -106,13 +106,15 @@ impl<A: Attribute> ChangeAttribute<A> for YaoyaoEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.is_c4 {
             let rate = self.c4_rate;
-            attribute.add_edge1(
-                AttributeName::HP,
-                AttributeName::ElementalMastery,
-                Box::new(move |hp, _| 120.0_f64.min(hp * 0.003) * rate),
-                Box::new(|_, _, _| (0.0, 0.0)),
-                "瑶瑶四命「爰爰可亲」",
-            );
+            attribute.with_combat_sources(|attribute| {
+                attribute.add_edge1(
+                    AttributeName::HP,
+                    AttributeName::ElementalMastery,
+                    Box::new(move |hp, _| 120.0_f64.min(hp * 0.003) * rate),
+                    Box::new(move |grad, hp, _| if hp * 0.003 < 120.0 { (grad * 0.003 * rate, 0.0) } else { (0.0, 0.0) }),
+                    "瑶瑶四命「爰爰可亲」",
+                );
+            });
         }
     }
 }
@@ -142,6 +144,8 @@ impl CharacterTrait for Yaoyao {
             zh_cn: "玉颗珊珊月中落",
             en: "Moonjade Descent",
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
         name_locale: locale!(
             zh_cn: "瑶瑶",
             en: "Yaoyao",
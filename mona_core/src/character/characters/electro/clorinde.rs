@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -117,24 +117,36 @@ impl<A: Attribute> ChangeAttribute<A> for ClorindeEffect {
                 AttributeName::ATK,
                 AttributeName::ExtraDmgNormalAttack,
                 Box::new(move |atk, _| (atk * ratio * talent1_stack).min(max)),
-                Box::new(|_x, _y, _grad| (0.0, 0.0)),
+                Box::new(move |grad, atk, _| {
+                    if atk * ratio * talent1_stack < max {
+                        (grad * ratio * talent1_stack, 0.0)
+                    } else {
+                        (0.0, 0.0)
+                    }
+                }),
                 "天赋1「破夜的明焰」",
             );
             attribute.add_edge1(
                 AttributeName::ATK,
                 AttributeName::ExtraDmgElementalBurst,
                 Box::new(move |atk, _| (atk * ratio * talent1_stack).min(max)),
-                Box::new(|_x, _y, _grad| (0.0, 0.0)),
+                Box::new(move |grad, atk, _| {
+                    if atk * ratio * talent1_stack < max {
+                        (grad * ratio * talent1_stack, 0.0)
+                    } else {
+                        (0.0, 0.0)
+                    }
+                }),
                 "天赋1「破夜的明焰」",
             )
         }
         if self.has_talent2 && self.talent2_stack > 0.0 {
             let bonus = 0.1 * self.talent2_stack;
-            attribute.set_value_by(AttributeName::CriticalBase, "天赋「契令的酬偿」", bonus);
+            attribute.set_combat_value_by(AttributeName::CriticalBase, "天赋「契令的酬偿」", bonus);
         }
         if self.constellation >= 6 && self.c6_rate > 0.0 {
-            attribute.set_value_by(AttributeName::CriticalBase, "C6「为此，勿将希望弃扬」", 0.1 * self.c6_rate);
-            attribute.set_value_by(AttributeName::CriticalDamageBase, "C6「为此，勿将希望弃扬」", 0.7 * self.c6_rate);
+            attribute.set_combat_value_by(AttributeName::CriticalBase, "C6「为此，勿将希望弃扬」", 0.1 * self.c6_rate);
+            attribute.set_combat_value_by(AttributeName::CriticalDamageBase, "C6「为此，勿将希望弃扬」", 0.7 * self.c6_rate);
         }
     }
 }
@@ -168,6 +180,8 @@ impl CharacterTrait for Clorinde {
             zh_cn: "残光将终",
             en: "Last Lightfall",
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = ClorindeSkillType;
     const SKILL: Self::SkillType = CLORINDE_SKILL;
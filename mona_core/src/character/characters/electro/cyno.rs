@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 // use strum_macros::{EnumCount as EnumCountMacro, EnumString};
 // use num_derive::FromPrimitive;
 // use strum::EnumCount;
@@ -54,10 +54,10 @@ pub struct CynoEffect {
 impl<A: Attribute> ChangeAttribute<A> for CynoEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.c2 {
-            attribute.set_value_by(AttributeName::BonusElectro, "赛诺2命：「令仪·引谒归灵」", 0.1 * self.c2_stack);
+            attribute.set_combat_value_by(AttributeName::BonusElectro, "赛诺2命：「令仪·引谒归灵」", 0.1 * self.c2_stack);
         }
         if self.after_q {
-            attribute.set_value_by(AttributeName::ElementalMastery, "「启途誓使」精通加成", 100.0);
+            attribute.set_combat_value_by(AttributeName::ElementalMastery, "「启途誓使」精通加成", 100.0);
         }
     }
 }
@@ -154,6 +154,8 @@ impl CharacterTrait for Cyno {
             zh_cn: "圣仪·煟煌随狼行",
             en: "Sacred Rite: Wolf’s Swiftness",
         ),
+        c3_talent: CharacterTalent::ElementalBurst,
+        c5_talent: CharacterTalent::ElementalSkill,
         name_locale: locale!(
             zh_cn: "赛诺",
             en: "Cyno",
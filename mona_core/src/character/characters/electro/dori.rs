@@ -1,6 +1,6 @@
 use crate::attribute::Attribute;
 use crate::character::character_common_data::CharacterCommonData;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{skill_type, damage_enum, skill_map, damage_ratio};
 use crate::character::skill_config::CharacterSkillConfig;
@@ -118,6 +118,8 @@ impl CharacterTrait for Dori {
             zh_cn: "卡萨扎莱宫的无微不至",
             en: "Alcazarzaray’s Exactitude",
         ),
+        c3_talent: CharacterTalent::ElementalBurst,
+        c5_talent: CharacterTalent::ElementalSkill,
         name_locale: locale!(
             zh_cn: "多莉",
             en: "Dori",
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::characters::clorinde::ClorindeDamageEnum;
@@ -93,7 +93,7 @@ struct IansanEffect {
 impl<A: Attribute> ChangeAttribute<A> for IansanEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.has_talent1 {
-            attribute.add_atk_percentage("天赋「强化抗阻练习」", 0.2 * self.talent1_rate);
+            attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("天赋「强化抗阻练习」", 0.2 * self.talent1_rate));
         }
     }
 }
@@ -127,6 +127,8 @@ impl CharacterTrait for Iansan {
             zh_cn: "力的三原理",
             en: "The Three Principles of Power"
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = IansanSkillType;
     const SKILL: Self::SkillType = IANSAN_SKILL;
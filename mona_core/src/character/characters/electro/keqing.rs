@@ -2,7 +2,7 @@ use num_derive::FromPrimitive;
 use crate::attribute::{Attribute, AttributeName};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
 use crate::common::{ChangeAttribute, Element, SkillType, WeaponType};
@@ -79,6 +79,8 @@ pub const KEQING_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "天街巡游",
         en: "Starward Sword",
     ),
+    c3_talent: CharacterTalent::ElementalBurst,
+    c5_talent: CharacterTalent::ElementalSkill,
     name_locale: locale!(
         zh_cn: "刻晴",
         en: "Keqing",
@@ -107,8 +109,8 @@ impl KeqingEffect {
 impl<A: Attribute> ChangeAttribute<A> for KeqingEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.has_talent2 {
-            attribute.set_value_by(AttributeName::CriticalBase, "刻晴天赋：玉衡之贵", 0.15 * self.rate);
-            attribute.set_value_by(AttributeName::Recharge, "刻晴天赋：玉衡之贵", 0.15 * self.rate);
+            attribute.set_combat_value_by(AttributeName::CriticalBase, "刻晴天赋：玉衡之贵", 0.15 * self.rate);
+            attribute.set_combat_value_by(AttributeName::Recharge, "刻晴天赋：玉衡之贵", 0.15 * self.rate);
         }
     }
 }
@@ -3,7 +3,7 @@ use strum::EnumCount;
 use strum_macros::{EnumCount as EnumCountMacro, EnumString};
 
 use crate::attribute::{Attribute, AttributeCommon, AttributeName};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::skill_config::CharacterSkillConfig;
@@ -67,10 +67,10 @@ pub struct KukiShinobuEffect {
 impl<A: Attribute> ChangeAttribute<A> for KukiShinobuEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.has_talent1 && self.hp_le_50 {
-            attribute.set_value_by(AttributeName::HealingBonus, "天赋：破笼之志", 0.15);
+            attribute.set_combat_value_by(AttributeName::HealingBonus, "天赋：破笼之志", 0.15);
         }
         if self.has_c6 && self.use_c6 {
-            attribute.set_value_by(AttributeName::ElementalMastery, "六命：割舍软弱之心", 150.0);
+            attribute.set_combat_value_by(AttributeName::ElementalMastery, "六命：割舍软弱之心", 150.0);
         }
     }
 }
@@ -162,6 +162,8 @@ impl CharacterTrait for KukiShinobu {
             zh_cn: "御咏鸣神刈山祭",
             en: "Gyoei Narukami Kariyama Rite",
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
         name_locale: locale!(
             zh_cn: "久岐忍",
             en: "Kuki Shinobu",
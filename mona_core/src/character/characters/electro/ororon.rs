@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::characters::electro::sethos::SethosDamageEnum;
@@ -112,6 +112,8 @@ impl CharacterTrait for Ororon {
             zh_cn: "黯声回响",
             en: "Dark Voices Echo"
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = OroronSkillType;
     const SKILL: Self::SkillType = ORORON_SKILL;
@@ -2,7 +2,7 @@ use num_derive::FromPrimitive;
 use crate::attribute::{Attribute, AttributeName};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
 use crate::common::{ChangeAttribute, Element, SkillType, StatName, WeaponType};
@@ -75,6 +75,8 @@ pub const RAZOR_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "雷牙",
         en: "Lightning Fang",
     ),
+    c3_talent: CharacterTalent::ElementalBurst,
+    c5_talent: CharacterTalent::ElementalSkill,
     name_locale: locale!(
         zh_cn: "雷泽",
         en: "Razor",
@@ -104,9 +106,9 @@ impl RazorEffect {
 impl<A: Attribute> ChangeAttribute<A> for RazorEffect {
     fn change_attribute(&self, attribute: &mut A) {
         let recharge_bonus = 0.2 * self.stack;
-        attribute.set_value_by(AttributeName::Recharge, "雷泽：雷之印加成", recharge_bonus);
+        attribute.set_combat_value_by(AttributeName::Recharge, "雷泽：雷之印加成", recharge_bonus);
         if self.has_talent2 {
-            attribute.set_value_by(AttributeName::Recharge, "雷泽天赋：饥饿", self.talent2_ratio * 0.3);
+            attribute.set_combat_value_by(AttributeName::Recharge, "雷泽天赋：饥饿", self.talent2_ratio * 0.3);
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -93,7 +93,7 @@ pub struct SethosEffect {
 impl<A: Attribute> ChangeAttribute<A> for SethosEffect {
     fn change_attribute(&self, attribute: &mut A) {
         let bonus = self.c2_stack * 0.15;
-        attribute.set_value_by(AttributeName::BonusElectro, "C2「寂秘纸草经」", bonus);
+        attribute.set_combat_value_by(AttributeName::BonusElectro, "C2「寂秘纸草经」", bonus);
     }
 }
 
@@ -126,6 +126,8 @@ impl CharacterTrait for Sethos {
             zh_cn: "秘仪·瞑光贯影",
             en: "Secret Rite: Twilight Shadowpiercer"
         ),
+        c3_talent: CharacterTalent::NormalAttack,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = SethosSkillType;
     const SKILL: Self::SkillType = SETHOS_SKILL;
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::characters::clorinde::ClorindeDamageEnum;
@@ -111,7 +111,7 @@ struct VaresaEffect {
 impl<A: Attribute> ChangeAttribute<A> for VaresaEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.has_talent2 {
-            attribute.add_atk_percentage("天赋「英雄，二度归来！」", 0.35 * self.talent2_stack);
+            attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("天赋「英雄，二度归来！」", 0.35 * self.talent2_stack));
         }
 
         if self.has_talent1 {
@@ -152,6 +152,8 @@ impl CharacterTrait for Varesa {
             zh_cn: "闪烈降临！",
             en: "Guardian Vent!"
         ),
+        c3_talent: CharacterTalent::NormalAttack,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = VaresaSkillType;
     const SKILL: Self::SkillType = VARESA_SKILL;
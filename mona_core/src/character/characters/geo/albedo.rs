@@ -2,7 +2,7 @@ use num_derive::FromPrimitive;
 use crate::attribute::Attribute;
 use crate::character::character_common_data::CharacterCommonData;
 use crate::common::{Element, WeaponType, StatName, SkillType, ChangeAttribute};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::characters::geo::albedo::AlbedoDamageEnum::ETransientBlossom;
 use crate::character::skill_config::CharacterSkillConfig;
@@ -75,6 +75,8 @@ const ALBEDO_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "诞生式·大地之潮",
         en: "Rite of Progeniture: Tectonic Tide",
     ),
+    c3_talent: CharacterTalent::ElementalSkill,
+    c5_talent: CharacterTalent::ElementalBurst,
     name_locale: locale!(
         zh_cn: "阿贝多",
         en: "Albedo",
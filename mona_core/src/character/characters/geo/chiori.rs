@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -100,7 +100,7 @@ pub struct ChioriEffect {
 impl<A: Attribute> ChangeAttribute<A> for ChioriEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.has_talent2 && self.talent2 {
-            attribute.set_value_by(AttributeName::BonusGeo, "天赋「锦上添花」", 0.2);
+            attribute.set_combat_value_by(AttributeName::BonusGeo, "天赋「锦上添花」", 0.2);
         }
     }
 }
@@ -134,6 +134,8 @@ impl CharacterTrait for Chiori {
             zh_cn: "二刀之形·比翼",
             en: "Hiyoku: Twin Blades"
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = ChioriSkillType;
     const SKILL: Self::SkillType = CHIORI_SKILL;
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::characters::kachina::KachinaDamageEnum::{Normal1, Normal21, Normal22, Normal3, Normal4};
@@ -93,7 +93,7 @@ pub struct KachinaEffect {
 
 impl<A: Attribute> ChangeAttribute<A> for KachinaEffect {
     fn change_attribute(&self, attribute: &mut A) {
-        attribute.set_value_by(AttributeName::BonusGeo, "天赋「山的回声」", self.talent1_rate * 0.2);
+        attribute.set_combat_value_by(AttributeName::BonusGeo, "天赋「山的回声」", self.talent1_rate * 0.2);
     }
 }
 
@@ -126,6 +126,8 @@ impl CharacterTrait for Kachina {
             zh_cn: "现在，认真时间！",
             en: "Time to Get Serious!"
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = KachinaSkillType;
     const SKILL: Self::SkillType = KACHINA_SKILL;
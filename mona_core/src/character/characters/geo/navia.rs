@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -99,7 +99,7 @@ pub struct NaviaEffect {
 impl<A: Attribute> ChangeAttribute<A> for NaviaEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.has_talent2 {
-            attribute.add_atk_percentage("天赋2「互助关系网」", self.talent2_character_count as f64 * 0.2);
+            attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("天赋2「互助关系网」", self.talent2_character_count as f64 * 0.2));
         }
     }
 }
@@ -132,7 +132,9 @@ impl CharacterTrait for Navia {
         skill_name3: locale!(
             zh_cn: "如霰澄天的鸣礼",
             en: "As the Sunlit Sky's Singing Salute"
-        )
+        ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = NaviaSkillType;
     const SKILL: Self::SkillType = NAVIA_SKILL;
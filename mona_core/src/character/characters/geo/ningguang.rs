@@ -2,7 +2,7 @@ use num_derive::FromPrimitive;
 use crate::attribute::{Attribute, AttributeName};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
 use crate::common::{ChangeAttribute, Element, SkillType, StatName, WeaponType};
@@ -65,6 +65,8 @@ pub const NINGGUANG_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "天权崩玉",
         en: "Starshatter",
     ),
+    c3_talent: CharacterTalent::ElementalBurst,
+    c5_talent: CharacterTalent::ElementalSkill,
     name_locale: locale!(
         zh_cn: "凝光",
         en: "Ningguang",
@@ -92,7 +94,7 @@ impl NingguangEffect {
 impl<A: Attribute> ChangeAttribute<A> for NingguangEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.has_talent2 {
-            attribute.set_value_by(AttributeName::BonusGeo, "凝光天赋：储之千日，用之一刻", self.rate * 0.12);
+            attribute.set_combat_value_by(AttributeName::BonusGeo, "凝光天赋：储之千日，用之一刻", self.rate * 0.12);
         }
     }
 }
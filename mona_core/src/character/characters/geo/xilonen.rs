@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::characters::kachina::KachinaDamageEnum;
@@ -174,7 +174,7 @@ impl<A: Attribute> ChangeAttribute<A> for XilonenEffect {
             attribute.set_value_by(AttributeName::BonusNormalAttack, "天赋「四境四象回声」", 0.3 * self.talent1_rate);
         }
 
-        attribute.add_def_percentage("天赋「便携铠装护层」", 0.2 * self.talent2_rate);
+        attribute.with_combat_sources(|attribute| attribute.add_def_percentage("天赋「便携铠装护层」", 0.2 * self.talent2_rate));
     }
 }
 
@@ -207,6 +207,8 @@ impl CharacterTrait for Xilonen {
             zh_cn: "豹烈律动！",
             en: "Ocelotlicue Point!"
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = XilonenSkillType;
     const SKILL: Self::SkillType = XILONEN_SKILL;
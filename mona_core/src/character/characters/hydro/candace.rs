@@ -3,7 +3,7 @@ use strum::EnumCount;
 use strum_macros::{EnumCount as EnumCountMacro, EnumString};
 
 use crate::attribute::{Attribute, AttributeCommon, AttributeName};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::skill_config::CharacterSkillConfig;
@@ -63,7 +63,7 @@ pub struct CandaceEffect {
 impl<A: Attribute> ChangeAttribute<A> for CandaceEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.c2 {
-            attribute.add_hp_percentage("坎蒂丝天赋命座2「贯月的耀锋」", 0.2 * self.c2_rate);
+            attribute.with_combat_sources(|attribute| attribute.add_hp_percentage("坎蒂丝天赋命座2「贯月的耀锋」", 0.2 * self.c2_rate));
         }
     }
 }
@@ -141,6 +141,8 @@ impl CharacterTrait for Candace {
             zh_cn: "圣仪·灰鸰衒潮",
             en: "Sacred Rite: Wagtail’s Tide",
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
         name_locale: locale!(
             zh_cn: "坎蒂丝",
             en: "Candace",
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -122,7 +122,7 @@ pub struct FurinaEffect {
 impl<A: Attribute> ChangeAttribute<A> for FurinaEffect {
     fn change_attribute(&self, attribute: &mut A) {
         let ratio = (self.c2_overflow * 0.0035).min(1.4);
-        attribute.add_hp_percentage("C2「女人皆善变，仿若水中萍。」", ratio);
+        attribute.with_combat_sources(|attribute| attribute.add_hp_percentage("C2「女人皆善变，仿若水中萍。」", ratio));
     }
 }
 
@@ -154,7 +154,9 @@ impl CharacterTrait for Furina {
         skill_name3: locale!(
             zh_cn: "万众狂欢",
             en: "Let the People Rejoice"
-        )
+        ),
+        c3_talent: CharacterTalent::ElementalBurst,
+        c5_talent: CharacterTalent::ElementalSkill
     };
     type SkillType = FurinaSkillType;
     const SKILL: Self::SkillType = FURINA_SKILL;
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::characters::navia::NaviaDamageEnum;
@@ -132,6 +132,8 @@ impl CharacterTrait for Mualani {
             zh_cn: "爆瀑飞弹",
             en: "Boomsharka-laka"
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = MualaniSkillType;
     const SKILL: Self::SkillType = MUALANI_SKILL;
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -95,18 +95,20 @@ impl<A: Attribute> ChangeAttribute<A> for NeuvilletteEffect {
     fn change_attribute(&self, attribute: &mut A) {
         let current_hp = self.current_hp;
         if self.has_talent2 {
-            attribute.add_edge1(
-                AttributeName::HP,
-                AttributeName::BonusHydro,
-                Box::new(move |hp, _| {
-                    // let bonus = (current_hp as i32 - 30_i32).max(0) as f64 * 0.006;
-                    let bonus = (current_hp - 30).max(0) as f64 * 0.006;
-                    let bonus = bonus.min(0.3);
-                    bonus
-                }),
-                Box::new(|dx, dy, grad| (0.0, 0.0)),
-                "天赋「至高仲裁的纪律」"
-            );
+            attribute.with_combat_sources(|attribute| {
+                attribute.add_edge1(
+                    AttributeName::HP,
+                    AttributeName::BonusHydro,
+                    Box::new(move |hp, _| {
+                        // let bonus = (current_hp as i32 - 30_i32).max(0) as f64 * 0.006;
+                        let bonus = (current_hp - 30).max(0) as f64 * 0.006;
+                        let bonus = bonus.min(0.3);
+                        bonus
+                    }),
+                    Box::new(|_grad, _hp, _| (0.0, 0.0)),
+                    "天赋「至高仲裁的纪律」"
+                );
+            });
         }
     }
 }
@@ -139,7 +141,9 @@ impl CharacterTrait for Neuvillette {
         skill_name3: locale!(
             zh_cn: "潮水啊，我已归来",
             en: "O Tides, I Have Returned"
-        )
+        ),
+        c3_talent: CharacterTalent::NormalAttack,
+        c5_talent: CharacterTalent::ElementalBurst
     };
     type SkillType = NeuvilletteSkillType;
     const SKILL: Self::SkillType = NEUVILLETTE_SKILL;
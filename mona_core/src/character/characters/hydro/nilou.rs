@@ -1,7 +1,7 @@
 // use num_derive::FromPrimitive;
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
 use crate::character::character_common_data::CharacterCommonData;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
@@ -73,20 +73,20 @@ impl<A: Attribute> ChangeAttribute<A> for NilouEffect {
             attribute.add_edge1(
                 AttributeName::HP, AttributeName::CriticalBase,
                 Box::new(|hp, _| ((hp / 1000.0).floor() * 0.006).min(0.3)),
-                Box::new(|hp, _, grad| (0.0, 0.0)), // todo
+                Box::new(|grad, hp, _| if (hp / 1000.0).floor() * 0.006 < 0.3 { (grad * 0.006 / 1000.0, 0.0) } else { (0.0, 0.0) }),
                 "六命：「断霜的弦歌」"
             );
             attribute.add_edge1(
                 AttributeName::HP,
                 AttributeName::CriticalDamageBase,
                 Box::new(|hp, _| ((hp / 1000.0).floor() * 0.012).min(0.6)),
-                Box::new(|hp, _, grad| (0.0, 0.0)), // todo
+                Box::new(|grad, hp, _| if (hp / 1000.0).floor() * 0.012 < 0.6 { (grad * 0.012 / 1000.0, 0.0) } else { (0.0, 0.0) }),
                 "六命：「断霜的弦歌」"
             );
         }
 
         if self.has_talent1 {
-            attribute.set_value_by(AttributeName::ElementalMastery, "天赋「折旋落英之庭」", self.golden_rate * 100.0);
+            attribute.set_combat_value_by(AttributeName::ElementalMastery, "天赋「折旋落英之庭」", self.golden_rate * 100.0);
         }
 
         if self.has_talent2 {
@@ -103,7 +103,13 @@ impl<A: Attribute> ChangeAttribute<A> for NilouEffect {
                         0.0
                     }
                 }),
-                Box::new(move |hp, _, grad| (0.0, 0.0)),
+                Box::new(move |grad, hp, _| {
+                    if hp >= 31000.0 && ((hp - 30000.0) / 1000.0).floor() * 0.09 < 4.0 {
+                        (grad * 0.09 / 1000.0 * rate, 0.0)
+                    } else {
+                        (0.0, 0.0)
+                    }
+                }),
                 "天赋「翩舞永世之梦」"
             );
         }
@@ -178,6 +184,8 @@ impl CharacterTrait for Nilou {
             zh_cn: "浮莲舞步·远梦聆泉",
             en: "Dance of Abzendegi: Distant Dreams, Listening Spring",
         ),
+        c3_talent: CharacterTalent::ElementalBurst,
+        c5_talent: CharacterTalent::ElementalSkill,
         name_locale: locale!(
             zh_cn: "妮露",
             en: "Nilou",
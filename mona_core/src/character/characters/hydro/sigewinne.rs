@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -119,7 +119,7 @@ impl<A: Attribute> ChangeAttribute<A> for SigewinneEffect {
                 Box::new(move |hp, _| {
                     ((hp / 1000.0).floor() * 0.004).min(0.2)
                 }),
-                Box::new(|_x, _y, _grad| (0.0, 0.0)),
+                Box::new(|grad, hp, _| if (hp / 1000.0).floor() * 0.004 < 0.2 { (grad * 0.004 / 1000.0, 0.0) } else { (0.0, 0.0) }),
                 "C6「最光辉的精灵，可否为我祷告」"
             );
             attribute.add_edge1(
@@ -128,7 +128,7 @@ impl<A: Attribute> ChangeAttribute<A> for SigewinneEffect {
                 Box::new(move |hp, _| {
                     ((hp / 1000.0).floor() * 0.022).min(1.1)
                 }),
-                Box::new(|_x, _y, _grad| (0.0, 0.0)),
+                Box::new(|grad, hp, _| if (hp / 1000.0).floor() * 0.022 < 1.1 { (grad * 0.022 / 1000.0, 0.0) } else { (0.0, 0.0) }),
                 "C6「最光辉的精灵，可否为我祷告」"
             );
         }
@@ -164,6 +164,8 @@ impl CharacterTrait for Sigewinne {
             zh_cn: "过饱和心意注射",
             en: "Super Saturated Syringing"
         ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = SigewinneSkillType;
     const SKILL: Self::SkillType = SIGEWINNE_SKILL;
@@ -4,7 +4,7 @@ use strum_macros::{EnumCount as EnumCountMacro, EnumString};
 
 use crate::attribute::{Attribute, AttributeCommon};
 use crate::character::character_common_data::CharacterCommonData;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
@@ -65,7 +65,7 @@ impl<A: Attribute> ChangeAttribute<A> for YelanEffect {
             _ => 0.3
         };
 
-        attribute.add_hp_percentage("天赋：猜先有方", value);
+        attribute.with_combat_sources(|attribute| attribute.add_hp_percentage("天赋：猜先有方", value));
     }
 }
 
@@ -154,6 +154,8 @@ impl CharacterTrait for Yelan {
             zh_cn: "渊图玲珑骰",
             en: "Depth-Clarion Dice",
         ),
+        c3_talent: CharacterTalent::ElementalBurst,
+        c5_talent: CharacterTalent::ElementalSkill,
         name_locale: locale!(
             zh_cn: "夜兰",
             en: "Yelan",
@@ -1,5 +1,5 @@
 use num_derive::FromPrimitive;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::common::{Element, WeaponType, ChangeAttribute, SkillType};
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::character_common_data::CharacterCommonData;
@@ -74,6 +74,8 @@ pub const AMBER_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "箭雨",
         en: "Fiery Rain",
     ),
+    c3_talent: CharacterTalent::ElementalBurst,
+    c5_talent: CharacterTalent::ElementalSkill,
     name_locale: locale!(
         zh_cn: "安柏",
         en: "Amber",
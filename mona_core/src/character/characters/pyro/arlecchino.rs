@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -156,6 +156,8 @@ impl CharacterTrait for Arlecchino {
             zh_cn: "厄月将升",
             en: "Balemoon Rising"
         ),
+        c3_talent: CharacterTalent::NormalAttack,
+        c5_talent: CharacterTalent::ElementalBurst,
     };
     type SkillType = ArlecchinoSkill;
     const SKILL: Self::SkillType = ARLECCHINO_SKILL;
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -118,12 +118,12 @@ impl<A: Attribute> ChangeAttribute<A> for ChevreuseEffect {
                 let x = (hp / 1000.0).floor();
                 (x * 0.01).min(0.4)
             }),
-            Box::new(|_x, _y, _grad| (0.0, 0.0)),
+            Box::new(|grad, hp, _| if (hp / 1000.0).floor() * 0.01 < 0.4 { (grad * 0.01 / 1000.0, 0.0) } else { (0.0, 0.0) }),
             "天赋「纵阵武力统筹」"
         );
         if self.constellation >= 6 {
-            attribute.set_value_by(AttributeName::BonusPyro, "C6「终结罪恶的追缉」", 0.2 * self.c6_stack);
-            attribute.set_value_by(AttributeName::BonusElectro, "C6「终结罪恶的追缉」", 0.2 * self.c6_stack);
+            attribute.set_combat_value_by(AttributeName::BonusPyro, "C6「终结罪恶的追缉」", 0.2 * self.c6_stack);
+            attribute.set_combat_value_by(AttributeName::BonusElectro, "C6「终结罪恶的追缉」", 0.2 * self.c6_stack);
         }
     }
 }
@@ -156,7 +156,9 @@ impl CharacterTrait for Chevreuse {
         skill_name3: locale!(
             zh_cn: "圆阵掷弹爆轰术",
             en: "Ring of Bursting Grenades"
-        )
+        ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst
     };
     type SkillType = ChevreuseSkillType;
     const SKILL: Self::SkillType = CHEVREUSE_SKILL;
@@ -4,7 +4,7 @@ use strum_macros::{EnumCount as EnumCountMacro, EnumString};
 
 use crate::attribute::Attribute;
 use crate::attribute::attribute_name::AttributeName;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::skill_config::CharacterSkillConfig;
@@ -83,6 +83,8 @@ const DEHYA_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "炎啸狮子咬",
         en: "Leonine Bite",
     ),
+    c3_talent: CharacterTalent::ElementalSkill,
+    c5_talent: CharacterTalent::ElementalBurst,
     name_locale: locale!(
         zh_cn: "迪希雅",
         en: "Dehya",
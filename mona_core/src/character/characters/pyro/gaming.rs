@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -56,13 +56,13 @@ pub struct GamingEffect {
 impl<A: Attribute> ChangeAttribute<A> for GamingEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.constellation >= 2 {
-            attribute.add_atk_percentage("C2「步踏梅花」", 0.2 * self.c2_rate);
+            attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("C2「步踏梅花」", 0.2 * self.c2_rate));
         }
         if self.has_talent2 {
             if self.hp_above50 {
                 attribute.set_value_by(AttributeName::USER1, "talent2_bonus", 0.2);
             } else {
-                attribute.set_value_by(AttributeName::IncomingHealingBonus, "天赋「祥烟瑞气」", 0.2);
+                attribute.set_combat_value_by(AttributeName::IncomingHealingBonus, "天赋「祥烟瑞气」", 0.2);
             }
         }
     }
@@ -136,7 +136,9 @@ impl CharacterTrait for Gaming {
         skill_name3: locale!(
             zh_cn: "璨焰金猊舞",
             en: "Suanni's Gilded Dance"
-        )
+        ),
+        c3_talent: CharacterTalent::ElementalSkill,
+        c5_talent: CharacterTalent::ElementalBurst
     };
     type SkillType = GamingSkillType;
     const SKILL: Self::SkillType = GAMING_SKILL;
@@ -3,7 +3,7 @@ use strum::EnumCount;
 use strum_macros::{EnumCount as EnumCountMacro, EnumString};
 
 use crate::attribute::{Attribute, AttributeName};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::skill_config::CharacterSkillConfig;
@@ -84,6 +84,8 @@ pub const HU_TAO_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "安神秘法",
         en: "Spirit Soother",
     ),
+    c3_talent: CharacterTalent::ElementalSkill,
+    c5_talent: CharacterTalent::ElementalBurst,
     name_locale: locale!(
         zh_cn: "胡桃",
         en: "Hu Tao",
@@ -112,7 +114,7 @@ impl HuTaoEffect {
 impl<T: Attribute> ChangeAttribute<T> for HuTaoEffect {
     fn change_attribute(&self, attribute: &mut T) {
         if self.has_talent2 && self.le_50 {
-            attribute.set_value_by(AttributeName::BonusPyro, "胡桃天赋2：血之灶火", 0.33);
+            attribute.set_combat_value_by(AttributeName::BonusPyro, "胡桃天赋2：血之灶火", 0.33);
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::macros::{damage_enum, damage_ratio, skill_map, skill_type};
@@ -108,7 +108,7 @@ impl<A: Attribute> ChangeAttribute<A> for LyneyEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.constellation >= 2 {
             let value = 0.2 * self.c2_stack;
-            attribute.set_value_by(AttributeName::CriticalDamageBase, "2命「巧言贴耳的诱引」", value);
+            attribute.set_combat_value_by(AttributeName::CriticalDamageBase, "2命「巧言贴耳的诱引」", value);
         }
         if self.constellation >= 4 {
             let value = 0.2 * self.c4_rate;
@@ -145,7 +145,9 @@ impl CharacterTrait for Lyney {
         skill_name3: locale!(
             zh_cn: "大魔术·灵迹巡游",
             en: "Wondrous Trick: Miracle Parade"
-        )
+        ),
+        c3_talent: CharacterTalent::NormalAttack,
+        c5_talent: CharacterTalent::ElementalSkill
     };
     type SkillType = LyneySkillType;
     const SKILL: Self::SkillType = LYNEY_SKILL;
@@ -1,5 +1,5 @@
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::characters::arlecchino::ArlecchinoDamageEnum;
@@ -147,7 +147,7 @@ impl<A: Attribute> ChangeAttribute<A> for MavuikaEffect {
     fn change_attribute(&self, attribute: &mut A) {
         if self.has_talent1 {
             let ratio = 0.3 * self.talent1_rate;
-            attribute.add_atk_percentage("天赋「炎花献礼」", ratio);
+            attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("天赋「炎花献礼」", ratio));
         }
 
         if self.has_talent2 {
@@ -160,11 +160,11 @@ impl<A: Attribute> ChangeAttribute<A> for MavuikaEffect {
         }
 
         if self.constellation >= 1 {
-            attribute.add_atk_percentage("C1「夜主的授记」", self.c1_rate * 0.4);
+            attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("C1「夜主的授记」", self.c1_rate * 0.4));
         }
 
         if self.constellation >= 2 {
-            attribute.set_value_by(AttributeName::ATKBase, "C2「灰烬的代价」", 200.0 * self.c2_rate);
+            attribute.set_combat_value_by(AttributeName::ATKBase, "C2「灰烬的代价」", 200.0 * self.c2_rate);
             attribute.set_value_by(AttributeName::USER1, "C2「灰烬的代价」", self.c2_rate);
         }
 
@@ -201,6 +201,8 @@ impl CharacterTrait for Mavuika {
             zh_cn: "燔天之时",
             en: "Hour of Burning Skies"
         ),
+        c3_talent: CharacterTalent::ElementalBurst,
+        c5_talent: CharacterTalent::ElementalSkill,
     };
     type SkillType = MavuikaSkillType;
     const SKILL: Self::SkillType = MAVUIKA_SKILL;
@@ -2,7 +2,7 @@ use num_derive::FromPrimitive;
 use crate::attribute::{Attribute, AttributeName};
 use crate::character::character_common_data::CharacterCommonData;
 use crate::character::character_sub_stat::CharacterSubStatFamily;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::{CharacterSkillMap, CharacterSkillMapItem, CharacterTrait};
 use crate::common::{ChangeAttribute, Element, SkillType, WeaponType};
@@ -75,6 +75,8 @@ pub const YOIMIYA_STATIC_DATA: CharacterStaticData = CharacterStaticData {
         zh_cn: "琉金云间草",
         en: "Ryuukin Saxifrage",
     ),
+    c3_talent: CharacterTalent::ElementalSkill,
+    c5_talent: CharacterTalent::ElementalBurst,
     name_locale: locale!(
         zh_cn: "宵宫",
         en: "Yoimiya",
@@ -102,7 +104,7 @@ impl YoimiyaEffect {
 impl<T: Attribute> ChangeAttribute<T> for YoimiyaEffect {
     fn change_attribute(&self, attribute: &mut T) {
         if self.has_talent1 {
-            attribute.set_value_by(AttributeName::BonusPyro, "宵宫天赋：袖火百景图", self.talent1_level * 0.02);
+            attribute.set_combat_value_by(AttributeName::BonusPyro, "宵宫天赋：袖火百景图", self.talent1_level * 0.02);
         }
     }
 }
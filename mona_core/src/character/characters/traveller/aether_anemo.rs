@@ -1,7 +1,7 @@
 use num_derive::FromPrimitive;
 use crate::attribute::{Attribute, AttributeName};
 use crate::character::character_common_data::CharacterCommonData;
-use crate::character::{CharacterConfig, CharacterName, CharacterStaticData};
+use crate::character::{CharacterConfig, CharacterName, CharacterStaticData, CharacterTalent};
 use crate::character::character_sub_stat::CharacterSubStatFamily;
 use crate::character::characters::geo::albedo::AlbedoRoleEnum;
 use crate::character::skill_config::CharacterSkillConfig;
@@ -156,6 +156,8 @@ impl CharacterTrait for AetherAnemo {
             zh_cn: "风息激荡",
             en: "Gust Surge",
         ),
+        c3_talent: CharacterTalent::ElementalBurst,
+        c5_talent: CharacterTalent::ElementalSkill,
         name_locale: locale!(
             zh_cn: "空-风",
             en: "Aether(Anemo)",
@@ -10,7 +10,7 @@ pub mod traits;
 pub mod prelude;
 pub mod macros;
 
-pub use character_static_data::{CharacterStaticData};
+pub use character_static_data::{CharacterStaticData, CharacterTalent};
 pub use character_name::CharacterName;
 pub use character::Character;
 pub use character_config::CharacterConfig;
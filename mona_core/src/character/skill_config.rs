@@ -8,7 +8,7 @@ fn default_false() -> bool {
     false
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum CharacterSkillConfig {
     Albedo { fatal_count: usize },
     Aloy { coil_count: usize },
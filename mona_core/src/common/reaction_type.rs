@@ -1,7 +1,7 @@
 use serde::{Serialize, Deserialize};
 use crate::common::Element;
 
-#[derive(Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Debug, Hash)]
 pub enum TransformativeType {
     SwirlCryo,
     SwirlHydro,
@@ -18,10 +18,65 @@ pub enum TransformativeType {
     Burgeon,
     // 超绽放
     Hyperbloom,
+
+    // 月感电
+    LunarCharged,
+    // 月绽放
+    LunarBloom,
+}
+
+impl TransformativeType {
+    pub const ALL: [TransformativeType; 14] = [
+        TransformativeType::SwirlCryo,
+        TransformativeType::SwirlHydro,
+        TransformativeType::SwirlElectro,
+        TransformativeType::SwirlPyro,
+        TransformativeType::Superconduct,
+        TransformativeType::Overload,
+        TransformativeType::Burning,
+        TransformativeType::ElectroCharged,
+        TransformativeType::Shatter,
+        TransformativeType::Bloom,
+        TransformativeType::Burgeon,
+        TransformativeType::Hyperbloom,
+        TransformativeType::LunarCharged,
+        TransformativeType::LunarBloom,
+    ];
+
+    /// lunar reactions can crit and ignore enemy's def
+    pub fn is_lunar(&self) -> bool {
+        matches!(*self, TransformativeType::LunarCharged | TransformativeType::LunarBloom)
+    }
+
+    pub fn is_swirl(&self) -> bool {
+        use TransformativeType::*;
+        matches!(*self, SwirlCryo | SwirlHydro | SwirlElectro | SwirlPyro)
+    }
+
+    /// the element whose res is used when calculating damage
+    pub fn damage_element(&self) -> Element {
+        use TransformativeType::*;
+        match *self {
+            SwirlCryo | Superconduct => Element::Cryo,
+            SwirlHydro => Element::Hydro,
+            SwirlElectro | ElectroCharged | LunarCharged => Element::Electro,
+            SwirlPyro | Overload | Burning => Element::Pyro,
+            Shatter => Element::Physical,
+            Bloom | Burgeon | Hyperbloom | LunarBloom => Element::Dendro,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Debug)]
 pub enum AmplifyingType {
     Melt(Element),
     Vaporize(Element)
 }
+
+#[derive(Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Debug)]
+pub enum CatalyzeType {
+    // 蔓激化
+    Spread,
+    // 超激化
+    Aggravate,
+}
@@ -3,7 +3,9 @@ use rand::{Rng, thread_rng};
 use num_derive::FromPrimitive;
 use strum_macros::{EnumCount as EnumCountMacro};
 
+use rand::distributions::{Distribution, WeightedIndex};
 use crate::artifacts::ArtifactSlotName;
+use crate::artifacts::artifact_generator::artifact_main_stat_weights;
 use crate::attribute::{AttributeName, Attribute, AttributeCommon};
 use super::element::Element;
 
@@ -32,66 +34,46 @@ pub enum StatName {
 }
 
 impl StatName {
+    /// node that receives the stat, percentage stats get `value * base` through an edge from `percentage_base`
+    pub fn attribute_name(&self) -> AttributeName {
+        match *self {
+            StatName::ATKFixed => AttributeName::ATKFixed,
+            StatName::ATKPercentage => AttributeName::ATKPercentage,
+            StatName::HealingBonus => AttributeName::HealingBonus,
+            StatName::HPFixed => AttributeName::HPFixed,
+            StatName::HPPercentage => AttributeName::HPPercentage,
+            StatName::DEFFixed => AttributeName::DEFFixed,
+            StatName::DEFPercentage => AttributeName::DEFPercentage,
+            StatName::CriticalRate => AttributeName::CriticalBase,
+            StatName::CriticalDamage => AttributeName::CriticalDamageBase,
+            StatName::ElementalMastery => AttributeName::ElementalMastery,
+            StatName::Recharge => AttributeName::Recharge,
+            StatName::ElectroBonus => AttributeName::BonusElectro,
+            StatName::PyroBonus => AttributeName::BonusPyro,
+            StatName::HydroBonus => AttributeName::BonusHydro,
+            StatName::CryoBonus => AttributeName::BonusCryo,
+            StatName::AnemoBonus => AttributeName::BonusAnemo,
+            StatName::GeoBonus => AttributeName::BonusGeo,
+            StatName::DendroBonus => AttributeName::BonusDendro,
+            StatName::PhysicalBonus => AttributeName::BonusPhysical,
+        }
+    }
+
+    pub fn percentage_base(&self) -> Option<AttributeName> {
+        match *self {
+            StatName::ATKPercentage => Some(AttributeName::ATKBase),
+            StatName::HPPercentage => Some(AttributeName::HPBase),
+            StatName::DEFPercentage => Some(AttributeName::DEFBase),
+            _ => None
+        }
+    }
+
     pub fn apply<T: Attribute>(&self, attribute: &mut T, key: &str, value: f64) {
         match *self {
-            StatName::ATKFixed => {
-                attribute.set_value_by(AttributeName::ATKFixed, key, value)
-            },
-            StatName::ATKPercentage => {
-                let temp = String::from(key);
-                attribute.add_atk_percentage(key, value);
-            },
-            StatName::HealingBonus => {
-                attribute.set_value_by(AttributeName::HealingBonus, key, value);
-            },
-            StatName::HPFixed => {
-                attribute.set_value_by(AttributeName::HPFixed, key, value);
-            },
-            StatName::HPPercentage => {
-                attribute.add_hp_percentage(key, value);
-            },
-            StatName::DEFFixed => {
-                attribute.set_value_by(AttributeName::DEFFixed, key, value);
-            },
-            StatName::DEFPercentage => {
-                attribute.add_def_percentage(key, value);
-            },
-            StatName::CriticalRate => {
-                attribute.set_value_by(AttributeName::CriticalBase, key, value);
-            },
-            StatName::CriticalDamage => {
-                attribute.set_value_by(AttributeName::CriticalDamageBase, key, value);
-            },
-            StatName::ElementalMastery => {
-                attribute.set_value_by(AttributeName::ElementalMastery, key, value);
-            },
-            StatName::Recharge => {
-                attribute.set_value_by(AttributeName::Recharge, key, value);
-            },
-            StatName::ElectroBonus => {
-                attribute.set_value_by(AttributeName::BonusElectro, key, value);
-            },
-            StatName::PyroBonus => {
-                attribute.set_value_by(AttributeName::BonusPyro, key, value);
-            },
-            StatName::HydroBonus => {
-                attribute.set_value_by(AttributeName::BonusHydro, key, value);
-            },
-            StatName::CryoBonus => {
-                attribute.set_value_by(AttributeName::BonusCryo, key, value);
-            },
-            StatName::AnemoBonus => {
-                attribute.set_value_by(AttributeName::BonusAnemo, key, value);
-            },
-            StatName::GeoBonus => {
-                attribute.set_value_by(AttributeName::BonusGeo, key, value);
-            },
-            StatName::DendroBonus => {
-                attribute.set_value_by(AttributeName::BonusDendro, key, value);
-            },
-            StatName::PhysicalBonus => {
-                attribute.set_value_by(AttributeName::BonusPhysical, key, value);
-            }
+            StatName::ATKPercentage => attribute.add_atk_percentage(key, value),
+            StatName::HPPercentage => attribute.add_hp_percentage(key, value),
+            StatName::DEFPercentage => attribute.add_def_percentage(key, value),
+            _ => attribute.set_value_by(self.attribute_name(), key, value),
         }
     }
 
@@ -120,19 +102,14 @@ impl StatName {
     }
 
     pub fn random_artifact_main_stat(slot: ArtifactSlotName) -> StatName {
-        use StatName::*;
-        let v = match slot {
-            ArtifactSlotName::Flower => return HPFixed,
-            ArtifactSlotName::Feather => return ATKFixed,
-            ArtifactSlotName::Sand => vec![ATKPercentage, DEFPercentage, HPPercentage, ElementalMastery, Recharge],
-            ArtifactSlotName::Goblet => vec![ATKPercentage, DEFPercentage, HPPercentage, ElementalMastery, DendroBonus, PyroBonus, ElectroBonus, HydroBonus, CryoBonus, AnemoBonus, GeoBonus, PhysicalBonus],
-            ArtifactSlotName::Head => vec![ATKPercentage, DEFPercentage, HPPercentage, ElementalMastery, CriticalRate, CriticalDamage, HealingBonus]
-        };
-
-        let len = v.len();
-        let index: usize = thread_rng().gen::<usize>() % len;
+        StatName::random_artifact_main_stat_with_rng(slot, &mut thread_rng())
+    }
 
-        v[index]
+    // weighted by the drop rate of each main stat
+    pub fn random_artifact_main_stat_with_rng<R: Rng + ?Sized>(slot: ArtifactSlotName, rng: &mut R) -> StatName {
+        let weights = artifact_main_stat_weights(slot);
+        let w = WeightedIndex::new(weights.iter().map(|x| x.1)).unwrap();
+        weights[w.sample(rng)].0
     }
 
     #[inline]
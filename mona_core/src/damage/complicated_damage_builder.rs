@@ -1,13 +1,15 @@
 use std::collections::HashMap;
 use crate::attribute::{Attribute, AttributeName, ComplicatedAttributeGraph, AttributeCommon};
 use crate::common::{DamageResult, Element, SkillType};
-use crate::damage::damage_analysis::DamageAnalysis;
+use crate::common::reaction_type::TransformativeType;
+use crate::damage::damage_analysis::{DamageAnalysis, HealAnalysis, ShieldAnalysis};
 use crate::enemies::Enemy;
 use crate::common::EntryType;
 use crate::damage::damage_builder::{DamageBuilder};
 use crate::damage::level_coefficient::LEVEL_MULTIPLIER;
 use crate::damage::reaction::Reaction;
 use crate::damage::SimpleDamageBuilder;
+use crate::damage::transformative_damage::{get_em_bonus, get_lunar_direct_coefficient, get_transformative_base, lunar_damage_result, transformative_damage_result, LunarDamageInput, TransformativeDamageInput};
 
 #[derive(Default)]
 pub struct ComplicatedDamageBuilder {
@@ -22,6 +24,7 @@ pub struct ComplicatedDamageBuilder {
 
     pub extra_enhance_melt: EntryType,
     pub extra_enhance_vaporize: EntryType,
+    pub extra_enhance_lunar: EntryType,
     pub extra_em: EntryType,
 
     pub extra_def_minus: EntryType,
@@ -98,6 +101,10 @@ impl DamageBuilder for ComplicatedDamageBuilder {
         *self.extra_enhance_vaporize.0.entry(String::from(key)).or_insert(0.0) += value;
     }
 
+    fn add_extra_enhance_lunar(&mut self, key: &str, value: f64) {
+        *self.extra_enhance_lunar.0.entry(String::from(key)).or_insert(0.0) += value;
+    }
+
     fn add_extra_def_minus(&mut self, key: &str, value: f64) {
         *self.extra_def_minus.0.entry(String::from(key)).or_insert(0.0) += value;
     }
@@ -260,12 +267,17 @@ impl DamageBuilder for ComplicatedDamageBuilder {
             critical_damage: critical_damage_comp.0,
             spread_compose: spread_enhance_comp.0,
             aggravate_compose: aggravate_enhance_comp.0,
+            lunar_enhance: HashMap::new(),
+            lunar_base_bonus: HashMap::new(),
+            transformative_enhance: HashMap::new(),
 
             melt_enhance: melt_enhance_comp.0,
             vaporize_enhance: vaporize_enhance_comp.0,
 
             healing_bonus: HashMap::new(),
+            incoming_healing_bonus: HashMap::new(),
             shield_strength: HashMap::new(),
+            shield_absorption: HashMap::new(),
             def_minus: def_minus_comp.0,
             def_penetration: def_penetration_comp.0,
             res_minus: res_minus_comp.0,
@@ -273,6 +285,8 @@ impl DamageBuilder for ComplicatedDamageBuilder {
             element,
             is_heal: false,
             is_shield: false,
+            lunar_type: None,
+            transformative_type: None,
 
             normal: damage_normal,
             melt: damage_melt,
@@ -282,7 +296,16 @@ impl DamageBuilder for ComplicatedDamageBuilder {
         }
     }
 
-    fn heal(&self, attribute: &Self::AttributeType) -> Self::Result {
+    fn lunar(
+        &self,
+        attribute: &Self::AttributeType,
+        enemy: &Enemy,
+        lunar_type: TransformativeType,
+        character_level: usize,
+        direct: bool
+    ) -> Self::Result {
+        let element = lunar_type.damage_element();
+
         let atk_comp = self.get_atk_composition(attribute);
         let atk = atk_comp.sum();
         let def_comp = self.get_def_composition(attribute);
@@ -292,49 +315,80 @@ impl DamageBuilder for ComplicatedDamageBuilder {
         let em_comp = self.get_em_composition(attribute);
         let em = em_comp.sum();
 
-        let healing_bonus_comp = self.get_healing_bonus_composition(attribute);
-        let healing_bonus = healing_bonus_comp.sum();
+        let extra_damage = self.extra_damage.sum();
+        let base_damage = if direct {
+            let coefficient = get_lunar_direct_coefficient(lunar_type);
+            (atk * self.ratio_atk.sum() + def * self.ratio_def.sum() + hp * self.ratio_hp.sum() + em * self.ratio_em.sum()) * coefficient
+        } else {
+            get_transformative_base(character_level, lunar_type)
+        } + extra_damage;
 
-        let base = atk * self.ratio_atk.sum() + hp * self.ratio_hp.sum() + def * self.ratio_def.sum() + em * self.ratio_em.sum() + self.extra_damage.sum();
+        let lunar_base_bonus_comp = attribute.get_attribute_composition(AttributeName::LunarBaseDmgBonus);
+        let lunar_base_bonus = lunar_base_bonus_comp.sum();
 
-        let heal_value = base * (1.0 + healing_bonus);
-        let damage_normal = DamageResult {
-            expectation: heal_value,
-            critical: heal_value,
-            non_critical: heal_value,
-            is_heal: true,
-            is_shield: false
+        let lunar_enhance_comp = self.get_enhance_lunar_composition(attribute, lunar_type);
+        let lunar_enhance = lunar_enhance_comp.sum();
+
+        let critical_comp = self.get_lunar_critical_composition(attribute, lunar_type);
+        let critical = critical_comp.sum();
+        let critical_damage_comp = self.get_lunar_critical_damage_composition(attribute, lunar_type);
+        let critical_damage = critical_damage_comp.sum();
+
+        let res_minus_comp = self.get_res_minus_composition(attribute, element);
+        let res_minus = res_minus_comp.sum();
+        let resistance_ratio = enemy.get_resistance_ratio(element, res_minus);
+
+        let damage_normal = lunar_damage_result(&LunarDamageInput {
+            base_damage,
+            base_bonus: lunar_base_bonus,
+            enhance: lunar_enhance,
+            critical,
+            critical_damage,
+            resistance_ratio,
+        });
+
+        let (atk_ratio, def_ratio, hp_ratio, em_ratio) = if direct {
+            (self.ratio_atk.0.clone(), self.ratio_def.0.clone(), self.ratio_hp.0.clone(), self.ratio_em.0.clone())
+        } else {
+            (HashMap::new(), HashMap::new(), HashMap::new(), HashMap::new())
         };
 
-        return DamageAnalysis {
+        DamageAnalysis {
             atk: atk_comp.0,
-            atk_ratio: self.ratio_atk.0.clone(),
+            atk_ratio,
             hp: hp_comp.0,
-            hp_ratio: self.ratio_hp.0.clone(),
+            hp_ratio,
             def: def_comp.0,
-            def_ratio: self.ratio_def.0.clone(),
+            def_ratio,
             em: em_comp.0,
-            em_ratio: self.ratio_em.0.clone(),
+            em_ratio,
             extra_damage: self.extra_damage.0.clone(),
             spread_compose: HashMap::new(),
             aggravate_compose: HashMap::new(),
+            lunar_enhance: lunar_enhance_comp.0,
+            lunar_base_bonus: lunar_base_bonus_comp.0,
+            transformative_enhance: HashMap::new(),
 
             bonus: HashMap::new(),
-            critical: HashMap::new(),
-            critical_damage: HashMap::new(),
+            critical: critical_comp.0,
+            critical_damage: critical_damage_comp.0,
 
             melt_enhance: HashMap::new(),
             vaporize_enhance: HashMap::new(),
 
-            healing_bonus: healing_bonus_comp.0,
+            healing_bonus: HashMap::new(),
+            incoming_healing_bonus: HashMap::new(),
             shield_strength: HashMap::new(),
+            shield_absorption: HashMap::new(),
             def_minus: HashMap::new(),
             def_penetration: HashMap::new(),
-            res_minus: HashMap::new(),
+            res_minus: res_minus_comp.0,
 
-            element: Element::Pyro,
-            is_heal: true,
+            element,
+            is_heal: false,
             is_shield: false,
+            lunar_type: Some(lunar_type),
+            transformative_type: None,
 
             normal: damage_normal,
             melt: None,
@@ -344,7 +398,143 @@ impl DamageBuilder for ComplicatedDamageBuilder {
         }
     }
 
+    fn transformative(
+        &self,
+        attribute: &Self::AttributeType,
+        enemy: &Enemy,
+        transformative_type: TransformativeType,
+        character_level: usize
+    ) -> Self::Result {
+        if transformative_type.is_lunar() {
+            return self.lunar(attribute, enemy, transformative_type, character_level, false);
+        }
+
+        let element = transformative_type.damage_element();
+        let em_comp = self.get_em_composition(attribute);
+
+        let extra_damage_comp = self.get_transformative_base_composition(attribute, transformative_type, character_level);
+        let base_damage = extra_damage_comp.sum();
+
+        let enhance_comp = self.get_enhance_transformative_composition(attribute, transformative_type);
+        let enhance = enhance_comp.sum();
+
+        let critical_comp = self.get_transformative_critical_composition(attribute, transformative_type);
+        let critical = critical_comp.sum();
+        let critical_damage_comp = self.get_transformative_critical_damage_composition(attribute, transformative_type);
+        let critical_damage = critical_damage_comp.sum();
+
+        let res_minus_comp = self.get_res_minus_composition(attribute, element);
+        let res_minus = res_minus_comp.sum();
+        let resistance_ratio = enemy.get_resistance_ratio(element, res_minus);
+
+        let damage_normal = transformative_damage_result(&TransformativeDamageInput {
+            base_damage,
+            enhance,
+            critical,
+            critical_damage,
+            resistance_ratio,
+        });
+
+        DamageAnalysis {
+            atk: HashMap::new(),
+            atk_ratio: HashMap::new(),
+            hp: HashMap::new(),
+            hp_ratio: HashMap::new(),
+            def: HashMap::new(),
+            def_ratio: HashMap::new(),
+            em: em_comp.0,
+            em_ratio: HashMap::new(),
+            extra_damage: extra_damage_comp.0,
+            spread_compose: HashMap::new(),
+            aggravate_compose: HashMap::new(),
+            lunar_enhance: HashMap::new(),
+            lunar_base_bonus: HashMap::new(),
+            transformative_enhance: enhance_comp.0,
+
+            bonus: HashMap::new(),
+            critical: critical_comp.0,
+            critical_damage: critical_damage_comp.0,
+
+            melt_enhance: HashMap::new(),
+            vaporize_enhance: HashMap::new(),
+
+            healing_bonus: HashMap::new(),
+            incoming_healing_bonus: HashMap::new(),
+            shield_strength: HashMap::new(),
+            shield_absorption: HashMap::new(),
+            def_minus: HashMap::new(),
+            def_penetration: HashMap::new(),
+            res_minus: res_minus_comp.0,
+
+            element,
+            is_heal: false,
+            is_shield: false,
+            lunar_type: None,
+            transformative_type: Some(transformative_type),
+
+            normal: damage_normal,
+            melt: None,
+            vaporize: None,
+            spread: None,
+            aggravate: None,
+        }
+    }
+
+    fn heal(&self, attribute: &Self::AttributeType) -> Self::Result {
+        self.heal_analysis(attribute).into_damage_analysis()
+    }
+
     fn shield(&self, attribute: &Self::AttributeType, element: Element) -> Self::Result {
+        self.shield_analysis(attribute, element).into_damage_analysis()
+    }
+}
+
+impl ComplicatedDamageBuilder {
+    pub fn heal_analysis(&self, attribute: &ComplicatedAttributeGraph) -> HealAnalysis {
+        let atk_comp = self.get_atk_composition(attribute);
+        let atk = atk_comp.sum();
+        let def_comp = self.get_def_composition(attribute);
+        let def = def_comp.sum();
+        let hp_comp = self.get_hp_composition(attribute);
+        let hp = hp_comp.sum();
+        let em_comp = self.get_em_composition(attribute);
+        let em = em_comp.sum();
+
+        let healing_bonus_comp = self.get_healing_bonus_composition(attribute);
+        let healing_bonus = healing_bonus_comp.sum();
+        let incoming_healing_bonus_comp = attribute.get_attribute_composition(AttributeName::IncomingHealingBonus);
+        let incoming_healing_bonus = incoming_healing_bonus_comp.sum();
+
+        let base = atk * self.ratio_atk.sum() + hp * self.ratio_hp.sum() + def * self.ratio_def.sum() + em * self.ratio_em.sum() + self.extra_damage.sum();
+
+        let heal_value = base * (1.0 + healing_bonus + incoming_healing_bonus);
+        let result = DamageResult {
+            expectation: heal_value,
+            critical: heal_value,
+            non_critical: heal_value,
+            is_heal: true,
+            is_shield: false
+        };
+
+        HealAnalysis {
+            atk: atk_comp.0,
+            atk_ratio: self.ratio_atk.0.clone(),
+            hp: hp_comp.0,
+            hp_ratio: self.ratio_hp.0.clone(),
+            def: def_comp.0,
+            def_ratio: self.ratio_def.0.clone(),
+            em: em_comp.0,
+            em_ratio: self.ratio_em.0.clone(),
+            extra_damage: self.extra_damage.0.clone(),
+            healing_bonus: healing_bonus_comp.0,
+            incoming_healing_bonus: incoming_healing_bonus_comp.0,
+
+            base,
+            result,
+        }
+    }
+
+    pub fn shield_analysis(&self, attribute: &ComplicatedAttributeGraph, element: Element) -> ShieldAnalysis {
         let atk_comp = self.get_atk_composition(attribute);
         let atk = atk_comp.sum();
         let def_comp = self.get_def_composition(attribute);
@@ -360,15 +550,15 @@ impl DamageBuilder for ComplicatedDamageBuilder {
         let base = atk * self.ratio_atk.sum() + hp * self.ratio_hp.sum() + def * self.ratio_def.sum() + em * self.ratio_em.sum() + self.extra_damage.sum();
 
         let shield_value = base * (1.0 + shield_strength);
-        let damage_normal = DamageResult {
+        let result = DamageResult {
             expectation: shield_value,
-            critical: 0.0,
-            non_critical: 0.0,
+            critical: shield_value,
+            non_critical: shield_value,
             is_heal: false,
             is_shield: true
         };
 
-        return DamageAnalysis {
+        ShieldAnalysis {
             atk: atk_comp.0,
             atk_ratio: self.ratio_atk.0.clone(),
             hp: hp_comp.0,
@@ -378,36 +568,15 @@ impl DamageBuilder for ComplicatedDamageBuilder {
             em: em_comp.0,
             em_ratio: self.ratio_em.0.clone(),
             extra_damage: self.extra_damage.0.clone(),
-            spread_compose: HashMap::new(),
-            aggravate_compose: HashMap::new(),
-
-            bonus: HashMap::new(),
-            critical: HashMap::new(),
-            critical_damage: HashMap::new(),
-
-            melt_enhance: HashMap::new(),
-            vaporize_enhance: HashMap::new(),
-
-            healing_bonus: HashMap::new(),
             shield_strength: shield_strength_comp.0,
-            def_minus: HashMap::new(),
-            def_penetration: HashMap::new(),
-            res_minus: HashMap::new(),
+            absorption: ShieldAnalysis::absorption_map(element),
 
             element,
-            is_heal: true,
-            is_shield: false,
-
-            normal: damage_normal,
-            melt: None,
-            vaporize: None,
-            spread: None,
-            aggravate: None,
+            base,
+            result,
         }
     }
-}
 
-impl ComplicatedDamageBuilder {
     fn get_def_minus_composition(&self, attribute: &ComplicatedAttributeGraph) -> EntryType {
         let mut comp = attribute.get_attribute_composition(AttributeName::DefMinus);
         comp.merge(&self.extra_def_minus);
@@ -492,6 +661,90 @@ impl ComplicatedDamageBuilder {
         comp
     }
 
+    fn get_enhance_lunar_composition(&self, attribute: &ComplicatedAttributeGraph, lunar_type: TransformativeType) -> EntryType {
+        let mut names = vec![AttributeName::EnhanceLunarBase];
+        if let Some(name) = AttributeName::enhance_name_by_lunar_type(lunar_type) {
+            names.push(name);
+        }
+        let mut comp = attribute.get_composition_merge(&names);
+        comp.merge(&self.extra_enhance_lunar);
+        let em = self.extra_em.sum() + attribute.get_em_all();
+        if em > 0.0 {
+            comp.add_value("精通", Reaction::lunar(em));
+        }
+        comp
+    }
+
+    fn get_transformative_base_composition(&self, attribute: &ComplicatedAttributeGraph, t: TransformativeType, character_level: usize) -> EntryType {
+        let mut names = vec![AttributeName::TransformativeExtraDmg];
+        if t.is_swirl() {
+            names.push(AttributeName::SwirlExtraDmg);
+        }
+        let mut comp = attribute.get_composition_merge(&names);
+        comp.merge(&self.extra_damage);
+        comp.add_value("反应基础伤害", get_transformative_base(character_level, t));
+        comp
+    }
+
+    fn get_enhance_transformative_composition(&self, attribute: &ComplicatedAttributeGraph, t: TransformativeType) -> EntryType {
+        let mut names = Vec::new();
+        if t.is_swirl() {
+            names.push(AttributeName::EnhanceSwirlBase);
+        }
+        if let Some(name) = AttributeName::enhance_name_by_transformative_type(t) {
+            names.push(name);
+        }
+        let mut comp = attribute.get_composition_merge(&names);
+        let em = self.extra_em.sum() + attribute.get_em_all();
+        if em > 0.0 {
+            comp.add_value("精通", get_em_bonus(em));
+        }
+        comp
+    }
+
+    fn get_transformative_critical_composition(&self, attribute: &ComplicatedAttributeGraph, t: TransformativeType) -> EntryType {
+        let mut names = vec![AttributeName::CriticalTransformative];
+        if let Some(name) = AttributeName::critical_rate_name_by_transformative_type(t) {
+            names.push(name);
+        }
+        let mut comp = attribute.get_composition_merge(&names);
+        comp.merge(&self.extra_critical_rate);
+        comp
+    }
+
+    fn get_transformative_critical_damage_composition(&self, attribute: &ComplicatedAttributeGraph, t: TransformativeType) -> EntryType {
+        let mut names = vec![AttributeName::CriticalDamageTransformative];
+        if let Some(name) = AttributeName::critical_damage_name_by_transformative_type(t) {
+            names.push(name);
+        }
+        let mut comp = attribute.get_composition_merge(&names);
+        comp.merge(&self.extra_critical_damage);
+        comp
+    }
+
+    fn get_lunar_critical_composition(&self, attribute: &ComplicatedAttributeGraph, lunar_type: TransformativeType) -> EntryType {
+        let mut names = vec![
+            AttributeName::CriticalBase,
+            AttributeName::CriticalAttacking,
+        ];
+        if let Some(name) = AttributeName::critical_rate_name_by_lunar_type(lunar_type) {
+            names.push(name);
+        }
+        let mut comp = attribute.get_composition_merge(&names);
+        comp.merge(&self.extra_critical_rate);
+        comp
+    }
+
+    fn get_lunar_critical_damage_composition(&self, attribute: &ComplicatedAttributeGraph, lunar_type: TransformativeType) -> EntryType {
+        let mut names = vec![AttributeName::CriticalDamageBase];
+        if let Some(name) = AttributeName::critical_damage_name_by_lunar_type(lunar_type) {
+            names.push(name);
+        }
+        let mut comp = attribute.get_composition_merge(&names);
+        comp.merge(&self.extra_critical_damage);
+        comp
+    }
+
     fn get_critical_damage_composition(&self, attribute: &ComplicatedAttributeGraph, element: Element, skill: SkillType) -> EntryType {
         let mut names = vec![
             AttributeName::CriticalDamageBase,
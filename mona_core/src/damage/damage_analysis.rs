@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
 use crate::common::{DamageResult, Element};
+use crate::common::reaction_type::TransformativeType;
 
 #[derive(Debug)]
 #[derive(Serialize, Deserialize)]
@@ -20,9 +21,15 @@ pub struct DamageAnalysis {
     pub melt_enhance: HashMap<String, f64>,
     pub vaporize_enhance: HashMap<String, f64>,
     pub healing_bonus: HashMap<String, f64>,
+    pub incoming_healing_bonus: HashMap<String, f64>,
     pub shield_strength: HashMap<String, f64>,
+    // shield absorption multiplier against each damage element
+    pub shield_absorption: HashMap<String, f64>,
     pub spread_compose: HashMap<String, f64>,
     pub aggravate_compose: HashMap<String, f64>,
+    pub lunar_enhance: HashMap<String, f64>,
+    pub lunar_base_bonus: HashMap<String, f64>,
+    pub transformative_enhance: HashMap<String, f64>,
 
     pub def_minus: HashMap<String, f64>,
     pub def_penetration: HashMap<String, f64>,
@@ -31,6 +38,8 @@ pub struct DamageAnalysis {
     pub element: Element,
     pub is_heal: bool,
     pub is_shield: bool,
+    pub lunar_type: Option<TransformativeType>,
+    pub transformative_type: Option<TransformativeType>,
 
     pub normal: DamageResult,
     pub melt: Option<DamageResult>,
@@ -39,6 +48,8 @@ pub struct DamageAnalysis {
     pub aggravate: Option<DamageResult>,
 }
 
+#[derive(Debug)]
+#[derive(Serialize, Deserialize)]
 pub struct HealAnalysis {
     pub atk: HashMap<String, f64>,
     pub atk_ratio: HashMap<String, f64>,
@@ -46,7 +57,154 @@ pub struct HealAnalysis {
     pub hp_ratio: HashMap<String, f64>,
     pub def: HashMap<String, f64>,
     pub def_ratio: HashMap<String, f64>,
+    pub em: HashMap<String, f64>,
+    pub em_ratio: HashMap<String, f64>,
+    pub extra_damage: HashMap<String, f64>,
+    pub healing_bonus: HashMap<String, f64>,
+    // incoming healing bonus of the healed character, assumed to be the character itself
+    pub incoming_healing_bonus: HashMap<String, f64>,
+
+    pub base: f64,
+    pub result: DamageResult,
+}
+
+#[derive(Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct ShieldAnalysis {
+    pub atk: HashMap<String, f64>,
+    pub atk_ratio: HashMap<String, f64>,
+    pub hp: HashMap<String, f64>,
+    pub hp_ratio: HashMap<String, f64>,
+    pub def: HashMap<String, f64>,
+    pub def_ratio: HashMap<String, f64>,
+    pub em: HashMap<String, f64>,
+    pub em_ratio: HashMap<String, f64>,
     pub extra_damage: HashMap<String, f64>,
+    pub shield_strength: HashMap<String, f64>,
+    pub absorption: HashMap<String, f64>,
+
+    pub element: Element,
+    pub base: f64,
+    pub result: DamageResult,
+}
+
+impl ShieldAnalysis {
+    /// geo shields absorb 150% against all elements, other elemental shields absorb 250% against their own element
+    pub fn absorption_multiplier(shield_element: Element, damage_element: Element) -> f64 {
+        if shield_element == Element::Geo {
+            1.5
+        } else if shield_element == damage_element && shield_element != Element::Physical {
+            2.5
+        } else {
+            1.0
+        }
+    }
+
+    pub fn absorption_map(shield_element: Element) -> HashMap<String, f64> {
+        let mut map = HashMap::new();
+        for i in 0..8 {
+            let e = Element::from_number(i);
+            map.insert(e.to_string(), ShieldAnalysis::absorption_multiplier(shield_element, e));
+        }
+        map
+    }
+
+    /// shield hp against damage of `element`
+    pub fn effective_shield(&self, element: Element) -> f64 {
+        self.result.expectation * ShieldAnalysis::absorption_multiplier(self.element, element)
+    }
+}
+
+impl HealAnalysis {
+    pub fn into_damage_analysis(self) -> DamageAnalysis {
+        DamageAnalysis {
+            atk: self.atk,
+            atk_ratio: self.atk_ratio,
+            hp: self.hp,
+            hp_ratio: self.hp_ratio,
+            def: self.def,
+            def_ratio: self.def_ratio,
+            em: self.em,
+            em_ratio: self.em_ratio,
+            extra_damage: self.extra_damage,
+            bonus: HashMap::new(),
+            critical: HashMap::new(),
+            critical_damage: HashMap::new(),
+            melt_enhance: HashMap::new(),
+            vaporize_enhance: HashMap::new(),
+            healing_bonus: self.healing_bonus,
+            incoming_healing_bonus: self.incoming_healing_bonus,
+            shield_strength: HashMap::new(),
+            shield_absorption: HashMap::new(),
+            spread_compose: HashMap::new(),
+            aggravate_compose: HashMap::new(),
+            lunar_enhance: HashMap::new(),
+            lunar_base_bonus: HashMap::new(),
+            transformative_enhance: HashMap::new(),
+
+            def_minus: HashMap::new(),
+            def_penetration: HashMap::new(),
+            res_minus: HashMap::new(),
+
+            element: Element::Pyro,
+            is_heal: true,
+            is_shield: false,
+            lunar_type: None,
+            transformative_type: None,
+
+            normal: self.result,
+            melt: None,
+            vaporize: None,
+            spread: None,
+            aggravate: None,
+        }
+    }
+}
+
+impl ShieldAnalysis {
+    pub fn into_damage_analysis(self) -> DamageAnalysis {
+        DamageAnalysis {
+            atk: self.atk,
+            atk_ratio: self.atk_ratio,
+            hp: self.hp,
+            hp_ratio: self.hp_ratio,
+            def: self.def,
+            def_ratio: self.def_ratio,
+            em: self.em,
+            em_ratio: self.em_ratio,
+            extra_damage: self.extra_damage,
+            bonus: HashMap::new(),
+            critical: HashMap::new(),
+            critical_damage: HashMap::new(),
+            melt_enhance: HashMap::new(),
+            vaporize_enhance: HashMap::new(),
+            healing_bonus: HashMap::new(),
+            incoming_healing_bonus: HashMap::new(),
+            shield_strength: self.shield_strength,
+            shield_absorption: self.absorption,
+            spread_compose: HashMap::new(),
+            aggravate_compose: HashMap::new(),
+            lunar_enhance: HashMap::new(),
+            lunar_base_bonus: HashMap::new(),
+            transformative_enhance: HashMap::new(),
+
+            def_minus: HashMap::new(),
+            def_penetration: HashMap::new(),
+            res_minus: HashMap::new(),
+
+            element: self.element,
+            is_heal: false,
+            is_shield: true,
+            lunar_type: None,
+            transformative_type: None,
+
+            normal: self.result,
+            melt: None,
+            vaporize: None,
+            spread: None,
+            aggravate: None,
+        }
+    }
 }
 
 // #[derive(Debug)]
@@ -38,6 +38,8 @@ pub trait DamageBuilder {
 
     fn add_extra_enhance_vaporize(&mut self, key: &str, value: f64);
 
+    fn add_extra_enhance_lunar(&mut self, key: &str, value: f64);
+
     fn add_extra_def_minus(&mut self, key: &str, value: f64);
 
     fn add_extra_def_penetration(&mut self, key: &str, value: f64);
@@ -54,6 +56,28 @@ pub trait DamageBuilder {
         fumo: Option<Element>
     ) -> Self::Result;
 
+    /// lunar reaction damage, which can crit and ignores enemy's def.
+    /// if `direct` is true, the ratios added to the builder are the base damage (talents dealing lunar damage directly),
+    /// otherwise the base damage comes from the reaction level coefficient
+    fn lunar(
+        &self,
+        attribute: &Self::AttributeType,
+        enemy: &Enemy,
+        lunar_type: TransformativeType,
+        character_level: usize,
+        direct: bool,
+    ) -> Self::Result;
+
+    /// transformative reaction damage triggered by the character, lunar types are forwarded to `lunar`.
+    /// the ratios added to the builder are ignored, extra damage is added to the reaction base damage
+    fn transformative(
+        &self,
+        attribute: &Self::AttributeType,
+        enemy: &Enemy,
+        transformative_type: TransformativeType,
+        character_level: usize,
+    ) -> Self::Result;
+
     fn heal(&self, attribute: &Self::AttributeType) -> Self::Result;
 
     fn shield(&self, attribute: &Self::AttributeType, element: Element) -> Self::Result;
@@ -1,13 +1,18 @@
 use crate::attribute::{Attribute, ComplicatedAttributeGraph};
 use crate::character::character_common_data::CharacterCommonData;
-use crate::common::Element;
-use crate::damage::transformative_damage::{TransformativeDamage, transformative_damage, swirl_without_element};
+use crate::common::{DamageResult, Element};
+use crate::common::reaction_type::TransformativeType;
+use crate::damage::damage_builder::DamageBuilder;
+use crate::damage::transformative_damage::{TransformativeDamage, transformative_damage, transformative_result, swirl_without_element, lunar_damage};
 use crate::enemies::Enemy;
 
 pub struct DamageContext<'a, A> {
     pub character_common_data: &'a CharacterCommonData,
     pub attribute: &'a A,
+    // primary target
     pub enemy: &'a Enemy,
+    // targets besides `enemy`, only hit by entries evaluated with a `TargetHit`, see `damage_targets`
+    pub other_enemies: &'a [Enemy],
 }
 
 impl<'a, A: Attribute> DamageContext<'a, A> {
@@ -17,6 +22,20 @@ impl<'a, A: Attribute> DamageContext<'a, A> {
         transformative_damage::<A>(level, &self.attribute, &self.enemy)
     }
 
+    /// single reaction with crit, see `DamageBuilder::transformative` for the breakdown
+    pub fn transformative_result(&self, transformative_type: TransformativeType) -> DamageResult {
+        transformative_result::<A>(self.character_common_data.level, &self.attribute, &self.enemy, transformative_type)
+    }
+
+    /// one reaction computed by a damage builder, `ComplicatedDamageBuilder` gives the full breakdown
+    pub fn transformative_by<D: DamageBuilder<AttributeType = A>>(&self, transformative_type: TransformativeType) -> D::Result {
+        D::new().transformative(self.attribute, self.enemy, transformative_type, self.character_common_data.level)
+    }
+
+    pub fn lunar(&self, lunar_type: TransformativeType) -> DamageResult {
+        lunar_damage::<A>(self.character_common_data.level, &self.attribute, &self.enemy, lunar_type)
+    }
+
     pub fn swirl_without_element(&self) -> f64 {
         swirl_without_element::<A>(
             self.character_common_data.level, &self.attribute, 0.9
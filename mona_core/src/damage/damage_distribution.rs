@@ -0,0 +1,208 @@
+use serde::{Serialize, Deserialize};
+use crate::damage::damage_result::DamageResult;
+
+// resolution of the histogram used when hits of different values are mixed
+const BINS: usize = 4096;
+
+/// `count` independent hits, each one crits with `critical_rate`
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub struct DamageHit {
+    pub non_critical: f64,
+    pub critical: f64,
+    pub critical_rate: f64,
+    pub count: usize,
+}
+
+impl DamageHit {
+    /// crit rate is recovered from expectation = non_critical + rate * (critical - non_critical)
+    pub fn from_result(result: &DamageResult, count: usize) -> DamageHit {
+        let diff = result.critical - result.non_critical;
+        let critical_rate = if diff.abs() < 1e-9 {
+            0.0
+        } else {
+            ((result.expectation - result.non_critical) / diff).clamp(0.0, 1.0)
+        };
+
+        DamageHit {
+            non_critical: result.non_critical,
+            critical: result.critical,
+            critical_rate,
+            count
+        }
+    }
+
+    fn min(&self) -> f64 {
+        self.non_critical.min(self.critical) * self.count as f64
+    }
+
+    fn max(&self) -> f64 {
+        self.non_critical.max(self.critical) * self.count as f64
+    }
+
+    // probability of exactly k crits
+    fn binomial(&self) -> Vec<f64> {
+        let n = self.count;
+        let p = self.critical_rate;
+        if p <= 0.0 || p >= 1.0 {
+            let mut pmf = vec![0.0; n + 1];
+            pmf[if p <= 0.0 { 0 } else { n }] = 1.0;
+            return pmf;
+        }
+
+        let mut ln_fact = vec![0.0; n + 1];
+        for i in 1..=n {
+            ln_fact[i] = ln_fact[i - 1] + (i as f64).ln();
+        }
+        let (lp, lq) = (p.ln(), (1.0 - p).ln());
+        (0..=n).map(|k| {
+            (ln_fact[n] - ln_fact[k] - ln_fact[n - k] + k as f64 * lp + (n - k) as f64 * lq).exp()
+        }).collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DamageDistributionSummary {
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+    // (p, value) pairs
+    pub percentiles: Vec<(f64, f64)>,
+}
+
+/// distribution of the total damage of several hits, considering crit only
+#[derive(Default, Debug, Clone)]
+pub struct DamageDistribution {
+    pub hits: Vec<DamageHit>,
+}
+
+impl DamageDistribution {
+    pub fn new() -> DamageDistribution {
+        Default::default()
+    }
+
+    pub fn add_hit(&mut self, hit: DamageHit) {
+        if hit.count > 0 {
+            self.hits.push(hit);
+        }
+    }
+
+    pub fn add_result(&mut self, result: &DamageResult, count: usize) {
+        self.add_hit(DamageHit::from_result(result, count));
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.hits.iter().map(|h| {
+            h.count as f64 * (h.non_critical + h.critical_rate * (h.critical - h.non_critical))
+        }).sum()
+    }
+
+    pub fn variance(&self) -> f64 {
+        self.hits.iter().map(|h| {
+            let d = h.critical - h.non_critical;
+            h.count as f64 * h.critical_rate * (1.0 - h.critical_rate) * d * d
+        }).sum()
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    pub fn min(&self) -> f64 {
+        self.hits.iter().map(|h| h.min()).sum()
+    }
+
+    pub fn max(&self) -> f64 {
+        self.hits.iter().map(|h| h.max()).sum()
+    }
+
+    // (lowest value, bin width, probabilities)
+    fn histogram(&self) -> (f64, f64, Vec<f64>) {
+        let lo = self.min();
+        let width = (self.max() - lo) / BINS as f64;
+        let mut pmf = vec![0.0; BINS + 1];
+        pmf[0] = 1.0;
+        if width <= 0.0 {
+            return (lo, 0.0, pmf);
+        }
+
+        for hit in self.hits.iter() {
+            let step = (hit.critical - hit.non_critical).abs();
+            let binomial = hit.binomial();
+            // when critical < non_critical the k-th value is reached by count - k crits
+            let reversed = hit.critical < hit.non_critical;
+            let mut next = vec![0.0; BINS + 1];
+            for (i, &p) in pmf.iter().enumerate() {
+                if p == 0.0 {
+                    continue;
+                }
+                for (k, &q) in binomial.iter().enumerate() {
+                    let q = if reversed { binomial[hit.count - k] } else { q };
+                    let j = (i + (k as f64 * step / width).round() as usize).min(BINS);
+                    next[j] += p * q;
+                }
+            }
+            pmf = next;
+        }
+
+        (lo, width, pmf)
+    }
+
+    /// smallest damage d with P(total <= d) >= p
+    pub fn percentile(&self, p: f64) -> f64 {
+        let p = p.clamp(0.0, 1.0);
+        // single group of identical hits is solved exactly
+        if self.hits.len() == 1 {
+            let hit = &self.hits[0];
+            let step = hit.critical - hit.non_critical;
+            let binomial = hit.binomial();
+            let mut acc = 0.0;
+            for k in 0..=hit.count {
+                // iterate in ascending damage order
+                let crits = if step >= 0.0 { k } else { hit.count - k };
+                acc += binomial[crits];
+                if acc >= p - 1e-12 {
+                    return hit.non_critical * hit.count as f64 + crits as f64 * step;
+                }
+            }
+            return self.max();
+        }
+
+        let (lo, width, pmf) = self.histogram();
+        let mut acc = 0.0;
+        for (i, q) in pmf.iter().enumerate() {
+            acc += q;
+            if acc >= p - 1e-12 {
+                return lo + i as f64 * width;
+            }
+        }
+        self.max()
+    }
+
+    /// probability that the total damage reaches `threshold`, e.g. a boss hp pool
+    pub fn exceed_probability(&self, threshold: f64) -> f64 {
+        if threshold <= self.min() {
+            return 1.0;
+        }
+        if threshold > self.max() {
+            return 0.0;
+        }
+
+        let (lo, width, pmf) = self.histogram();
+        pmf.iter().enumerate()
+            .filter(|(i, _)| lo + *i as f64 * width >= threshold)
+            .map(|(_, q)| q)
+            .sum::<f64>()
+            .min(1.0)
+    }
+
+    pub fn summary(&self, percentiles: &[f64]) -> DamageDistributionSummary {
+        DamageDistributionSummary {
+            mean: self.mean(),
+            std_dev: self.std_dev(),
+            min: self.min(),
+            max: self.max(),
+            percentiles: percentiles.iter().map(|&p| (p, self.percentile(p))).collect(),
+        }
+    }
+}
@@ -1,8 +1,8 @@
-use std::ops::Mul;
+use std::ops::{Add, Mul};
 use serde::{Serialize, Deserialize};
 use crate::damage::transformative_damage::TransformativeDamage;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 #[derive(Deserialize, Serialize)]
 pub struct DamageResult {
     pub critical: f64,
@@ -27,7 +27,21 @@ impl Mul<f64> for DamageResult {
     }
 }
 
-#[derive(Debug)]
+impl Add for DamageResult {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        DamageResult {
+            critical: self.critical + rhs.critical,
+            non_critical: self.non_critical + rhs.non_critical,
+            expectation: self.expectation + rhs.expectation,
+            is_shield: self.is_shield,
+            is_heal: self.is_heal
+        }
+    }
+}
+
+#[derive(Debug, Default)]
 #[derive(Serialize, Deserialize)]
 pub struct SimpleDamageResult {
     pub normal: DamageResult,
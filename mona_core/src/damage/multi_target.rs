@@ -0,0 +1,134 @@
+use serde::{Serialize, Deserialize};
+use crate::attribute::{Attribute, SimpleAttributeGraph2};
+use crate::character::characters::damage;
+use crate::character::skill_config::CharacterSkillConfig;
+use crate::common::{DamageResult, Element};
+use crate::damage::{DamageContext, SimpleDamageBuilder};
+use crate::damage::damage_result::SimpleDamageResult;
+use crate::damage::transformative_damage::TransformativeDamage;
+
+// a `DamageContext` holds the primary enemy and the other targets, each damage entry is given a `TargetHit`
+// deciding which targets it hits. per-target res shred comes from the enemies themselves
+
+/// how a damage entry spreads over several targets
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum TargetHit {
+    // only the primary target
+    #[default]
+    Single,
+    // every target once, e.g. aoe bursts or swirl
+    All,
+    // the first n targets once each
+    Targets(usize),
+    // n hits bouncing between targets, never hitting the same target twice in a row
+    Bounce(usize),
+}
+
+impl TargetHit {
+    /// hit count on each target, the first target is the primary one
+    pub fn hits_per_target(&self, target_count: usize) -> Vec<usize> {
+        let mut hits = vec![0; target_count];
+        if target_count == 0 {
+            return hits;
+        }
+
+        match *self {
+            TargetHit::Single => hits[0] = 1,
+            TargetHit::All => hits.iter_mut().for_each(|x| *x = 1),
+            TargetHit::Targets(n) => hits.iter_mut().take(n).for_each(|x| *x = 1),
+            TargetHit::Bounce(n) => {
+                if target_count == 1 {
+                    hits[0] = n.min(1);
+                } else {
+                    for i in 0..n {
+                        hits[i % target_count] += 1;
+                    }
+                }
+            }
+        }
+        hits
+    }
+}
+
+fn add_option(a: Option<DamageResult>, b: Option<DamageResult>) -> Option<DamageResult> {
+    match (a, b) {
+        (Some(x), Some(y)) => Some(x + y),
+        (x, None) => x,
+        (None, y) => y,
+    }
+}
+
+fn scale_result(result: &SimpleDamageResult, count: f64) -> SimpleDamageResult {
+    SimpleDamageResult {
+        normal: result.normal * count,
+        melt: result.melt.map(|x| x * count),
+        vaporize: result.vaporize.map(|x| x * count),
+        spread: result.spread.map(|x| x * count),
+        aggravate: result.aggravate.map(|x| x * count),
+        is_heal: result.is_heal,
+        is_shield: result.is_shield,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MultiTargetDamageResult {
+    pub hits: Vec<usize>,
+    // damage of a single hit on each target
+    pub targets: Vec<SimpleDamageResult>,
+    // sum over all targets and hits
+    pub total: SimpleDamageResult,
+}
+
+impl<'a, A: Attribute> DamageContext<'a, A> {
+    pub fn target_count(&self) -> usize {
+        self.other_enemies.len() + 1
+    }
+
+    /// context against a single target, 0 is the primary one
+    pub fn target_context(&self, index: usize) -> DamageContext<'a, A> {
+        DamageContext {
+            character_common_data: self.character_common_data,
+            attribute: self.attribute,
+            enemy: if index == 0 { self.enemy } else { &self.other_enemies[index - 1] },
+            other_enemies: &[],
+        }
+    }
+
+    pub fn transformative_targets(&self) -> Vec<TransformativeDamage> {
+        (0..self.target_count()).map(|i| self.target_context(i).transformative()).collect()
+    }
+}
+
+impl DamageContext<'_, SimpleAttributeGraph2> {
+    pub fn damage_targets(&self, skill_index: usize, skill_config: &CharacterSkillConfig, fumo: Option<Element>, hit: TargetHit) -> MultiTargetDamageResult {
+        let hits = hit.hits_per_target(self.target_count());
+        let targets: Vec<SimpleDamageResult> = (0..self.target_count())
+            .map(|i| damage::<SimpleDamageBuilder>(&self.target_context(i), skill_index, skill_config, fumo))
+            .collect();
+
+        let mut total = SimpleDamageResult {
+            is_heal: targets[0].is_heal,
+            is_shield: targets[0].is_shield,
+            ..Default::default()
+        };
+        for (result, &count) in targets.iter().zip(hits.iter()) {
+            let scaled = scale_result(result, count as f64);
+            total.normal = total.normal + scaled.normal;
+            total.melt = add_option(total.melt, scaled.melt);
+            total.vaporize = add_option(total.vaporize, scaled.vaporize);
+            total.spread = add_option(total.spread, scaled.spread);
+            total.aggravate = add_option(total.aggravate, scaled.aggravate);
+        }
+
+        MultiTargetDamageResult {
+            hits,
+            targets,
+            total
+        }
+    }
+
+    /// sum of `damage_targets` over all targets and hits
+    pub fn damage_total(&self, skill_index: usize, skill_config: &CharacterSkillConfig, fumo: Option<Element>, hit: TargetHit) -> SimpleDamageResult {
+        self.damage_targets(skill_index, skill_config, fumo, hit).total
+    }
+}
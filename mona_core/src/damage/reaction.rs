@@ -12,4 +12,8 @@ impl Reaction {
     pub fn catalyze(em: f64) -> f64 {
         em * 5.0 / (em + 1200.0)
     }
+
+    pub fn lunar(em: f64) -> f64 {
+        em * 6.0 / (em + 2000.0)
+    }
 }
\ No newline at end of file
@@ -1,9 +1,11 @@
 use crate::attribute::{Attribute, AttributeCommon, AttributeName, SimpleAttributeGraph2};
 use crate::common::{DamageResult, Element, SkillType};
+use crate::common::reaction_type::TransformativeType;
 use crate::damage::damage_builder::DamageBuilder;
 use crate::damage::damage_result::SimpleDamageResult;
 use crate::damage::level_coefficient::LEVEL_MULTIPLIER;
 use crate::damage::reaction::Reaction;
+use crate::damage::transformative_damage::{get_em_bonus, get_lunar_direct_coefficient, get_transformative_base, lunar_damage_input, lunar_damage_result, transformative_damage_input, transformative_damage_result, LunarExtra, TransformativeExtra};
 use crate::enemies::Enemy;
 
 pub struct SimpleDamageBuilder {
@@ -26,6 +28,7 @@ pub struct SimpleDamageBuilder {
 
     pub extra_enhance_melt: f64,
     pub extra_enhance_vaporize: f64,
+    pub extra_enhance_lunar: f64,
     pub enhance_melt: f64,
     pub enhance_vaporize: f64,
     pub extra_em: f64,
@@ -95,6 +98,10 @@ impl DamageBuilder for SimpleDamageBuilder {
         self.extra_enhance_vaporize += value
     }
 
+    fn add_extra_enhance_lunar(&mut self, _key: &str, value: f64) {
+        self.extra_enhance_lunar += value
+    }
+
     fn add_extra_def_minus(&mut self, _key: &str, value: f64) {
         self.extra_def_minus += value
     }
@@ -247,6 +254,62 @@ impl DamageBuilder for SimpleDamageBuilder {
         }
     }
 
+    fn lunar(&self, attribute: &Self::AttributeType, enemy: &Enemy, lunar_type: TransformativeType, character_level: usize, direct: bool) -> Self::Result {
+        let atk = attribute.get_atk() + self.extra_atk;
+        let def = attribute.get_def() + self.extra_def;
+        let hp = attribute.get_hp() + self.extra_hp;
+        let em = self.extra_em + attribute.get_em_all();
+
+        let base = if direct {
+            (self.ratio_atk * atk + self.ratio_def * def + self.ratio_hp * hp + self.ratio_em * em) * get_lunar_direct_coefficient(lunar_type)
+        } else {
+            get_transformative_base(character_level, lunar_type)
+        } + self.extra_damage;
+        let extra = LunarExtra {
+            em: self.extra_em,
+            enhance: self.extra_enhance_lunar,
+            critical: self.extra_critical_rate,
+            critical_damage: self.extra_critical_damage,
+            res_minus: self.extra_res_minus,
+        };
+        let result = lunar_damage_result(&lunar_damage_input(attribute, enemy, lunar_type, base, &extra));
+
+        SimpleDamageResult {
+            normal: result,
+            melt: None,
+            vaporize: None,
+            spread: None,
+            aggravate: None,
+            is_heal: false,
+            is_shield: false,
+        }
+    }
+
+    fn transformative(&self, attribute: &Self::AttributeType, enemy: &Enemy, transformative_type: TransformativeType, character_level: usize) -> Self::Result {
+        if transformative_type.is_lunar() {
+            return self.lunar(attribute, enemy, transformative_type, character_level, false);
+        }
+
+        let extra = TransformativeExtra {
+            em: self.extra_em,
+            damage: self.extra_damage,
+            critical: self.extra_critical_rate,
+            critical_damage: self.extra_critical_damage,
+            res_minus: self.extra_res_minus,
+        };
+        let result = transformative_damage_result(&transformative_damage_input(character_level, attribute, enemy, transformative_type, &extra));
+
+        SimpleDamageResult {
+            normal: result,
+            melt: None,
+            vaporize: None,
+            spread: None,
+            aggravate: None,
+            is_heal: false,
+            is_shield: false,
+        }
+    }
+
     fn heal(&self, attribute: &Self::AttributeType) -> Self::Result {
         let atk = attribute.get_atk() + self.extra_atk;
         let def = attribute.get_def() + self.extra_def;
@@ -255,7 +318,8 @@ impl DamageBuilder for SimpleDamageBuilder {
         let base = self.ratio_def * def + self.ratio_hp * hp + self.ratio_atk * atk + self.extra_damage;
 
         let healing_bonus = attribute.get_value(AttributeName::HealingBonus);
-        let heal_value = base * (1.0 + healing_bonus);
+        let incoming_healing_bonus = attribute.get_value(AttributeName::IncomingHealingBonus);
+        let heal_value = base * (1.0 + healing_bonus + incoming_healing_bonus);
         let result = {
             DamageResult {
                 critical: heal_value,
@@ -329,6 +393,7 @@ impl SimpleDamageBuilder {
             enhance_melt: 0.0,
             enhance_vaporize: 0.0,
             extra_enhance_vaporize: 0.0,
+            extra_enhance_lunar: 0.0,
             extra_em: 0.0
         }
     }
@@ -1,9 +1,10 @@
 use wasm_bindgen::prelude::*;
 use crate::attribute::{Attribute, AttributeName, AttributeCommon};
-use crate::common::{Element, SkillType};
+use crate::common::{DamageResult, Element, SkillType};
 use crate::common::reaction_type::TransformativeType;
 use crate::enemies::Enemy;
 use crate::damage::level_coefficient::{LEVEL_MULTIPLIER, CRYSTALLIZE_BASE};
+use crate::damage::reaction::Reaction;
 
 #[wasm_bindgen]
 pub struct TransformativeDamage {
@@ -20,6 +21,8 @@ pub struct TransformativeDamage {
     pub burgeon: f64,
     pub burning: f64,
     pub crystallize: f64,
+    pub lunar_charged: f64,
+    pub lunar_bloom: f64,
 }
 
 #[inline]
@@ -33,6 +36,17 @@ fn get_reaction_coefficient(t: TransformativeType) -> f64 {
         ElectroCharged => 1.2,
         Shatter => 1.5,
         Burning => 0.25,
+        LunarCharged => 1.8,
+        LunarBloom => 1.0,
+    }
+}
+
+/// coefficient applied to talent ratios when a skill deals lunar reaction damage directly
+#[inline]
+pub fn get_lunar_direct_coefficient(t: TransformativeType) -> f64 {
+    match t {
+        TransformativeType::LunarCharged => 3.0,
+        _ => 1.0,
     }
 }
 
@@ -46,6 +60,74 @@ pub fn get_em_bonus(em: f64) -> f64 {
     16.0 * em / (em + 2000.0)
 }
 
+/// summed inputs of one lunar reaction hit
+pub struct LunarDamageInput {
+    /// reaction level coefficient or the talent ratios, extra damage included
+    pub base_damage: f64,
+    pub base_bonus: f64,
+    pub enhance: f64,
+    pub critical: f64,
+    pub critical_damage: f64,
+    pub resistance_ratio: f64,
+}
+
+/// bonuses a damage builder adds on top of the attribute
+#[derive(Default)]
+pub struct LunarExtra {
+    pub em: f64,
+    pub enhance: f64,
+    pub critical: f64,
+    pub critical_damage: f64,
+    pub res_minus: f64,
+}
+
+/// the only lunar damage formula, lunar reactions can crit and ignore enemy's def
+pub fn lunar_damage_result(input: &LunarDamageInput) -> DamageResult {
+    let critical = input.critical.clamp(0.0, 1.0);
+    let non_critical = input.base_damage * (1.0 + input.base_bonus) * (1.0 + input.enhance) * input.resistance_ratio;
+    DamageResult {
+        critical: non_critical * (1.0 + input.critical_damage),
+        non_critical,
+        expectation: non_critical * (1.0 + critical * input.critical_damage),
+        is_heal: false,
+        is_shield: false
+    }
+}
+
+pub fn lunar_damage_input<A: Attribute>(attribute: &A, enemy: &Enemy, t: TransformativeType, base_damage: f64, extra: &LunarExtra) -> LunarDamageInput {
+    let element = t.damage_element();
+
+    let mut enhance = Reaction::lunar(attribute.get_em_all() + extra.em) + attribute.get_value(AttributeName::EnhanceLunarBase) + extra.enhance;
+    let mut critical = attribute.get_value(AttributeName::CriticalBase) + attribute.get_value(AttributeName::CriticalAttacking) + extra.critical;
+    let mut critical_damage = attribute.get_value(AttributeName::CriticalDamageBase) + extra.critical_damage;
+    if let Some(name) = AttributeName::enhance_name_by_lunar_type(t) {
+        enhance += attribute.get_value(name);
+    }
+    if let Some(name) = AttributeName::critical_rate_name_by_lunar_type(t) {
+        critical += attribute.get_value(name);
+    }
+    if let Some(name) = AttributeName::critical_damage_name_by_lunar_type(t) {
+        critical_damage += attribute.get_value(name);
+    }
+
+    let res_minus = extra.res_minus + attribute.get_enemy_res_minus(element, SkillType::NoneType);
+
+    LunarDamageInput {
+        base_damage,
+        base_bonus: attribute.get_value(AttributeName::LunarBaseDmgBonus),
+        enhance,
+        critical,
+        critical_damage,
+        resistance_ratio: enemy.get_resistance_ratio(element, res_minus),
+    }
+}
+
+/// lunar reaction triggered by elemental reaction, the base damage is the reaction level coefficient
+pub fn lunar_damage<A: Attribute>(level: usize, attribute: &A, enemy: &Enemy, t: TransformativeType) -> DamageResult {
+    let base_damage = get_transformative_base(level, t);
+    lunar_damage_result(&lunar_damage_input(attribute, enemy, t, base_damage, &Default::default()))
+}
+
 pub fn transformative_damage_simple(level: usize, em: f64, enemy: &Enemy) -> TransformativeDamage {
     let base_swirl = get_transformative_base(level, TransformativeType::SwirlPyro);
 
@@ -81,6 +163,9 @@ pub fn transformative_damage_simple(level: usize, em: f64, enemy: &Enemy) -> Tra
     let dmg_burning = base_burning * res_ratio_pyro * (1.0 + em_bonus);
     let shield_crystallize = CRYSTALLIZE_BASE[level - 1] * (1.0 + 40.0 / 9.0 * em / (em + 1400.0));
 
+    let lunar_bonus = Reaction::lunar(em);
+    let dmg_lunar_charged = get_transformative_base(level, TransformativeType::LunarCharged) * res_ratio_electro * (1.0 + lunar_bonus);
+    let dmg_lunar_bloom = get_transformative_base(level, TransformativeType::LunarBloom) * res_ratio_dendro * (1.0 + lunar_bonus);
 
     TransformativeDamage {
         swirl_cryo: dmg_swirl_cryo,
@@ -96,84 +181,115 @@ pub fn transformative_damage_simple(level: usize, em: f64, enemy: &Enemy) -> Tra
         burgeon: dmg_burgeon,
         burning: dmg_burning,
         crystallize: shield_crystallize,
+        lunar_charged: dmg_lunar_charged,
+        lunar_bloom: dmg_lunar_bloom,
     }
 }
 
-pub fn transformative_damage<A: Attribute>(level: usize, attribute: &A, enemy: &Enemy) -> TransformativeDamage {
-    let enhance_base = attribute.get_value(AttributeName::EnhanceSwirlBase);
-    let enhance_swirl_pyro = attribute.get_value(AttributeName::EnhanceSwirlPyro) + enhance_base;
-    let enhance_swirl_cryo = attribute.get_value(AttributeName::EnhanceSwirlCryo) + enhance_base;
-    let enhance_swirl_electro = attribute.get_value(AttributeName::EnhanceSwirlElectro) + enhance_base;
-    let enhance_swirl_hydro = attribute.get_value(AttributeName::EnhanceSwirlHydro) + enhance_base;
-    let enhance_overload = attribute.get_value(AttributeName::EnhanceOverload);
-    let enhance_superconduct = attribute.get_value(AttributeName::EnhanceSuperconduct);
-    let enhance_shatter = attribute.get_value(AttributeName::EnhanceShatter);
-    let enhance_electro_charged = attribute.get_value(AttributeName::EnhanceElectroCharged);
-    let enhance_bloom = attribute.get_value(AttributeName::EnhanceBloom);
-    let enhance_hyperbloom = attribute.get_value(AttributeName::EnhanceHyperbloom);
-    let enhance_burgeon = attribute.get_value(AttributeName::EnhanceBurgeon);
-    let enhance_burning = attribute.get_value(AttributeName::EnhanceBurning);
-
-    let mut base_swirl = get_transformative_base(level, TransformativeType::SwirlPyro);
-
+/// flat base damage added to the reaction level coefficient
+pub fn get_transformative_extra_damage<A: Attribute>(attribute: &A, t: TransformativeType) -> f64 {
+    let mut extra = attribute.get_value(AttributeName::TransformativeExtraDmg);
     // introduced by Yumemizuki Mizuki C1
-    base_swirl += attribute.get_value(AttributeName::SwirlExtraDmg);
+    if t.is_swirl() {
+        extra += attribute.get_value(AttributeName::SwirlExtraDmg);
+    }
+    extra
+}
 
-    let base_overload = get_transformative_base(level, TransformativeType::Overload);
-    let base_superconduct = get_transformative_base(level, TransformativeType::Superconduct);
-    let base_shatter = get_transformative_base(level, TransformativeType::Shatter);
-    let base_electro_charged = get_transformative_base(level, TransformativeType::ElectroCharged);
-    let base_bloom = get_transformative_base(level, TransformativeType::Bloom);
-    let base_hyperbloom = get_transformative_base(level, TransformativeType::Hyperbloom);
-    let base_burgeon = base_hyperbloom;
-    let base_burning = get_transformative_base(level, TransformativeType::Burning);
+/// summed inputs of one transformative reaction hit
+pub struct TransformativeDamageInput {
+    /// reaction level coefficient plus flat extra damage
+    pub base_damage: f64,
+    pub enhance: f64,
+    pub critical: f64,
+    pub critical_damage: f64,
+    pub resistance_ratio: f64,
+}
 
-    let em = attribute.get_em_all();
-    let em_bonus = get_em_bonus(em);
+/// bonuses a damage builder adds on top of the attribute
+#[derive(Default)]
+pub struct TransformativeExtra {
+    pub em: f64,
+    pub damage: f64,
+    pub critical: f64,
+    pub critical_damage: f64,
+    pub res_minus: f64,
+}
+
+/// the only transformative damage formula, transformative reactions can only crit with reaction-specific crit (e.g. Nahida C2)
+pub fn transformative_damage_result(input: &TransformativeDamageInput) -> DamageResult {
+    let critical = input.critical.clamp(0.0, 1.0);
+    let non_critical = input.base_damage * (1.0 + input.enhance) * input.resistance_ratio;
+    DamageResult {
+        critical: non_critical * (1.0 + input.critical_damage),
+        non_critical,
+        expectation: non_critical * (1.0 + critical * input.critical_damage),
+        is_heal: false,
+        is_shield: false
+    }
+}
+
+pub fn transformative_damage_input<A: Attribute>(level: usize, attribute: &A, enemy: &Enemy, t: TransformativeType, extra: &TransformativeExtra) -> TransformativeDamageInput {
+    let element = t.damage_element();
+    let base_damage = get_transformative_base(level, t) + get_transformative_extra_damage(attribute, t) + extra.damage;
+
+    let mut enhance = get_em_bonus(attribute.get_em_all() + extra.em);
+    if t.is_swirl() {
+        enhance += attribute.get_value(AttributeName::EnhanceSwirlBase);
+    }
+    if let Some(name) = AttributeName::enhance_name_by_transformative_type(t) {
+        enhance += attribute.get_value(name);
+    }
+
+    let mut critical = attribute.get_value(AttributeName::CriticalTransformative) + extra.critical;
+    let mut critical_damage = attribute.get_value(AttributeName::CriticalDamageTransformative) + extra.critical_damage;
+    if let Some(name) = AttributeName::critical_rate_name_by_transformative_type(t) {
+        critical += attribute.get_value(name);
+    }
+    if let Some(name) = AttributeName::critical_damage_name_by_transformative_type(t) {
+        critical_damage += attribute.get_value(name);
+    }
+
+    let res_minus = extra.res_minus + attribute.get_enemy_res_minus(element, SkillType::NoneType);
+
+    TransformativeDamageInput {
+        base_damage,
+        enhance,
+        critical,
+        critical_damage,
+        resistance_ratio: enemy.get_resistance_ratio(element, res_minus),
+    }
+}
 
-    // skill type is not used, thus passing normal attack
-    let res_pyro = attribute.get_enemy_res_minus(Element::Pyro, SkillType::NormalAttack);
-    let res_cryo = attribute.get_enemy_res_minus(Element::Cryo, SkillType::NormalAttack);
-    let res_electro = attribute.get_enemy_res_minus(Element::Electro, SkillType::NormalAttack);
-    let res_hydro = attribute.get_enemy_res_minus(Element::Hydro, SkillType::NormalAttack);
-    let res_physical = attribute.get_enemy_res_minus(Element::Physical, SkillType::NormalAttack);
-    let res_dendro = attribute.get_enemy_res_minus(Element::Dendro, SkillType::NormalAttack);
-
-    let res_ratio_pyro = enemy.get_resistance_ratio(Element::Pyro, res_pyro);
-    let res_ratio_cryo = enemy.get_resistance_ratio(Element::Cryo, res_cryo);
-    let res_ratio_electro = enemy.get_resistance_ratio(Element::Electro, res_electro);
-    let res_ratio_hydro = enemy.get_resistance_ratio(Element::Hydro, res_hydro);
-    let res_ratio_physical = enemy.get_resistance_ratio(Element::Physical, res_physical);
-    let res_ratio_dendro = enemy.get_resistance_ratio(Element::Dendro, res_dendro);
-
-    let dmg_swirl_pyro = base_swirl * res_ratio_pyro * (1.0 + em_bonus + enhance_swirl_pyro);
-    let dmg_swirl_cryo = base_swirl * res_ratio_cryo * (1.0 + em_bonus + enhance_swirl_cryo);
-    let dmg_swirl_electro = base_swirl * res_ratio_electro * (1.0 + em_bonus + enhance_swirl_electro);
-    let dmg_swirl_hydro = base_swirl * res_ratio_hydro * (1.0 + em_bonus + enhance_swirl_hydro);
-    let dmg_overload = base_overload * res_ratio_pyro * (1.0 + em_bonus + enhance_overload);
-    let dmg_superconduct = base_superconduct * res_ratio_cryo * (1.0 + em_bonus + enhance_superconduct);
-    let dmg_shatter = base_shatter * res_ratio_physical * (1.0 + em_bonus + enhance_shatter);
-    let dmg_electro_charged = base_electro_charged * res_ratio_electro * (1.0 + em_bonus + enhance_electro_charged);
-    let dmg_bloom = base_bloom * res_ratio_dendro * (1.0 + em_bonus + enhance_bloom);
-    let dmg_hyperbloom = base_hyperbloom * res_ratio_dendro * (1.0 + em_bonus + enhance_hyperbloom);
-    let dmg_burgeon = base_burgeon * res_ratio_dendro * (1.0 + em_bonus + enhance_burgeon);
-    let dmg_burning = base_burning * res_ratio_pyro * (1.0 + em_bonus + enhance_burning);
+/// single reaction with crit, lunar types are forwarded to `lunar_damage`
+pub fn transformative_result<A: Attribute>(level: usize, attribute: &A, enemy: &Enemy, t: TransformativeType) -> DamageResult {
+    if t.is_lunar() {
+        return lunar_damage(level, attribute, enemy, t);
+    }
+    transformative_damage_result(&transformative_damage_input(level, attribute, enemy, t, &Default::default()))
+}
+
+pub fn transformative_damage<A: Attribute>(level: usize, attribute: &A, enemy: &Enemy) -> TransformativeDamage {
+    let em = attribute.get_em_all();
     let shield_crystallize = CRYSTALLIZE_BASE[level - 1] * (1.0 + 40.0 / 9.0 * em / (em + 1400.0));
+    let dmg = |t: TransformativeType| transformative_result(level, attribute, enemy, t).expectation;
 
     TransformativeDamage {
-        swirl_cryo: dmg_swirl_cryo,
-        swirl_hydro: dmg_swirl_hydro,
-        swirl_pyro: dmg_swirl_pyro,
-        swirl_electro: dmg_swirl_electro,
-        overload: dmg_overload,
-        electro_charged: dmg_electro_charged,
-        shatter: dmg_shatter,
-        superconduct: dmg_superconduct,
-        bloom: dmg_bloom,
-        hyperbloom: dmg_hyperbloom,
-        burgeon: dmg_burgeon,
-        burning: dmg_burning,
+        swirl_cryo: dmg(TransformativeType::SwirlCryo),
+        swirl_hydro: dmg(TransformativeType::SwirlHydro),
+        swirl_pyro: dmg(TransformativeType::SwirlPyro),
+        swirl_electro: dmg(TransformativeType::SwirlElectro),
+        overload: dmg(TransformativeType::Overload),
+        electro_charged: dmg(TransformativeType::ElectroCharged),
+        shatter: dmg(TransformativeType::Shatter),
+        superconduct: dmg(TransformativeType::Superconduct),
+        bloom: dmg(TransformativeType::Bloom),
+        hyperbloom: dmg(TransformativeType::Hyperbloom),
+        burgeon: dmg(TransformativeType::Burgeon),
+        burning: dmg(TransformativeType::Burning),
         crystallize: shield_crystallize,
+        lunar_charged: dmg(TransformativeType::LunarCharged),
+        lunar_bloom: dmg(TransformativeType::LunarBloom),
     }
 }
 
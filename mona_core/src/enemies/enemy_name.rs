@@ -0,0 +1,217 @@
+use serde::{Serialize, Deserialize};
+use strum_macros::{Display, EnumIter};
+use crate::common::Element;
+use crate::enemies::Enemy;
+
+// immune enemies take no damage of that element, see `Enemy::get_resistance_ratio`
+pub const IMMUNE: f64 = f64::INFINITY;
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq)]
+pub struct EnemyResistance {
+    pub electro: f64,
+    pub pyro: f64,
+    pub hydro: f64,
+    pub cryo: f64,
+    pub anemo: f64,
+    pub geo: f64,
+    pub dendro: f64,
+    pub physical: f64,
+}
+
+impl EnemyResistance {
+    pub const fn all(value: f64) -> EnemyResistance {
+        EnemyResistance {
+            electro: value,
+            pyro: value,
+            hydro: value,
+            cryo: value,
+            anemo: value,
+            geo: value,
+            dendro: value,
+            physical: value,
+        }
+    }
+
+    pub fn get(&self, element: Element) -> f64 {
+        match element {
+            Element::Electro => self.electro,
+            Element::Pyro => self.pyro,
+            Element::Hydro => self.hydro,
+            Element::Cryo => self.cryo,
+            Element::Anemo => self.anemo,
+            Element::Geo => self.geo,
+            Element::Dendro => self.dendro,
+            Element::Physical => self.physical,
+        }
+    }
+
+    pub fn with(mut self, element: Element, value: f64) -> EnemyResistance {
+        match element {
+            Element::Electro => self.electro = value,
+            Element::Pyro => self.pyro = value,
+            Element::Hydro => self.hydro = value,
+            Element::Cryo => self.cryo = value,
+            Element::Anemo => self.anemo = value,
+            Element::Geo => self.geo = value,
+            Element::Dendro => self.dendro = value,
+            Element::Physical => self.physical = value,
+        }
+        self
+    }
+
+    pub fn add(self, element: Element, value: f64) -> EnemyResistance {
+        let res = self.get(element);
+        self.with(element, res + value)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum EnemyState {
+    #[default]
+    Normal,
+    // e.g. elemental shields of abyss mages or the shell of hypostases
+    Shielded,
+    // absorbed element of geovishaps or azhdaha
+    Infused(Element),
+    // azhdaha absorbs a second element in later phases
+    Phase2(Element, Element),
+}
+
+/// resistance presets of common enemies, every enemy has the standard level + 100 defense,
+/// enemies with special defense are not covered
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Display, EnumIter)]
+pub enum EnemyName {
+    // 10% for all elements
+    Common,
+    Hilichurl,
+    RuinGuard,
+    RuinHunter,
+    RuinGrader,
+    PyroSlime,
+    HydroSlime,
+    ElectroSlime,
+    CryoSlime,
+    AnemoSlime,
+    GeoSlime,
+    DendroSlime,
+    PyroAbyssMage,
+    HydroAbyssMage,
+    ElectroAbyssMage,
+    CryoAbyssMage,
+    GeovishapHatchling,
+    Geovishap,
+    PrimoGeovishap,
+    Azhdaha,
+    MaguuKenki,
+    PyroHypostasis,
+    HydroHypostasis,
+    ElectroHypostasis,
+    CryoHypostasis,
+    AnemoHypostasis,
+    GeoHypostasis,
+    DendroHypostasis,
+}
+
+impl EnemyName {
+    pub fn base_resistance(&self) -> EnemyResistance {
+        use EnemyName::*;
+        let common = EnemyResistance::all(0.1);
+        match *self {
+            Common | Hilichurl => common,
+            RuinGuard => common.with(Element::Physical, 0.7),
+            RuinHunter | RuinGrader => common.with(Element::Physical, 0.5),
+            PyroSlime => common.with(Element::Pyro, IMMUNE),
+            HydroSlime => common.with(Element::Hydro, IMMUNE),
+            ElectroSlime => common.with(Element::Electro, IMMUNE),
+            CryoSlime => common.with(Element::Cryo, IMMUNE),
+            AnemoSlime => common.with(Element::Anemo, IMMUNE),
+            GeoSlime => common.with(Element::Geo, IMMUNE),
+            DendroSlime => common.with(Element::Dendro, IMMUNE),
+            PyroAbyssMage => common.with(Element::Pyro, 0.5),
+            HydroAbyssMage => common.with(Element::Hydro, 0.5),
+            ElectroAbyssMage => common.with(Element::Electro, 0.5),
+            CryoAbyssMage => common.with(Element::Cryo, 0.5),
+            GeovishapHatchling | Geovishap | PrimoGeovishap => common.with(Element::Geo, 0.5).with(Element::Physical, 0.3),
+            Azhdaha => common.with(Element::Geo, 0.7).with(Element::Physical, 0.4),
+            MaguuKenki => common.with(Element::Anemo, 0.5).with(Element::Cryo, 0.5),
+            PyroHypostasis => common.with(Element::Pyro, IMMUNE),
+            HydroHypostasis => common.with(Element::Hydro, IMMUNE),
+            ElectroHypostasis => common.with(Element::Electro, IMMUNE),
+            CryoHypostasis => common.with(Element::Cryo, IMMUNE),
+            AnemoHypostasis => common.with(Element::Anemo, IMMUNE),
+            GeoHypostasis => common.with(Element::Geo, IMMUNE),
+            DendroHypostasis => common.with(Element::Dendro, IMMUNE),
+        }
+    }
+
+    pub fn element(&self) -> Option<Element> {
+        use EnemyName::*;
+        match *self {
+            PyroSlime | PyroAbyssMage | PyroHypostasis => Some(Element::Pyro),
+            HydroSlime | HydroAbyssMage | HydroHypostasis => Some(Element::Hydro),
+            ElectroSlime | ElectroAbyssMage | ElectroHypostasis => Some(Element::Electro),
+            CryoSlime | CryoAbyssMage | CryoHypostasis => Some(Element::Cryo),
+            AnemoSlime | AnemoHypostasis => Some(Element::Anemo),
+            GeoSlime | GeoHypostasis | GeovishapHatchling | Geovishap | PrimoGeovishap | Azhdaha => Some(Element::Geo),
+            DendroSlime | DendroHypostasis => Some(Element::Dendro),
+            _ => None
+        }
+    }
+
+    /// states with different resistances, the first one is the default
+    pub fn states(&self) -> Vec<EnemyState> {
+        use EnemyName::*;
+        let infusions = [Element::Pyro, Element::Hydro, Element::Electro, Element::Cryo];
+        match *self {
+            PyroAbyssMage | HydroAbyssMage | ElectroAbyssMage | CryoAbyssMage => vec![EnemyState::Normal, EnemyState::Shielded],
+            PyroHypostasis | HydroHypostasis | ElectroHypostasis | CryoHypostasis
+            | AnemoHypostasis | GeoHypostasis | DendroHypostasis => vec![EnemyState::Normal, EnemyState::Shielded],
+            Geovishap | PrimoGeovishap => {
+                let mut states = vec![EnemyState::Normal];
+                states.extend(infusions.iter().map(|&e| EnemyState::Infused(e)));
+                states
+            },
+            Azhdaha => {
+                let mut states = vec![EnemyState::Normal];
+                states.extend(infusions.iter().map(|&e| EnemyState::Infused(e)));
+                for (i, &e1) in infusions.iter().enumerate() {
+                    states.extend(infusions[i + 1..].iter().map(|&e2| EnemyState::Phase2(e1, e2)));
+                }
+                states
+            },
+            _ => vec![EnemyState::Normal]
+        }
+    }
+
+    pub fn resistance(&self, state: EnemyState) -> EnemyResistance {
+        use EnemyName::*;
+        let base = self.base_resistance();
+        match (*self, state) {
+            (PyroAbyssMage | HydroAbyssMage | ElectroAbyssMage | CryoAbyssMage, EnemyState::Shielded) => {
+                // the elemental shield ignores its own element and takes reduced physical damage
+                base.with(self.element().unwrap(), IMMUNE).with(Element::Physical, 0.5)
+            },
+            (PyroHypostasis | HydroHypostasis | ElectroHypostasis | CryoHypostasis
+            | AnemoHypostasis | GeoHypostasis | DendroHypostasis, EnemyState::Shielded) => EnemyResistance::all(IMMUNE),
+            (Geovishap | PrimoGeovishap | Azhdaha, EnemyState::Infused(e)) => base.add(e, 0.5),
+            (Azhdaha, EnemyState::Phase2(e1, e2)) => base.add(e1, 0.5).add(e2, 0.5),
+            _ => base
+        }
+    }
+
+    pub fn create(&self, level: i32, state: EnemyState) -> Enemy {
+        let res = self.resistance(state);
+        Enemy {
+            level,
+            electro_res: res.electro,
+            pyro_res: res.pyro,
+            hydro_res: res.hydro,
+            cryo_res: res.cryo,
+            anemo_res: res.anemo,
+            geo_res: res.geo,
+            dendro_res: res.dendro,
+            physical_res: res.physical,
+        }
+    }
+}
@@ -1,3 +1,5 @@
 pub use enemy::Enemy;
+pub use enemy_name::{EnemyName, EnemyState, EnemyResistance};
 
-pub mod enemy;
\ No newline at end of file
+pub mod enemy;
+pub mod enemy_name;
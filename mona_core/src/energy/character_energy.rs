@@ -0,0 +1,123 @@
+use serde::{Serialize, Deserialize};
+use crate::character::CharacterName;
+use crate::common::Element;
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub struct CharacterEnergyData {
+    pub burst_cost: f64,
+    // average particles generated by one elemental skill cast
+    pub skill_particles: f64,
+    pub skill_cooldown: f64,
+    pub element: Element,
+}
+
+// particle counts are community-measured averages over common usage (press/hold mixed), users can override them in team config
+pub fn get_character_energy_data(name: CharacterName) -> CharacterEnergyData {
+    use CharacterName::*;
+    let (burst_cost, skill_particles, skill_cooldown) = match name {
+        AetherAnemo => (60.0, 2.5, 8.0),
+        Albedo => (40.0, 5.0, 4.0),
+        Alhaitham => (70.0, 5.0, 18.0),
+        Aloy => (40.0, 5.0, 20.0),
+        Amber => (40.0, 4.0, 15.0),
+        AratakiItto => (70.0, 3.5, 10.0),
+        Baizhu => (80.0, 3.0, 10.0),
+        Barbara => (80.0, 0.0, 32.0),
+        Beidou => (80.0, 2.5, 7.5),
+        Bennett => (60.0, 2.5, 5.0),
+        Candace => (60.0, 2.5, 6.0),
+        Charlotte => (80.0, 3.0, 12.0),
+        Chongyun => (40.0, 4.0, 15.0),
+        Collei => (60.0, 3.0, 12.0),
+        Cyno => (80.0, 3.0, 7.5),
+        Dehya => (70.0, 4.0, 20.0),
+        Diluc => (40.0, 4.0, 10.0),
+        Diona => (80.0, 3.0, 6.0),
+        Dori => (80.0, 2.0, 9.0),
+        Eula => (80.0, 3.0, 4.0),
+        Faruzan => (80.0, 2.0, 6.0),
+        Fischl => (60.0, 10.0, 25.0),
+        Freminet => (60.0, 3.0, 10.0),
+        Furina => (60.0, 4.0, 20.0),
+        Ganyu => (60.0, 4.0, 10.0),
+        Gorou => (80.0, 2.0, 10.0),
+        HuTao => (60.0, 5.0, 16.0),
+        Jean => (80.0, 2.5, 6.0),
+        KaedeharaKazuha => (60.0, 3.5, 6.0),
+        Kaeya => (60.0, 3.5, 6.0),
+        KamisatoAyaka => (80.0, 4.5, 10.0),
+        KamisatoAyato => (80.0, 4.5, 12.0),
+        Kaveh => (80.0, 2.5, 6.0),
+        Keqing => (40.0, 2.5, 7.5),
+        Klee => (60.0, 4.0, 20.0),
+        KujouSara => (80.0, 3.0, 10.0),
+        KukiShinobu => (60.0, 4.0, 15.0),
+        Layla => (40.0, 4.0, 12.0),
+        Lisa => (80.0, 4.0, 16.0),
+        Lynette => (70.0, 3.0, 12.0),
+        Lyney => (60.0, 3.0, 15.0),
+        Mona => (60.0, 3.5, 12.0),
+        Nahida => (50.0, 3.0, 6.0),
+        Neuvillette => (70.0, 4.0, 12.0),
+        Nilou => (70.0, 4.0, 18.0),
+        Ningguang => (40.0, 3.5, 12.0),
+        Noelle => (60.0, 2.0, 24.0),
+        Mika => (70.0, 3.0, 15.0),
+        Qiqi => (80.0, 0.0, 30.0),
+        RaidenShogun => (90.0, 9.0, 10.0),
+        Razor => (80.0, 3.5, 6.0),
+        Rosaria => (60.0, 3.0, 6.0),
+        SangonomiyaKokomi => (70.0, 5.0, 20.0),
+        Sayu => (80.0, 3.0, 6.0),
+        Shenhe => (80.0, 3.5, 10.0),
+        ShikanoinHeizou => (40.0, 2.5, 10.0),
+        Sucrose => (80.0, 4.0, 15.0),
+        Tartaglia => (60.0, 6.0, 6.0),
+        Thoma => (80.0, 3.5, 15.0),
+        Tighnari => (40.0, 3.5, 12.0),
+        Venti => (60.0, 3.5, 6.0),
+        Wanderer => (60.0, 4.0, 6.0),
+        Wriothesley => (70.0, 4.0, 16.0),
+        Xiangling => (80.0, 4.0, 12.0),
+        Xiao => (70.0, 3.0, 10.0),
+        Xingqiu => (80.0, 5.0, 21.0),
+        Xinyan => (60.0, 4.5, 18.0),
+        YaeMiko => (90.0, 1.67, 4.0),
+        Yanfei => (80.0, 3.0, 9.0),
+        Yaoyao => (70.0, 2.0, 15.0),
+        Yelan => (70.0, 4.0, 10.0),
+        Yoimiya => (60.0, 4.0, 18.0),
+        Yunjin => (60.0, 2.5, 9.0),
+        Zhongli => (40.0, 2.0, 12.0),
+        Kirara => (60.0, 2.5, 8.0),
+        Chevreuse => (60.0, 3.0, 15.0),
+        Navia => (60.0, 3.0, 9.0),
+        Gaming => (60.0, 2.0, 6.0),
+        Xianyun => (70.0, 3.0, 12.0),
+        Chiori => (50.0, 2.5, 16.0),
+        Arlecchino => (60.0, 2.0, 30.0),
+        Clorinde => (60.0, 4.0, 16.0),
+        Sigewinne => (70.0, 4.0, 18.0),
+        Sethos => (60.0, 2.0, 8.0),
+        Emilie => (50.0, 3.0, 14.0),
+        Kachina => (70.0, 4.0, 20.0),
+        Mualani => (60.0, 4.0, 15.0),
+        Kinich => (70.0, 3.0, 10.0),
+        Xilonen => (60.0, 3.0, 9.0),
+        Ororon => (60.0, 3.0, 15.0),
+        Chasca => (60.0, 4.0, 6.5),
+        Lanyan => (60.0, 3.0, 10.0),
+        Mavuika => (0.0, 5.0, 15.0),
+        Citlali => (60.0, 4.0, 16.0),
+        YumemizukiMizuki => (70.0, 5.0, 15.0),
+        Iansan => (70.0, 4.0, 16.0),
+        Varesa => (70.0, 3.0, 15.0),
+    };
+
+    CharacterEnergyData {
+        burst_cost,
+        skill_particles,
+        skill_cooldown,
+        element: name.get_static_data().element,
+    }
+}
@@ -0,0 +1,143 @@
+use serde::{Serialize, Deserialize};
+use crate::character::CharacterName;
+use crate::common::Element;
+use super::character_energy::get_character_energy_data;
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub struct ParticleSource {
+    // None means clear (neutral) particles
+    pub element: Option<Element>,
+    pub count: f64,
+    // orbs restore 3 times as much energy as particles
+    #[serde(default)]
+    pub orb: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EnergyMemberConfig {
+    pub name: CharacterName,
+    // skill casts in one rotation, defaults to rotation length / skill cooldown
+    #[serde(default)]
+    pub skill_casts: Option<f64>,
+    #[serde(default)]
+    pub skill_particles: Option<f64>,
+    #[serde(default)]
+    pub burst_cost: Option<f64>,
+    // ratio of the rotation this member stays on field and catches particles at full value
+    #[serde(default)]
+    pub on_field_ratio: f64,
+    // energy restored directly each rotation (weapons, constellations), not affected by energy recharge
+    #[serde(default)]
+    pub flat_energy: f64,
+    // particles generated by this member besides skills, e.g. favonius weapons
+    #[serde(default)]
+    pub extra_particles: Vec<ParticleSource>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EnergyTeamConfig {
+    pub members: Vec<EnergyMemberConfig>,
+    pub rotation_length: f64,
+    // particles and orbs dropped by enemies each rotation
+    #[serde(default)]
+    pub enemy_particles: Vec<ParticleSource>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EnergyMemberResult {
+    pub name: CharacterName,
+    pub burst_cost: f64,
+    // energy from particles at 100% energy recharge
+    pub particle_energy: f64,
+    pub flat_energy: f64,
+    // None if particles cannot refill the burst
+    pub recharge_demand: Option<f64>,
+}
+
+/// which member's recharge demand is used, see `TargetFunctionConfig::set_recharge_demand`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EnergyDemandConfig {
+    pub team: EnergyTeamConfig,
+    pub member: usize,
+}
+
+impl EnergyDemandConfig {
+    pub fn recharge_demand(&self) -> Option<f64> {
+        let results = EnergyTeamConfig::calc(&self.team);
+        results.get(self.member).and_then(|x| x.recharge_demand)
+    }
+}
+
+fn particle_value(particle: Option<Element>, receiver: Element) -> f64 {
+    match particle {
+        None => 2.0,
+        Some(e) if e == receiver => 3.0,
+        Some(_) => 1.0,
+    }
+}
+
+// off-field members receive less energy, depending on team size
+fn off_field_multiplier(team_size: usize) -> f64 {
+    match team_size {
+        0 | 1 => 1.0,
+        2 => 0.8,
+        3 => 0.7,
+        _ => 0.6,
+    }
+}
+
+impl EnergyTeamConfig {
+    pub fn calc(&self) -> Vec<EnergyMemberResult> {
+        let mut sources: Vec<ParticleSource> = self.enemy_particles.clone();
+        for member in self.members.iter() {
+            let data = get_character_energy_data(member.name);
+            let casts = member.skill_casts.unwrap_or_else(|| {
+                if data.skill_cooldown > 0.0 {
+                    (self.rotation_length / data.skill_cooldown).floor().max(1.0)
+                } else {
+                    1.0
+                }
+            });
+            sources.push(ParticleSource {
+                element: Some(data.element),
+                count: casts * member.skill_particles.unwrap_or(data.skill_particles),
+                orb: false,
+            });
+            sources.extend(member.extra_particles.iter().cloned());
+        }
+
+        let off_field = off_field_multiplier(self.members.len());
+        let mut results = Vec::with_capacity(self.members.len());
+        for member in self.members.iter() {
+            let data = get_character_energy_data(member.name);
+            let on_field_ratio = member.on_field_ratio.clamp(0.0, 1.0);
+            let receive = on_field_ratio + (1.0 - on_field_ratio) * off_field;
+
+            let mut particle_energy = 0.0;
+            for source in sources.iter() {
+                let orb = if source.orb { 3.0 } else { 1.0 };
+                particle_energy += source.count * orb * particle_value(source.element, data.element) * receive;
+            }
+
+            let burst_cost = member.burst_cost.unwrap_or(data.burst_cost);
+            let remaining = burst_cost - member.flat_energy;
+            let recharge_demand = if remaining <= 0.0 {
+                Some(1.0)
+            } else if particle_energy > 0.0 {
+                Some((remaining / particle_energy).max(1.0))
+            } else {
+                None
+            };
+
+            results.push(EnergyMemberResult {
+                name: member.name,
+                burst_cost,
+                particle_energy,
+                flat_energy: member.flat_energy,
+                recharge_demand
+            });
+        }
+
+        results
+    }
+}
@@ -0,0 +1,5 @@
+pub use character_energy::{CharacterEnergyData, get_character_energy_data};
+pub use energy_model::{ParticleSource, EnergyMemberConfig, EnergyTeamConfig, EnergyMemberResult, EnergyDemandConfig};
+
+mod character_energy;
+mod energy_model;
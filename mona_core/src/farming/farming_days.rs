@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+/// days until an event in each simulation, simulations where it never happened count as never
+#[derive(Debug, Clone)]
+pub struct FarmingDays {
+    // ascending
+    days: Vec<usize>,
+    simulations: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FarmingDaysSummary {
+    /// probability that the event happens within the simulated days
+    pub probability: f64,
+    /// mean over the simulations where the event happened
+    pub mean: Option<f64>,
+    // `None` if less than that fraction of the simulations reached the event
+    pub p25: Option<usize>,
+    pub p50: Option<usize>,
+    pub p75: Option<usize>,
+    pub p90: Option<usize>,
+}
+
+impl FarmingDays {
+    pub fn new(days: &[Option<usize>]) -> FarmingDays {
+        let mut reached: Vec<usize> = days.iter().flatten().cloned().collect();
+        reached.sort();
+
+        FarmingDays {
+            days: reached,
+            simulations: days.len(),
+        }
+    }
+
+    pub fn probability(&self) -> f64 {
+        if self.simulations == 0 {
+            0.0
+        } else {
+            self.days.len() as f64 / self.simulations as f64
+        }
+    }
+
+    /// probability that the event happens within `days`
+    pub fn probability_within(&self, days: usize) -> f64 {
+        if self.simulations == 0 {
+            return 0.0;
+        }
+        let count = self.days.partition_point(|&x| x <= days);
+        count as f64 / self.simulations as f64
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        if self.days.is_empty() {
+            None
+        } else {
+            Some(self.days.iter().sum::<usize>() as f64 / self.days.len() as f64)
+        }
+    }
+
+    /// smallest number of days within which the event happens with probability `p`
+    pub fn percentile(&self, p: f64) -> Option<usize> {
+        if self.simulations == 0 {
+            return None;
+        }
+        let rank = ((p.clamp(0.0, 1.0) * self.simulations as f64).ceil() as usize).max(1);
+        self.days.get(rank - 1).cloned()
+    }
+
+    pub fn summary(&self) -> FarmingDaysSummary {
+        FarmingDaysSummary {
+            probability: self.probability(),
+            mean: self.mean(),
+            p25: self.percentile(0.25),
+            p50: self.percentile(0.5),
+            p75: self.percentile(0.75),
+            p90: self.percentile(0.9),
+        }
+    }
+}
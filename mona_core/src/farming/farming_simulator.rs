@@ -0,0 +1,206 @@
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+use crate::artifacts::{Artifact, ArtifactGenerator, ArtifactList, ArtifactSetName, ArtifactSlotName, ArtifactSource};
+use crate::artifacts::effect_config::ArtifactEffectConfig;
+use crate::attribute::{AttributeGraphError, AttributeUtils, SimpleAttributeGraph2};
+use crate::buffs::Buff;
+use crate::character::Character;
+use crate::enemies::Enemy;
+use crate::farming::farming_days::{FarmingDays, FarmingDaysSummary};
+use crate::target_functions::TargetFunction;
+use crate::upgrade_predicate::artifact_upgrader::ArtifactUpgrader;
+use crate::weapon::Weapon;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FarmingConfig {
+    /// the two sets of the domain, each drop is one of them with equal probability
+    pub domain: [ArtifactSetName; 2],
+    #[serde(default = "default_daily_resin")]
+    pub daily_resin: usize,
+    #[serde(default = "default_resin_per_run")]
+    pub resin_per_run: usize,
+    /// probability of a second five star artifact in a run
+    #[serde(default = "default_extra_drop_probability")]
+    pub extra_drop_probability: f64,
+    /// relative gain of the target value to wait for, 0.05 for 5%
+    pub improvement: f64,
+    pub max_days: usize,
+    pub simulations: usize,
+    // fixed seed for reproducible simulation
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+fn default_daily_resin() -> usize {
+    180
+}
+
+fn default_resin_per_run() -> usize {
+    20
+}
+
+fn default_extra_drop_probability() -> f64 {
+    0.07
+}
+
+/// each drop is only tried in place of the equipped piece of its slot, so switching to the 4-piece of the domain,
+/// which pays off only once several pieces are replaced together, is not found
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FarmingSimulationResult {
+    /// target value of the initial equipment
+    pub baseline: f64,
+    /// days until the target value improves by `improvement`
+    pub improvement: FarmingDaysSummary,
+    /// days until the piece of each slot is first replaced, in slot order
+    pub slot_replacement: Vec<(ArtifactSlotName, FarmingDaysSummary)>,
+    /// mean relative gain of the target value at the end of each day,
+    /// a flat curve means the build is at diminishing returns for this domain
+    pub mean_improvement_by_day: Vec<f64>,
+}
+
+/// the build being farmed for
+pub struct FarmingSetup {
+    pub character: Character<SimpleAttributeGraph2>,
+    pub weapon: Weapon<SimpleAttributeGraph2>,
+    pub target_function: Box<dyn TargetFunction>,
+    pub enemy: Enemy,
+    pub buffs: Vec<Box<dyn Buff<SimpleAttributeGraph2>>>,
+    pub artifact_config: ArtifactEffectConfig,
+    pub equipped: Vec<Artifact>,
+}
+
+/// farms a domain day by day, levels every drop with the upgrader and equips a max level drop when it raises the target value
+pub struct FarmingSimulator {
+    setup: FarmingSetup,
+    upgrader: ArtifactUpgrader,
+}
+
+struct FarmingRun {
+    improvement_day: Option<usize>,
+    slot_replacement_days: [Option<usize>; 5],
+    value_by_day: Vec<f64>,
+}
+
+impl FarmingSimulator {
+    pub fn new(setup: FarmingSetup, upgrader: ArtifactUpgrader) -> FarmingSimulator {
+        FarmingSimulator {
+            setup,
+            upgrader,
+        }
+    }
+
+    fn value(&self, equipment: &[Option<Artifact>; 5]) -> Result<f64, AttributeGraphError> {
+        let artifacts: Vec<&Artifact> = equipment.iter().flatten().collect();
+        let list = ArtifactList { artifacts: &artifacts };
+        let setup = &self.setup;
+        let attribute = AttributeUtils::create_attribute_from_big_config(
+            &list,
+            &setup.artifact_config,
+            &setup.character,
+            &setup.weapon,
+            &setup.buffs
+        )?;
+
+        Ok(setup.target_function.target(&attribute, &setup.character, &setup.weapon, &artifacts, &setup.enemy, &[]))
+    }
+
+    fn initial_equipment(&self) -> [Option<Artifact>; 5] {
+        let mut equipment: [Option<Artifact>; 5] = Default::default();
+        for artifact in self.setup.equipped.iter() {
+            equipment[artifact.slot as usize] = Some(artifact.clone());
+        }
+        equipment
+    }
+
+    fn run<R: Rng>(&self, config: &FarmingConfig, baseline: f64, generator: &mut ArtifactGenerator<R>) -> FarmingRun {
+        let mut equipment = self.initial_equipment();
+        let mut value = baseline;
+        let target = baseline + baseline.abs() * config.improvement;
+
+        let mut result = FarmingRun {
+            improvement_day: None,
+            slot_replacement_days: [None; 5],
+            value_by_day: Vec::with_capacity(config.max_days),
+        };
+
+        let resin_per_run = config.resin_per_run.max(1);
+        let mut resin = 0;
+        for day in 1..=config.max_days {
+            resin += config.daily_resin;
+            while resin >= resin_per_run {
+                resin -= resin_per_run;
+
+                let drops = if generator.rng.gen_bool(config.extra_drop_probability.clamp(0.0, 1.0)) { 2 } else { 1 };
+                for _ in 0..drops {
+                    let set_name = config.domain[generator.rng.gen_range(0..2)];
+                    let slot: ArtifactSlotName = num::FromPrimitive::from_usize(generator.rng.gen_range(0..5)).unwrap();
+                    let mut artifact = generator.generate(set_name, slot, 5, ArtifactSource::Domain);
+                    // the policy gives up on the artifact
+                    if !self.upgrader.upgrade_with_rng(&mut artifact, &mut generator.rng) {
+                        continue;
+                    }
+
+                    let index = slot as usize;
+                    let old = equipment[index].replace(artifact);
+                    // a combination with invalid edges is never an improvement
+                    let new_value = self.value(&equipment).unwrap_or(f64::NEG_INFINITY);
+                    if new_value > value {
+                        value = new_value;
+                        if result.slot_replacement_days[index].is_none() {
+                            result.slot_replacement_days[index] = Some(day);
+                        }
+                    } else {
+                        equipment[index] = old;
+                    }
+                }
+            }
+
+            if result.improvement_day.is_none() && value >= target {
+                result.improvement_day = Some(day);
+            }
+            result.value_by_day.push(value);
+        }
+
+        result
+    }
+
+    pub fn simulate(&self, config: &FarmingConfig) -> Result<FarmingSimulationResult, AttributeGraphError> {
+        let mut generator = ArtifactGenerator::new(match config.seed {
+            Some(x) => StdRng::seed_from_u64(x),
+            None => StdRng::from_entropy()
+        });
+
+        let baseline = self.value(&self.initial_equipment())?;
+        let runs: Vec<FarmingRun> = (0..config.simulations)
+            .map(|_| self.run(config, baseline, &mut generator))
+            .collect();
+
+        let improvement_days: Vec<Option<usize>> = runs.iter().map(|x| x.improvement_day).collect();
+        let slot_replacement = (0..5)
+            .map(|i| {
+                let slot: ArtifactSlotName = num::FromPrimitive::from_usize(i).unwrap();
+                let days: Vec<Option<usize>> = runs.iter().map(|x| x.slot_replacement_days[i]).collect();
+                (slot, FarmingDays::new(&days).summary())
+            })
+            .collect();
+
+        let scale = if baseline == 0.0 { 1.0 } else { baseline.abs() };
+        let mean_improvement_by_day = (0..config.max_days)
+            .map(|day| {
+                if runs.is_empty() {
+                    return 0.0;
+                }
+                let total: f64 = runs.iter().map(|x| x.value_by_day[day]).sum();
+                (total / runs.len() as f64 - baseline) / scale
+            })
+            .collect();
+
+        Ok(FarmingSimulationResult {
+            baseline,
+            improvement: FarmingDays::new(&improvement_days).summary(),
+            slot_replacement,
+            mean_improvement_by_day,
+        })
+    }
+}
@@ -0,0 +1,5 @@
+pub mod farming_days;
+pub mod farming_simulator;
+
+pub use farming_days::{FarmingDays, FarmingDaysSummary};
+pub use farming_simulator::{FarmingConfig, FarmingSetup, FarmingSimulator, FarmingSimulationResult};
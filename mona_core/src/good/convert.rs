@@ -0,0 +1,312 @@
+use serde::{Deserialize, Serialize};
+use crate::artifacts::{artifact_main_stat_value, Artifact};
+use crate::character::CharacterName;
+use crate::weapon::WeaponName;
+use super::format::{GoodArtifact, GoodCharacter, GoodDocument, GoodSubStat, GoodTalent, GoodWeapon};
+use super::keys::*;
+
+const GOOD_FORMAT: &str = "GOOD";
+const GOOD_VERSION: i32 = 2;
+
+// max level before each ascension, a character at one of these levels may be ascended or not
+const ASCENSION_MAX_LEVELS: [i32; 6] = [20, 40, 50, 60, 70, 80];
+
+fn ascend_from_good(level: i32, ascension: i32) -> bool {
+    ascension >= 1 && ASCENSION_MAX_LEVELS.get(ascension as usize - 1) == Some(&level)
+}
+
+fn ascension_to_good(level: i32, ascend: bool) -> i32 {
+    let passed = ASCENSION_MAX_LEVELS.iter().filter(|&&x| x < level).count() as i32;
+    if ascend && ASCENSION_MAX_LEVELS.contains(&level) {
+        passed + 1
+    } else {
+        passed
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GoodArtifactEntry {
+    pub artifact: Artifact,
+    // the equipping character
+    pub location: Option<CharacterName>,
+    pub lock: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GoodCharacterEntry {
+    pub name: CharacterName,
+    pub level: usize,
+    pub ascend: bool,
+    pub constellation: i32,
+    pub skill1: usize,
+    pub skill2: usize,
+    pub skill3: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GoodWeaponEntry {
+    pub name: WeaponName,
+    pub level: i32,
+    pub ascend: bool,
+    pub refine: i32,
+    pub location: Option<CharacterName>,
+    pub lock: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GoodEntryKind {
+    Artifact,
+    Character,
+    Weapon,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum GoodSkipReason {
+    UnknownKeys(Vec<GoodUnknownKey>),
+    // a known character key mona has no character for, e.g. the geo traveler
+    UnsupportedCharacter(String),
+    // a known stat key that can not be a main stat, e.g. flat def
+    InvalidMainStat(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GoodSkippedEntry {
+    pub kind: GoodEntryKind,
+    // index in the array of its kind in the document
+    pub index: usize,
+    pub reason: GoodSkipReason,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct GoodImport {
+    pub artifacts: Vec<GoodArtifactEntry>,
+    pub characters: Vec<GoodCharacterEntry>,
+    pub weapons: Vec<GoodWeaponEntry>,
+    // entries left out of the import, ignored on export
+    #[serde(default)]
+    pub skipped: Vec<GoodSkippedEntry>,
+}
+
+// collects unknown keys of an entry, so that all of them are reported at once
+#[derive(Default)]
+struct KeyResolver {
+    unknown: Vec<GoodUnknownKey>,
+}
+
+impl KeyResolver {
+    fn resolve<T>(&mut self, kind: GoodKeyKind, key: &str, f: fn(&str) -> Option<T>) -> Option<T> {
+        let result = f(key);
+        if result.is_none() && !self.unknown.iter().any(|x| x.kind == kind && x.key == key) {
+            self.unknown.push(GoodUnknownKey {
+                kind,
+                key: key.to_string(),
+            });
+        }
+        result
+    }
+
+    // empty locations are not equipped, unknown ones are still errors
+    fn location(&mut self, key: &str) -> Result<Option<CharacterName>, ()> {
+        if key.is_empty() {
+            Ok(None)
+        } else {
+            self.resolve(GoodKeyKind::Character, key, location_from_good).map(Some).ok_or(())
+        }
+    }
+
+    fn skip_reason(&mut self) -> GoodSkipReason {
+        GoodSkipReason::UnknownKeys(std::mem::take(&mut self.unknown))
+    }
+
+    fn artifact(&mut self, id: u64, good: &GoodArtifact) -> Result<GoodArtifactEntry, GoodSkipReason> {
+        let set_name = self.resolve(GoodKeyKind::Set, &good.set_key, set_from_good);
+        let slot = self.resolve(GoodKeyKind::Slot, &good.slot_key, slot_from_good);
+        let main_stat = self.resolve(GoodKeyKind::Stat, &good.main_stat_key, stat_from_good);
+        let location = self.location(&good.location);
+
+        // exporters pad missing sub stats with an empty key
+        let mut sub_stats = Vec::new();
+        let mut sub_stats_ok = true;
+        for sub_stat in good.substats.iter().filter(|x| !x.key.is_empty()) {
+            match self.resolve(GoodKeyKind::Stat, &sub_stat.key, stat_from_good) {
+                Some(stat) => {
+                    let value = if is_good_percentage_key(&sub_stat.key) { sub_stat.value / 100.0 } else { sub_stat.value };
+                    sub_stats.push((stat, value));
+                },
+                None => sub_stats_ok = false
+            }
+        }
+
+        let (set_name, slot, main_stat, location) = match (set_name, slot, main_stat, location) {
+            (Some(set_name), Some(slot), Some(main_stat), Ok(location)) if sub_stats_ok => (set_name, slot, main_stat, location),
+            _ => return Err(self.skip_reason())
+        };
+        let main_stat_value = artifact_main_stat_value(main_stat, good.rarity, good.level)
+            .ok_or_else(|| GoodSkipReason::InvalidMainStat(good.main_stat_key.clone()))?;
+
+        Ok(GoodArtifactEntry {
+            artifact: Artifact {
+                set_name,
+                slot,
+                level: good.level,
+                star: good.rarity,
+                sub_stats,
+                main_stat: (main_stat, main_stat_value),
+                id,
+            },
+            location,
+            lock: good.lock,
+        })
+    }
+
+    fn character(&mut self, good: &GoodCharacter) -> Option<GoodCharacterEntry> {
+        let name = self.resolve(GoodKeyKind::Character, &good.key, character_from_good)?;
+        Some(GoodCharacterEntry {
+            name,
+            level: good.level as usize,
+            ascend: ascend_from_good(good.level, good.ascension),
+            constellation: good.constellation,
+            // GOOD talent levels start at 1, mona skill levels start at 0
+            skill1: (good.talent.auto - 1).max(0) as usize,
+            skill2: (good.talent.skill - 1).max(0) as usize,
+            skill3: (good.talent.burst - 1).max(0) as usize,
+        })
+    }
+
+    fn weapon(&mut self, good: &GoodWeapon) -> Option<GoodWeaponEntry> {
+        let name = self.resolve(GoodKeyKind::Weapon, &good.key, weapon_from_good);
+        let location = self.location(&good.location);
+        Some(GoodWeaponEntry {
+            name: name?,
+            level: good.level,
+            ascend: ascend_from_good(good.level, good.ascension),
+            refine: good.refinement,
+            location: location.ok()?,
+            lock: good.lock,
+        })
+    }
+}
+
+impl GoodDocument {
+    pub fn from_json(s: &str) -> Result<GoodDocument, GoodError> {
+        let document: GoodDocument = serde_json::from_str(s).map_err(|e| GoodError::Json(e.to_string()))?;
+        if document.format != GOOD_FORMAT {
+            return Err(GoodError::InvalidFormat(document.format));
+        }
+        Ok(document)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    /// artifact ids are their indices in the document, main stat values are derived from rarity and level,
+    /// entries with unknown keys are skipped and reported in `GoodImport::skipped`
+    pub fn import(&self) -> GoodImport {
+        let mut resolver = KeyResolver::default();
+        let mut result = GoodImport::default();
+        let mut skip = |kind: GoodEntryKind, index: usize, reason: GoodSkipReason| result.skipped.push(GoodSkippedEntry { kind, index, reason });
+
+        let mut artifacts = Vec::new();
+        for (index, artifact) in self.artifacts.iter().flatten().enumerate() {
+            match resolver.artifact(index as u64, artifact) {
+                Ok(x) => artifacts.push(x),
+                Err(reason) => skip(GoodEntryKind::Artifact, index, reason)
+            }
+        }
+        let mut characters = Vec::new();
+        for (index, character) in self.characters.iter().flatten().enumerate() {
+            if is_unsupported_character_key(&character.key) {
+                skip(GoodEntryKind::Character, index, GoodSkipReason::UnsupportedCharacter(character.key.clone()));
+                continue;
+            }
+            match resolver.character(character) {
+                Some(x) => characters.push(x),
+                None => skip(GoodEntryKind::Character, index, resolver.skip_reason())
+            }
+        }
+        let mut weapons = Vec::new();
+        for (index, weapon) in self.weapons.iter().flatten().enumerate() {
+            match resolver.weapon(weapon) {
+                Some(x) => weapons.push(x),
+                None => skip(GoodEntryKind::Weapon, index, resolver.skip_reason())
+            }
+        }
+
+        result.artifacts = artifacts;
+        result.characters = characters;
+        result.weapons = weapons;
+        result
+    }
+}
+
+impl GoodArtifact {
+    /// converts a single artifact, reporting its unknown keys
+    pub fn to_artifact(&self, id: u64) -> Result<GoodArtifactEntry, GoodError> {
+        let mut resolver = KeyResolver::default();
+        resolver.artifact(id, self).map_err(|reason| match reason {
+            GoodSkipReason::UnknownKeys(keys) => GoodError::UnknownKeys(keys),
+            GoodSkipReason::InvalidMainStat(key) => GoodError::InvalidMainStat(key),
+            GoodSkipReason::UnsupportedCharacter(key) => GoodError::UnknownKeys(vec![GoodUnknownKey { kind: GoodKeyKind::Character, key }]),
+        })
+    }
+}
+
+fn optional_location_to_good(location: Option<CharacterName>) -> String {
+    location.map_or_else(String::new, location_to_good)
+}
+
+impl GoodImport {
+    /// sub stat values are rounded as shown in game, main stat values are not part of the format
+    pub fn export(&self, source: &str) -> GoodDocument {
+        let artifacts = self.artifacts.iter().map(|entry| {
+            let artifact = &entry.artifact;
+            GoodArtifact {
+                set_key: set_to_good(artifact.set_name),
+                slot_key: slot_to_good(artifact.slot).to_string(),
+                level: artifact.level,
+                rarity: artifact.star,
+                main_stat_key: stat_to_good(artifact.main_stat.0).to_string(),
+                location: optional_location_to_good(entry.location),
+                lock: entry.lock,
+                substats: artifact.sub_stats.iter().map(|&(stat, value)| {
+                    let key = stat_to_good(stat);
+                    GoodSubStat {
+                        key: key.to_string(),
+                        value: if is_good_percentage_key(key) { (value * 1000.0).round() / 10.0 } else { value.round() },
+                    }
+                }).collect(),
+            }
+        }).collect();
+
+        let characters = self.characters.iter().map(|entry| GoodCharacter {
+            key: character_to_good(entry.name),
+            level: entry.level as i32,
+            constellation: entry.constellation,
+            ascension: ascension_to_good(entry.level as i32, entry.ascend),
+            talent: GoodTalent {
+                auto: entry.skill1 as i32 + 1,
+                skill: entry.skill2 as i32 + 1,
+                burst: entry.skill3 as i32 + 1,
+            },
+        }).collect();
+
+        let weapons = self.weapons.iter().map(|entry| GoodWeapon {
+            key: weapon_to_good(entry.name),
+            level: entry.level,
+            ascension: ascension_to_good(entry.level, entry.ascend),
+            refinement: entry.refine,
+            location: optional_location_to_good(entry.location),
+            lock: entry.lock,
+        }).collect();
+
+        GoodDocument {
+            format: GOOD_FORMAT.to_string(),
+            version: GOOD_VERSION,
+            source: source.to_string(),
+            characters: Some(characters),
+            artifacts: Some(artifacts),
+            weapons: Some(weapons),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+// the Genshin Open Object Description (GOOD) json format shared by community tools,
+// percentage stats are in percent and keys are strings
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GoodDocument {
+    pub format: String,
+    pub version: i32,
+    pub source: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub characters: Option<Vec<GoodCharacter>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifacts: Option<Vec<GoodArtifact>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weapons: Option<Vec<GoodWeapon>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GoodArtifact {
+    pub set_key: String,
+    pub slot_key: String,
+    pub level: i32,
+    pub rarity: i32,
+    pub main_stat_key: String,
+    // key of the equipping character, empty if not equipped
+    #[serde(default)]
+    pub location: String,
+    #[serde(default)]
+    pub lock: bool,
+    #[serde(default)]
+    pub substats: Vec<GoodSubStat>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GoodSubStat {
+    pub key: String,
+    pub value: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GoodCharacter {
+    pub key: String,
+    pub level: i32,
+    pub constellation: i32,
+    pub ascension: i32,
+    pub talent: GoodTalent,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GoodTalent {
+    pub auto: i32,
+    pub skill: i32,
+    pub burst: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GoodWeapon {
+    pub key: String,
+    pub level: i32,
+    pub ascension: i32,
+    pub refinement: i32,
+    #[serde(default)]
+    pub location: String,
+    #[serde(default)]
+    pub lock: bool,
+}
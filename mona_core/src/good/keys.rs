@@ -0,0 +1,184 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use crate::artifacts::{ArtifactSetName, ArtifactSlotName};
+use crate::character::CharacterName;
+use crate::common::StatName;
+use crate::weapon::WeaponName;
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GoodKeyKind {
+    Set,
+    Slot,
+    Stat,
+    Character,
+    Weapon,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct GoodUnknownKey {
+    pub kind: GoodKeyKind,
+    pub key: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GoodError {
+    Json(String),
+    InvalidFormat(String),
+    // every distinct key without a mapping in the document
+    UnknownKeys(Vec<GoodUnknownKey>),
+    // a stat key that can not be a main stat, e.g. flat def
+    InvalidMainStat(String),
+}
+
+impl Display for GoodError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GoodError::Json(e) => write!(f, "GOOD Error: invalid json, {}", e),
+            GoodError::InvalidFormat(format) => write!(f, "GOOD Error: unsupported format {}", format),
+            GoodError::UnknownKeys(keys) => {
+                write!(f, "GOOD Error: unknown keys")?;
+                for key in keys.iter() {
+                    write!(f, " {:?}:{}", key.kind, key.key)?;
+                }
+                Ok(())
+            },
+            GoodError::InvalidMainStat(key) => write!(f, "GOOD Error: {} can not be a main stat", key),
+        }
+    }
+}
+
+impl Error for GoodError {}
+
+pub const GOOD_SLOT_KEYS: [(&str, ArtifactSlotName); 5] = [
+    ("flower", ArtifactSlotName::Flower),
+    ("plume", ArtifactSlotName::Feather),
+    ("sands", ArtifactSlotName::Sand),
+    ("goblet", ArtifactSlotName::Goblet),
+    ("circlet", ArtifactSlotName::Head),
+];
+
+// keys ending with `_` are percentages
+pub const GOOD_STAT_KEYS: [(&str, StatName); 19] = [
+    ("hp", StatName::HPFixed),
+    ("hp_", StatName::HPPercentage),
+    ("atk", StatName::ATKFixed),
+    ("atk_", StatName::ATKPercentage),
+    ("def", StatName::DEFFixed),
+    ("def_", StatName::DEFPercentage),
+    ("eleMas", StatName::ElementalMastery),
+    ("enerRech_", StatName::Recharge),
+    ("critRate_", StatName::CriticalRate),
+    ("critDMG_", StatName::CriticalDamage),
+    ("heal_", StatName::HealingBonus),
+    ("pyro_dmg_", StatName::PyroBonus),
+    ("electro_dmg_", StatName::ElectroBonus),
+    ("hydro_dmg_", StatName::HydroBonus),
+    ("cryo_dmg_", StatName::CryoBonus),
+    ("anemo_dmg_", StatName::AnemoBonus),
+    ("geo_dmg_", StatName::GeoBonus),
+    ("dendro_dmg_", StatName::DendroBonus),
+    ("physical_dmg_", StatName::PhysicalBonus),
+];
+
+// set, character and weapon keys are the variant names, except for these
+pub const GOOD_SET_KEY_OVERRIDES: [(&str, ArtifactSetName); 0] = [];
+
+pub const GOOD_CHARACTER_KEY_OVERRIDES: [(&str, CharacterName); 2] = [
+    ("TravelerAnemo", CharacterName::AetherAnemo),
+    ("YunJin", CharacterName::Yunjin),
+];
+
+// traveler elements without a character in mona, their entries are skipped as unsupported
+pub const GOOD_UNSUPPORTED_CHARACTER_KEYS: [&str; 4] = [
+    "TravelerGeo",
+    "TravelerElectro",
+    "TravelerDendro",
+    "TravelerHydro",
+];
+
+// locations use a single key for every traveler element
+pub const GOOD_LOCATION_KEY_OVERRIDES: [(&str, CharacterName); 1] = [
+    ("Traveler", CharacterName::AetherAnemo),
+];
+
+pub const GOOD_WEAPON_KEY_OVERRIDES: [(&str, WeaponName); 1] = [
+    ("JadefallsSplendor", WeaponName::JadeFallsSplendor),
+];
+
+fn lookup<T: Copy + PartialEq>(table: &[(&'static str, T)], key: &str) -> Option<T> {
+    table.iter().find(|x| x.0 == key).map(|x| x.1)
+}
+
+fn reverse_lookup<T: Copy + PartialEq>(table: &[(&'static str, T)], value: T) -> Option<&'static str> {
+    table.iter().find(|x| x.1 == value).map(|x| x.0)
+}
+
+fn from_variant_name<T: DeserializeOwned>(key: &str) -> Option<T> {
+    serde_json::from_value(serde_json::Value::String(key.to_string())).ok()
+}
+
+fn variant_name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value).ok()
+        .and_then(|x| x.as_str().map(String::from))
+        .unwrap()
+}
+
+pub fn set_from_good(key: &str) -> Option<ArtifactSetName> {
+    lookup(&GOOD_SET_KEY_OVERRIDES, key)
+        .or_else(|| from_variant_name(key))
+        .filter(|&x| x != ArtifactSetName::Empty)
+}
+
+pub fn set_to_good(name: ArtifactSetName) -> String {
+    reverse_lookup(&GOOD_SET_KEY_OVERRIDES, name).map_or_else(|| variant_name(&name), String::from)
+}
+
+pub fn slot_from_good(key: &str) -> Option<ArtifactSlotName> {
+    lookup(&GOOD_SLOT_KEYS, key)
+}
+
+pub fn slot_to_good(slot: ArtifactSlotName) -> &'static str {
+    reverse_lookup(&GOOD_SLOT_KEYS, slot).unwrap()
+}
+
+pub fn stat_from_good(key: &str) -> Option<StatName> {
+    lookup(&GOOD_STAT_KEYS, key)
+}
+
+pub fn stat_to_good(stat: StatName) -> &'static str {
+    reverse_lookup(&GOOD_STAT_KEYS, stat).unwrap()
+}
+
+pub fn is_good_percentage_key(key: &str) -> bool {
+    key.ends_with('_')
+}
+
+pub fn character_from_good(key: &str) -> Option<CharacterName> {
+    lookup(&GOOD_CHARACTER_KEY_OVERRIDES, key).or_else(|| from_variant_name(key))
+}
+
+pub fn character_to_good(name: CharacterName) -> String {
+    reverse_lookup(&GOOD_CHARACTER_KEY_OVERRIDES, name).map_or_else(|| variant_name(&name), String::from)
+}
+
+pub fn is_unsupported_character_key(key: &str) -> bool {
+    GOOD_UNSUPPORTED_CHARACTER_KEYS.contains(&key)
+}
+
+pub fn location_from_good(key: &str) -> Option<CharacterName> {
+    lookup(&GOOD_LOCATION_KEY_OVERRIDES, key).or_else(|| character_from_good(key))
+}
+
+pub fn location_to_good(name: CharacterName) -> String {
+    reverse_lookup(&GOOD_LOCATION_KEY_OVERRIDES, name).map_or_else(|| character_to_good(name), String::from)
+}
+
+pub fn weapon_from_good(key: &str) -> Option<WeaponName> {
+    lookup(&GOOD_WEAPON_KEY_OVERRIDES, key).or_else(|| from_variant_name(key))
+}
+
+pub fn weapon_to_good(name: WeaponName) -> String {
+    reverse_lookup(&GOOD_WEAPON_KEY_OVERRIDES, name).map_or_else(|| variant_name(&name), String::from)
+}
@@ -0,0 +1,7 @@
+pub use format::{GoodDocument, GoodArtifact, GoodSubStat, GoodCharacter, GoodTalent, GoodWeapon};
+pub use keys::{GoodKeyKind, GoodUnknownKey, GoodError, GOOD_SLOT_KEYS, GOOD_STAT_KEYS, GOOD_SET_KEY_OVERRIDES, GOOD_CHARACTER_KEY_OVERRIDES, GOOD_UNSUPPORTED_CHARACTER_KEYS, GOOD_LOCATION_KEY_OVERRIDES, GOOD_WEAPON_KEY_OVERRIDES};
+pub use convert::{GoodArtifactEntry, GoodCharacterEntry, GoodWeaponEntry, GoodImport, GoodEntryKind, GoodSkipReason, GoodSkippedEntry};
+
+mod format;
+mod keys;
+mod convert;
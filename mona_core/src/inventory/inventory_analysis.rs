@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::artifacts::{Artifact, ArtifactSetName, ArtifactSlotName};
+use crate::target_functions::target_function_opt_config::TargetFunctionOptConfig;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InventoryAnalysisConfig {
+    /// candidate pool size of each slot of each character
+    #[serde(default = "default_top_k")]
+    pub top_k: usize,
+    /// additional candidates of each slot for every set the character cares about, so 4 piece sets can be built
+    #[serde(default = "default_set_top_k")]
+    pub set_top_k: usize,
+    /// also rank artifacts by their expected score after leveling to max
+    #[serde(default = "default_consider_leveling")]
+    pub consider_leveling: bool,
+}
+
+fn default_top_k() -> usize {
+    10
+}
+
+fn default_set_top_k() -> usize {
+    3
+}
+
+fn default_consider_leveling() -> bool {
+    true
+}
+
+impl Default for InventoryAnalysisConfig {
+    fn default() -> Self {
+        InventoryAnalysisConfig {
+            top_k: default_top_k(),
+            set_top_k: default_set_top_k(),
+            consider_leveling: default_consider_leveling(),
+        }
+    }
+}
+
+pub struct InventoryCharacter {
+    /// shown in `ArtifactWant`, e.g. the character name or a build name
+    pub name: String,
+    pub opt_config: TargetFunctionOptConfig,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ArtifactWant {
+    pub character: String,
+    /// 1-based rank in the pool of the slot, the better of the current and the expected rank
+    pub rank: usize,
+    /// `Some` if the artifact is only in the pool of this set
+    pub set_name: Option<ArtifactSetName>,
+    pub score: f64,
+    pub expected_score: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct KeptArtifact {
+    pub id: u64,
+    pub wanted_by: Vec<ArtifactWant>,
+}
+
+/// artifacts worth locking and artifacts no character wants
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InventoryAnalysis {
+    pub keep: Vec<KeptArtifact>,
+    /// ids of artifacts outside every candidate pool, now and after expected leveling.
+    /// `None` if the analysis is incomplete, i.e. no characters or some are ignored, their artifacts would be listed
+    pub trash: Option<Vec<u64>>,
+    /// characters left out of the analysis, e.g. no stat raises their target
+    pub ignored: Vec<String>,
+}
+
+struct ScoredArtifact {
+    index: usize,
+    set_name: ArtifactSetName,
+    score: f64,
+    expected_score: f64,
+}
+
+fn is_wanted_main_stat(opt_config: &TargetFunctionOptConfig, artifact: &Artifact) -> bool {
+    let main_stats = match artifact.slot {
+        ArtifactSlotName::Sand => &opt_config.sand_main_stats,
+        ArtifactSlotName::Goblet => &opt_config.goblet_main_stats,
+        ArtifactSlotName::Head => &opt_config.head_main_stats,
+        _ => return true
+    };
+
+    main_stats.is_empty() || main_stats.contains(&artifact.main_stat.0) || opt_config.is_very_critical_set_name(artifact.set_name)
+}
+
+// best rank of each artifact among the first `k` by score and by expected score, keyed by artifact index
+fn top_k_ranks(candidates: &[&ScoredArtifact], k: usize, consider_leveling: bool) -> HashMap<usize, usize> {
+    let mut by_score: Vec<&ScoredArtifact> = candidates.to_vec();
+    by_score.sort_by(|x, y| y.score.total_cmp(&x.score));
+    let mut orders = vec![by_score];
+
+    if consider_leveling {
+        let mut by_expected: Vec<&ScoredArtifact> = candidates.to_vec();
+        by_expected.sort_by(|x, y| y.expected_score.total_cmp(&x.expected_score));
+        orders.push(by_expected);
+    }
+
+    let mut ranks = HashMap::new();
+    for order in orders.iter() {
+        for (rank, item) in order.iter().take(k).enumerate() {
+            let entry = ranks.entry(item.index).or_insert(rank + 1);
+            *entry = (*entry).min(rank + 1);
+        }
+    }
+    ranks
+}
+
+impl InventoryAnalysis {
+    pub fn new(characters: &[InventoryCharacter], artifacts: &[Artifact], config: &InventoryAnalysisConfig) -> InventoryAnalysis {
+        let mut wanted_by: Vec<Vec<ArtifactWant>> = vec![Vec::new(); artifacts.len()];
+        let mut ignored = Vec::new();
+
+        for character in characters.iter() {
+            let opt_config = &character.opt_config;
+            // all weights are zero, every score would be NaN
+            if opt_config.wants_nothing() {
+                ignored.push(character.name.clone());
+                continue;
+            }
+            let scored: Vec<ScoredArtifact> = artifacts.iter().enumerate()
+                .filter(|(_, a)| is_wanted_main_stat(opt_config, a))
+                .map(|(index, a)| ScoredArtifact {
+                    index,
+                    set_name: a.set_name,
+                    score: opt_config.score_normalized(a),
+                    expected_score: if config.consider_leveling { opt_config.expected_score_normalized(a) } else { 0.0 },
+                })
+                .collect();
+
+            let mut pools: HashMap<usize, (usize, Option<ArtifactSetName>)> = HashMap::new();
+            for slot in [ArtifactSlotName::Flower, ArtifactSlotName::Feather, ArtifactSlotName::Sand, ArtifactSlotName::Goblet, ArtifactSlotName::Head] {
+                let candidates: Vec<&ScoredArtifact> = scored.iter().filter(|x| artifacts[x.index].slot == slot).collect();
+                for (index, rank) in top_k_ranks(&candidates, config.top_k, config.consider_leveling) {
+                    pools.insert(index, (rank, None));
+                }
+
+                // off pieces only come from the pool above, the sets the character cares about get pools of their own
+                if let Some(ref set_names) = opt_config.set_names {
+                    for &set_name in set_names.iter() {
+                        let set_candidates: Vec<&ScoredArtifact> = candidates.iter().cloned().filter(|x| x.set_name == set_name).collect();
+                        for (index, rank) in top_k_ranks(&set_candidates, config.set_top_k, config.consider_leveling) {
+                            pools.entry(index).or_insert((rank, Some(set_name)));
+                        }
+                    }
+                }
+            }
+
+            for item in scored.iter() {
+                if let Some(&(rank, set_name)) = pools.get(&item.index) {
+                    wanted_by[item.index].push(ArtifactWant {
+                        character: character.name.clone(),
+                        rank,
+                        set_name,
+                        score: item.score,
+                        expected_score: item.expected_score,
+                    });
+                }
+            }
+        }
+
+        let mut keep = Vec::new();
+        let mut trash = Vec::new();
+        for (artifact, wants) in artifacts.iter().zip(wanted_by.into_iter()) {
+            if wants.is_empty() {
+                trash.push(artifact.id);
+            } else {
+                keep.push(KeptArtifact {
+                    id: artifact.id,
+                    wanted_by: wants,
+                });
+            }
+        }
+
+        let trash = if characters.is_empty() || !ignored.is_empty() {
+            None
+        } else {
+            Some(trash)
+        };
+
+        InventoryAnalysis {
+            keep,
+            trash,
+            ignored,
+        }
+    }
+
+    /// characters left out before the analysis, e.g. whose weights could not be computed, the trash list is dropped
+    pub fn add_ignored(&mut self, names: Vec<String>) {
+        if !names.is_empty() {
+            self.trash = None;
+        }
+        self.ignored.extend(names);
+    }
+}
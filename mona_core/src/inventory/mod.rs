@@ -0,0 +1,3 @@
+pub mod inventory_analysis;
+
+pub use inventory_analysis::{ArtifactWant, InventoryAnalysis, InventoryAnalysisConfig, InventoryCharacter, KeptArtifact};
@@ -27,6 +27,12 @@ pub mod damage;
 pub mod team;
 pub mod potential_function;
 pub mod upgrade_predicate;
+pub mod reaction_sim;
+pub mod rotation;
+pub mod energy;
+pub mod good;
+pub mod farming;
+pub mod inventory;
 // pub mod ast;
 // pub mod compiler;
 // pub mod parser;
@@ -3,3 +3,4 @@ pub mod potential_functions;
 pub mod potential_function_name;
 pub mod potential_function_engine;
 pub mod potential_function_config;
+pub mod potential_distribution;
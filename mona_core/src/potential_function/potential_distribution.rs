@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+
+/// potential of every upgrade outcome of an artifact, values ascending with their probabilities
+#[derive(Debug, Clone)]
+pub struct PotentialDistribution {
+    outcomes: Vec<(f64, f64)>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PotentialSummary {
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+    pub p5: f64,
+    pub p25: f64,
+    pub p50: f64,
+    pub p75: f64,
+    pub p95: f64,
+    // probability of a potential above the given threshold
+    pub probability_above: Option<f64>,
+}
+
+impl PotentialDistribution {
+    /// `outcomes` are (value, weight), equal values are merged and weights are normalized
+    pub fn new(mut outcomes: Vec<(f64, f64)>) -> PotentialDistribution {
+        outcomes.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+        let mut merged: Vec<(f64, f64)> = Vec::with_capacity(outcomes.len());
+        for (value, weight) in outcomes {
+            match merged.last_mut() {
+                Some(last) if last.0 == value => last.1 += weight,
+                _ => merged.push((value, weight))
+            }
+        }
+
+        let total: f64 = merged.iter().map(|x| x.1).sum();
+        if total > 0.0 {
+            for x in merged.iter_mut() {
+                x.1 /= total;
+            }
+        }
+
+        PotentialDistribution {
+            outcomes: merged
+        }
+    }
+
+    pub fn point(value: f64) -> PotentialDistribution {
+        PotentialDistribution {
+            outcomes: vec![(value, 1.0)]
+        }
+    }
+
+    pub fn outcomes(&self) -> &[(f64, f64)] {
+        &self.outcomes
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.outcomes.iter().map(|x| x.0 * x.1).sum()
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        let mean = self.mean();
+        let variance: f64 = self.outcomes.iter().map(|x| (x.0 - mean) * (x.0 - mean) * x.1).sum();
+        variance.sqrt()
+    }
+
+    pub fn min(&self) -> f64 {
+        self.outcomes.first().map_or(0.0, |x| x.0)
+    }
+
+    pub fn max(&self) -> f64 {
+        self.outcomes.last().map_or(0.0, |x| x.0)
+    }
+
+    /// the smallest value whose cumulative probability reaches `q`
+    pub fn percentile(&self, q: f64) -> f64 {
+        let mut cumulative = 0.0;
+        for &(value, prob) in self.outcomes.iter() {
+            cumulative += prob;
+            // tolerate rounding of the summed probabilities
+            if cumulative >= q - 1e-12 {
+                return value;
+            }
+        }
+        self.max()
+    }
+
+    pub fn probability_above(&self, threshold: f64) -> f64 {
+        let prob = self.outcomes.iter().filter(|x| x.0 > threshold).fold(0.0, |acc, x| acc + x.1);
+        prob.min(1.0)
+    }
+
+    pub fn summary(&self, threshold: Option<f64>) -> PotentialSummary {
+        PotentialSummary {
+            mean: self.mean(),
+            std_dev: self.std_dev(),
+            min: self.min(),
+            max: self.max(),
+            p5: self.percentile(0.05),
+            p25: self.percentile(0.25),
+            p50: self.percentile(0.5),
+            p75: self.percentile(0.75),
+            p95: self.percentile(0.95),
+            probability_above: threshold.map(|x| self.probability_above(x)),
+        }
+    }
+}
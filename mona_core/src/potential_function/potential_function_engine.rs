@@ -1,10 +1,21 @@
-use crate::artifacts::Artifact;
-use crate::artifacts::eff::{ARTIFACT_EFF4, ARTIFACT_EFF5};
+use std::collections::HashMap;
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+use crate::artifacts::{Artifact, ArtifactGenerator};
+use crate::artifacts::artifact_generator::artifact_max_level;
+use crate::artifacts::eff::{get_artifact_eff, ARTIFACT_EFF4, ARTIFACT_EFF5};
 use crate::common::StatName;
+use crate::potential_function::potential_distribution::PotentialDistribution;
 use crate::potential_function::potential_function::PotentialFunction;
 
 pub trait PotentialFunctionEngine {
     fn value(&self, potential_function: &Box<dyn PotentialFunction>, artifact: &Artifact) -> f64;
+
+    /// potential over the upgrade outcomes, engines computing only the mean give a single point
+    fn distribution(&self, potential_function: &Box<dyn PotentialFunction>, artifact: &Artifact) -> PotentialDistribution {
+        PotentialDistribution::point(self.value(potential_function, artifact))
+    }
 }
 
 pub struct NaivePotentialFunctionEngine;
@@ -33,6 +44,8 @@ fn expectation_helper(
     }
 
     let mut result = 0.0;
+    // leveling up also grows the main stat, restored together with the level
+    let old_main_stat_value = artifact_ref.main_stat.1;
 
     let sub_stat_count = artifact_ref.sub_stats.len();
     if sub_stat_count == 4 {
@@ -52,7 +65,7 @@ fn expectation_helper(
                     };
                     unsafe {
                         (*artifact).sub_stats[index].1 += delta_value;
-                        (*artifact).level += delta_level;
+                        (*artifact).project_main_stat(old_level + delta_level);
                     }
 
                     result += expectation_helper(artifact, &pf, &effective_stat_names) * 0.0625;
@@ -60,16 +73,18 @@ fn expectation_helper(
                     unsafe {
                         (*artifact).sub_stats[index].1 = old_value;
                         (*artifact).level = old_level;
+                        (*artifact).main_stat.1 = old_main_stat_value;
                     }
                 }
             } else {
                 // let v = pf.potential(&artifact_ref);
                 unsafe {
-                    (*artifact).level += delta_level;
+                    (*artifact).project_main_stat(old_level + delta_level);
                 }
                 result += 0.25 * expectation_helper(artifact, &pf, &effective_stat_names);
                 unsafe {
                     (*artifact).level = old_level;
+                    (*artifact).main_stat.1 = old_main_stat_value;
                 }
             }
         }
@@ -86,12 +101,13 @@ fn expectation_helper(
 
             if !effective_stat_names.contains(&stat_name) {
                 unsafe {
-                    (*artifact).level += delta_level;
+                    (*artifact).project_main_stat(old_level + delta_level);
                     (*artifact).sub_stats.push((stat_name, 0.0));
                 }
                 result += prob * expectation_helper(artifact, &pf, &effective_stat_names);
                 unsafe {
                     (*artifact).level = old_level;
+                    (*artifact).main_stat.1 = old_main_stat_value;
                     (*artifact).sub_stats.pop();
                 }
             } else {
@@ -104,7 +120,7 @@ fn expectation_helper(
 
                     unsafe {
                         (*artifact).sub_stats.push((stat_name, delta_value));
-                        (*artifact).level += delta_level;
+                        (*artifact).project_main_stat(old_level + delta_level);
                     }
 
                     result += prob * 0.25 * expectation_helper(artifact, &pf, &effective_stat_names);
@@ -112,6 +128,7 @@ fn expectation_helper(
                     unsafe {
                         (*artifact).sub_stats.pop();
                         (*artifact).level = old_level;
+                        (*artifact).main_stat.1 = old_main_stat_value;
                     }
                 }
             }
@@ -130,7 +147,145 @@ impl PotentialFunctionEngine for ExpectationPotentialFunctionEngine {
     }
 }
 
-// pub enum PotentialFunctionEngineName {
-//     Naive,
-//     Expect,
-// }
+fn remaining_upgrade_count(artifact: &Artifact) -> usize {
+    let max_level = artifact_max_level(artifact.star);
+    let mut level = artifact.level;
+    let mut count = 0;
+    while level < max_level {
+        level = (level / 4 + 1) * 4;
+        count += 1;
+    }
+    count
+}
+
+// sub stats of a roll state, with the number of rolls of each tier after the starting level.
+// tiers of stats which are not effective do not matter, all their rolls are counted in tier 0
+type RollState = SmallVec<[(StatName, [u8; 4]); 4]>;
+
+/// exact distribution of the potential after all remaining upgrades
+///
+/// the probability of every roll state is propagated one upgrade at a time
+/// and identical states reached through different roll orders are merged.
+/// rolls of stats which are not effective add the mean roll value, so they do not split states
+pub struct DynamicProgrammingPotentialFunctionEngine;
+
+impl DynamicProgrammingPotentialFunctionEngine {
+    fn next_states(artifact: &Artifact, state: &RollState, prob: f64, effective_stat_names: &[StatName], result: &mut HashMap<RollState, f64>) {
+        let tier_count = get_artifact_eff(artifact.star).tier_count;
+
+        if state.len() < 4 {
+            let mut temp = artifact.clone();
+            temp.sub_stats = state.iter().map(|x| (x.0, 0.0)).collect();
+            for (stat_name, p) in temp.get_next_stat_name_dist().unwrap() {
+                let tiers = if effective_stat_names.contains(&stat_name) { tier_count } else { 1 };
+                for tier in 0..tiers {
+                    let mut next = state.clone();
+                    let mut counts = [0; 4];
+                    counts[tier] = 1;
+                    next.push((stat_name, counts));
+                    *result.entry(next).or_insert(0.0) += prob * p / tiers as f64;
+                }
+            }
+        } else {
+            for index in 0..state.len() {
+                let stat_name = state[index].0;
+                let tiers = if effective_stat_names.contains(&stat_name) { tier_count } else { 1 };
+                for tier in 0..tiers {
+                    let mut next = state.clone();
+                    next[index].1[tier] += 1;
+                    *result.entry(next).or_insert(0.0) += prob / state.len() as f64 / tiers as f64;
+                }
+            }
+        }
+    }
+
+    fn final_artifact(artifact: &Artifact, state: &RollState, effective_stat_names: &[StatName]) -> Artifact {
+        let eff = get_artifact_eff(artifact.star);
+        // an invalid main stat (flat def) keeps its value
+        let mut result = artifact.clone();
+        result.project_main_stat(artifact_max_level(artifact.star));
+        result.sub_stats = state.iter().enumerate().map(|(index, &(stat_name, counts))| {
+            let start = artifact.sub_stats.get(index).map_or(0.0, |x| x.1);
+            let added: f64 = if effective_stat_names.contains(&stat_name) {
+                (0..eff.tier_count).map(|tier| counts[tier] as f64 * eff.get_value(stat_name, tier)).sum()
+            } else {
+                let mean_roll = (0..eff.tier_count).map(|tier| eff.get_value(stat_name, tier)).sum::<f64>() / eff.tier_count as f64;
+                counts[0] as f64 * mean_roll
+            };
+            (stat_name, start + added)
+        }).collect();
+        result
+    }
+}
+
+impl PotentialFunctionEngine for DynamicProgrammingPotentialFunctionEngine {
+    fn value(&self, potential_function: &Box<dyn PotentialFunction>, artifact: &Artifact) -> f64 {
+        self.distribution(potential_function, artifact).mean()
+    }
+
+    fn distribution(&self, potential_function: &Box<dyn PotentialFunction>, artifact: &Artifact) -> PotentialDistribution {
+        let effective_stat_names = potential_function.get_effective_stats();
+
+        let mut states: HashMap<RollState, f64> = HashMap::new();
+        states.insert(artifact.sub_stats.iter().map(|x| (x.0, [0; 4])).collect(), 1.0);
+        for _ in 0..remaining_upgrade_count(artifact) {
+            let mut next = HashMap::with_capacity(states.len() * 4);
+            for (state, &prob) in states.iter() {
+                Self::next_states(artifact, state, prob, &effective_stat_names, &mut next);
+            }
+            states = next;
+        }
+
+        let outcomes = states.iter()
+            .map(|(state, &prob)| {
+                let final_artifact = Self::final_artifact(artifact, state, &effective_stat_names);
+                (potential_function.potential(&final_artifact), prob)
+            })
+            .collect();
+        PotentialDistribution::new(outcomes)
+    }
+}
+
+/// distribution sampled by upgrading the artifact to its max level `samples` times, reproducible with the same seed
+pub struct MonteCarloPotentialFunctionEngine {
+    pub samples: usize,
+    pub seed: u64,
+}
+
+impl PotentialFunctionEngine for MonteCarloPotentialFunctionEngine {
+    fn value(&self, potential_function: &Box<dyn PotentialFunction>, artifact: &Artifact) -> f64 {
+        self.distribution(potential_function, artifact).mean()
+    }
+
+    fn distribution(&self, potential_function: &Box<dyn PotentialFunction>, artifact: &Artifact) -> PotentialDistribution {
+        let mut generator = ArtifactGenerator::new(StdRng::seed_from_u64(self.seed));
+        let outcomes = (0..self.samples.max(1))
+            .map(|_| {
+                let mut sample = artifact.clone();
+                generator.upgrade_to_max(&mut sample);
+                (potential_function.potential(&sample), 1.0)
+            })
+            .collect();
+        PotentialDistribution::new(outcomes)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default)]
+pub enum PotentialFunctionEngineName {
+    Naive,
+    #[default]
+    Expectation,
+    DynamicProgramming,
+    MonteCarlo { samples: usize, seed: u64 },
+}
+
+impl PotentialFunctionEngineName {
+    pub fn create(&self) -> Box<dyn PotentialFunctionEngine> {
+        match *self {
+            PotentialFunctionEngineName::Naive => Box::new(NaivePotentialFunctionEngine),
+            PotentialFunctionEngineName::Expectation => Box::new(ExpectationPotentialFunctionEngine),
+            PotentialFunctionEngineName::DynamicProgramming => Box::new(DynamicProgrammingPotentialFunctionEngine),
+            PotentialFunctionEngineName::MonteCarlo { samples, seed } => Box::new(MonteCarloPotentialFunctionEngine { samples, seed }),
+        }
+    }
+}
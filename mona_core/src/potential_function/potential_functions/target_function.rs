@@ -0,0 +1,150 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use crate::artifacts::{Artifact, ArtifactList, ArtifactSetName, ArtifactSlotName};
+use crate::artifacts::effect_config::ArtifactEffectConfig;
+use crate::artifacts::eff::ARTIFACT_EFF5;
+use crate::attribute::{AttributeGraphError, AttributeUtils, SimpleAttributeGraph2};
+use crate::buffs::Buff;
+use crate::character::Character;
+use crate::common::StatName;
+use crate::enemies::Enemy;
+use crate::potential_function::potential_function::PotentialFunction;
+use crate::target_functions::TargetFunction;
+use crate::weapon::Weapon;
+
+const SUB_STAT_NAMES: [StatName; 10] = [
+    StatName::HPFixed, StatName::HPPercentage, StatName::ATKFixed, StatName::ATKPercentage, StatName::DEFFixed,
+    StatName::DEFPercentage, StatName::CriticalRate, StatName::CriticalDamage, StatName::ElementalMastery, StatName::Recharge,
+];
+
+#[derive(Hash, Eq, PartialEq)]
+struct CacheKey {
+    set_name: ArtifactSetName,
+    slot: ArtifactSlotName,
+    main_stat: StatName,
+    star: i32,
+    level: i32,
+    // sorted by stat, values scaled to integers
+    sub_stats: Vec<(StatName, i64)>,
+}
+
+impl CacheKey {
+    fn new(artifact: &Artifact) -> CacheKey {
+        let mut sub_stats: Vec<(StatName, i64)> = artifact.sub_stats.iter()
+            .map(|&(stat, value)| (stat, (value * 1e6).round() as i64))
+            .collect();
+        sub_stats.sort_by_key(|x| x.0 as usize);
+
+        CacheKey {
+            set_name: artifact.set_name,
+            slot: artifact.slot,
+            main_stat: artifact.main_stat.0,
+            star: artifact.star,
+            level: artifact.level,
+            sub_stats,
+        }
+    }
+}
+
+/// gain of the target value when an artifact replaces the equipped one of the same slot.
+/// with `ExpectationPotentialFunctionEngine` this is the expected gain of leveling the artifact to its max level
+pub struct PotentialFunctionTargetFunction {
+    character: Character<SimpleAttributeGraph2>,
+    weapon: Weapon<SimpleAttributeGraph2>,
+    target_function: Box<dyn TargetFunction>,
+    enemy: Enemy,
+    buffs: Vec<Box<dyn Buff<SimpleAttributeGraph2>>>,
+    artifact_config: ArtifactEffectConfig,
+    equipped: Vec<Artifact>,
+    baseline: f64,
+    effective_stats: Vec<StatName>,
+    // the engine reaches the same max level artifact through different roll orders
+    cache: RefCell<HashMap<CacheKey, f64>>,
+}
+
+impl PotentialFunctionTargetFunction {
+    pub fn new(
+        character: Character<SimpleAttributeGraph2>,
+        weapon: Weapon<SimpleAttributeGraph2>,
+        target_function: Box<dyn TargetFunction>,
+        enemy: Enemy,
+        buffs: Vec<Box<dyn Buff<SimpleAttributeGraph2>>>,
+        artifact_config: ArtifactEffectConfig,
+        equipped: Vec<Artifact>,
+    ) -> Result<PotentialFunctionTargetFunction, AttributeGraphError> {
+        let mut result = PotentialFunctionTargetFunction {
+            character,
+            weapon,
+            target_function,
+            enemy,
+            buffs,
+            artifact_config,
+            equipped,
+            baseline: 0.0,
+            effective_stats: Vec::new(),
+            cache: RefCell::new(HashMap::new()),
+        };
+
+        let equipped: Vec<&Artifact> = result.equipped.iter().collect();
+        result.baseline = result.value(&equipped, None)?;
+
+        // rolls of the other stats do not change the target and are skipped by the engine
+        let baseline = result.baseline;
+        result.effective_stats = SUB_STAT_NAMES.iter().cloned()
+            .filter(|&stat| {
+                let value = result.value(&equipped, Some((stat, ARTIFACT_EFF5.max_value(stat))))
+                    .unwrap_or(baseline);
+                (value - baseline).abs() > 1e-9 * baseline.abs().max(1.0)
+            })
+            .collect();
+
+        Ok(result)
+    }
+
+    /// target value of the current equipment
+    pub fn baseline(&self) -> f64 {
+        self.baseline
+    }
+
+    fn value(&self, artifacts: &[&Artifact], extra_stat: Option<(StatName, f64)>) -> Result<f64, AttributeGraphError> {
+        let list = ArtifactList { artifacts };
+        let mut attribute = AttributeUtils::create_attribute_from_big_config(
+            &list,
+            &self.artifact_config,
+            &self.character,
+            &self.weapon,
+            &self.buffs
+        )?;
+        if let Some((stat, value)) = extra_stat {
+            stat.apply(&mut attribute, "", value);
+        }
+
+        Ok(self.target_function.target(&attribute, &self.character, &self.weapon, artifacts, &self.enemy, &[]))
+    }
+
+    /// target value with `artifact` in place of the equipped one of its slot
+    pub fn value_with(&self, artifact: &Artifact) -> f64 {
+        let key = CacheKey::new(artifact);
+        if let Some(&x) = self.cache.borrow().get(&key) {
+            return x;
+        }
+
+        let mut artifacts: Vec<&Artifact> = self.equipped.iter().filter(|x| x.slot != artifact.slot).collect();
+        artifacts.push(artifact);
+        // the set effects of the candidate may form invalid edges, such a candidate gains nothing
+        let value = self.value(&artifacts, None).unwrap_or(self.baseline);
+
+        self.cache.borrow_mut().insert(key, value);
+        value
+    }
+}
+
+impl PotentialFunction for PotentialFunctionTargetFunction {
+    fn potential(&self, artifact: &Artifact) -> f64 {
+        self.value_with(artifact) - self.baseline
+    }
+
+    fn get_effective_stats(&self) -> Vec<StatName> {
+        self.effective_stats.clone()
+    }
+}
@@ -0,0 +1,79 @@
+use serde::{Serialize, Deserialize};
+use crate::common::Element;
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Copy, Clone)]
+pub enum AuraElement {
+    Pyro,
+    Hydro,
+    Electro,
+    Cryo,
+    Dendro,
+    // 激化
+    Quicken,
+    // 冻结
+    Frozen,
+}
+
+impl AuraElement {
+    /// anemo, geo and physical never leave an aura
+    pub fn from_element(element: Element) -> Option<AuraElement> {
+        match element {
+            Element::Pyro => Some(AuraElement::Pyro),
+            Element::Hydro => Some(AuraElement::Hydro),
+            Element::Electro => Some(AuraElement::Electro),
+            Element::Cryo => Some(AuraElement::Cryo),
+            Element::Dendro => Some(AuraElement::Dendro),
+            _ => None
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub struct Aura {
+    pub element: AuraElement,
+    pub gauge: f64,
+    // gauge units lost per second
+    pub decay_rate: f64,
+}
+
+impl Aura {
+    /// applying `gu` units to an empty target leaves 80% of it, lasting 2.5 * gu + 7 seconds
+    pub fn new(element: AuraElement, gu: f64) -> Aura {
+        let gauge = 0.8 * gu;
+        Aura {
+            element,
+            gauge,
+            decay_rate: gauge / (2.5 * gu + 7.0),
+        }
+    }
+
+    /// quicken lasts 5 * gauge + 6 seconds
+    pub fn new_quicken(gauge: f64) -> Aura {
+        Aura {
+            element: AuraElement::Quicken,
+            gauge,
+            decay_rate: gauge / (5.0 * gauge + 6.0),
+        }
+    }
+
+    pub fn new_frozen(gauge: f64) -> Aura {
+        Aura {
+            element: AuraElement::Frozen,
+            gauge,
+            decay_rate: 0.4,
+        }
+    }
+
+    /// re-applying an existing aura refreshes the gauge but keeps the original decay rate
+    pub fn refill(&mut self, gu: f64) {
+        self.gauge = self.gauge.max(0.8 * gu);
+    }
+
+    pub fn decay(&mut self, dt: f64) {
+        self.gauge -= self.decay_rate * dt;
+    }
+
+    pub fn is_gone(&self) -> bool {
+        self.gauge <= 1e-6
+    }
+}
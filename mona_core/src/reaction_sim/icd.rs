@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub struct IcdRule {
+    // seconds after the first applying hit before the timer resets
+    pub interval: f64,
+    // every n-th hit inside the timer window applies element
+    pub hits: usize,
+}
+
+impl IcdRule {
+    pub const STANDARD: IcdRule = IcdRule {
+        interval: 2.5,
+        hits: 3,
+    };
+}
+
+impl Default for IcdRule {
+    fn default() -> Self {
+        IcdRule::STANDARD
+    }
+}
+
+#[derive(Copy, Clone)]
+struct IcdState {
+    start: f64,
+    count: usize,
+}
+
+/// tracks internal cooldowns per character and icd tag
+#[derive(Default)]
+pub struct IcdTracker {
+    states: HashMap<(usize, String), IcdState>,
+}
+
+impl IcdTracker {
+    /// returns whether the hit applies its element
+    pub fn check(&mut self, character: usize, tag: &str, rule: IcdRule, time: f64) -> bool {
+        let key = (character, String::from(tag));
+        match self.states.get_mut(&key) {
+            Some(state) if time < state.start + rule.interval => {
+                let apply = rule.hits > 0 && state.count % rule.hits == 0;
+                state.count += 1;
+                apply
+            },
+            _ => {
+                self.states.insert(key, IcdState {
+                    start: time,
+                    count: 1,
+                });
+                true
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub use aura::{Aura, AuraElement};
+pub use icd::{IcdRule, IcdTracker};
+pub use simulator::{ElementalHit, HitReaction, ReactionRates, ReactionSimResult, ReactionSimulator, SimReaction};
+
+mod aura;
+mod icd;
+mod simulator;
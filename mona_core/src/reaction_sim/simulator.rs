@@ -0,0 +1,322 @@
+use serde::{Serialize, Deserialize};
+use crate::common::Element;
+use crate::common::reaction_type::{AmplifyingType, CatalyzeType, TransformativeType};
+use crate::reaction_sim::aura::{Aura, AuraElement};
+use crate::reaction_sim::icd::{IcdRule, IcdTracker};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ElementalHit {
+    pub time: f64,
+    // index of the character in the team
+    pub character: usize,
+    pub element: Element,
+    // gauge units, usually 1, 2 or 4
+    pub gauge: f64,
+    // hits sharing the same tag of the same character share one icd, None means no icd
+    pub icd_tag: Option<String>,
+    #[serde(default)]
+    pub icd_rule: IcdRule,
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq)]
+pub enum SimReaction {
+    Amplifying(AmplifyingType),
+    Catalyze(CatalyzeType),
+    Transformative(TransformativeType),
+    Quicken,
+    Freeze,
+    Crystallize(Element),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HitReaction {
+    // index of the hit in the input sequence
+    pub index: usize,
+    pub time: f64,
+    pub character: usize,
+    pub element: Element,
+    // whether the hit applied element, i.e. not blocked by icd
+    pub applied: bool,
+    pub reactions: Vec<SimReaction>,
+}
+
+impl HitReaction {
+    pub fn has_reaction<F: Fn(&SimReaction) -> bool>(&self, f: F) -> bool {
+        self.reactions.iter().any(f)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ReactionRates {
+    pub hit_count: usize,
+    pub melt_rate: f64,
+    pub vaporize_rate: f64,
+    pub spread_rate: f64,
+    pub aggravate_rate: f64,
+    pub swirl_rate: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReactionSimResult {
+    pub hits: Vec<HitReaction>,
+    // reactions not caused by a hit directly, e.g. electro-charged ticks
+    pub ticks: Vec<(f64, SimReaction)>,
+}
+
+impl ReactionSimResult {
+    /// ratio of the selected hits which triggered each reaction, can be used in place of hand-entered rates in target functions
+    pub fn rates<F: Fn(&HitReaction) -> bool>(&self, filter: F) -> ReactionRates {
+        let hits: Vec<&HitReaction> = self.hits.iter().filter(|x| filter(x)).collect();
+        let count = hits.len();
+        if count == 0 {
+            return Default::default();
+        }
+
+        let rate = |f: &dyn Fn(&SimReaction) -> bool| {
+            hits.iter().filter(|x| x.has_reaction(f)).count() as f64 / count as f64
+        };
+
+        ReactionRates {
+            hit_count: count,
+            melt_rate: rate(&|r| matches!(r, SimReaction::Amplifying(AmplifyingType::Melt(_)))),
+            vaporize_rate: rate(&|r| matches!(r, SimReaction::Amplifying(AmplifyingType::Vaporize(_)))),
+            spread_rate: rate(&|r| *r == SimReaction::Catalyze(CatalyzeType::Spread)),
+            aggravate_rate: rate(&|r| *r == SimReaction::Catalyze(CatalyzeType::Aggravate)),
+            swirl_rate: rate(&|r| matches!(r, SimReaction::Transformative(
+                TransformativeType::SwirlPyro | TransformativeType::SwirlHydro | TransformativeType::SwirlElectro | TransformativeType::SwirlCryo
+            ))),
+        }
+    }
+
+    pub fn rates_of_character(&self, character: usize) -> ReactionRates {
+        self.rates(|x| x.character == character)
+    }
+
+    pub fn rates_of_character_element(&self, character: usize, element: Element) -> ReactionRates {
+        self.rates(|x| x.character == character && x.element == element)
+    }
+}
+
+enum ReactionOutcome {
+    // consumes aura with the modifier
+    Consume(SimReaction, f64),
+    // both elements stay on the enemy, e.g. electro-charged
+    Coexist(SimReaction),
+}
+
+/// single target aura and gauge simulation
+pub struct ReactionSimulator {
+    // electro-charged is replaced by lunar-charged when there is a moonsign character in the team
+    pub moonsign: bool,
+    pub auras: Vec<Aura>,
+    icd: IcdTracker,
+    time: f64,
+    next_ec_tick: Option<f64>,
+}
+
+impl Default for ReactionSimulator {
+    fn default() -> Self {
+        ReactionSimulator {
+            moonsign: false,
+            auras: Vec::new(),
+            icd: Default::default(),
+            time: 0.0,
+            next_ec_tick: None,
+        }
+    }
+}
+
+impl ReactionSimulator {
+    pub fn new(moonsign: bool) -> ReactionSimulator {
+        ReactionSimulator {
+            moonsign,
+            ..Default::default()
+        }
+    }
+
+    /// hits with a NaN or infinite time are left out of the result
+    pub fn simulate(&mut self, hits: &[ElementalHit]) -> ReactionSimResult {
+        let mut order: Vec<usize> = (0..hits.len()).filter(|&i| hits[i].time.is_finite()).collect();
+        order.sort_by(|a, b| hits[*a].time.total_cmp(&hits[*b].time));
+
+        let mut results = Vec::with_capacity(hits.len());
+        let mut ticks = Vec::new();
+        for index in order {
+            let hit = &hits[index];
+            self.advance(hit.time, &mut ticks);
+
+            let applied = match &hit.icd_tag {
+                Some(tag) => self.icd.check(hit.character, tag, hit.icd_rule, hit.time),
+                None => true,
+            };
+            let reactions = if applied && hit.element != Element::Physical && hit.gauge > 0.0 {
+                self.apply(hit.element, hit.gauge)
+            } else {
+                Vec::new()
+            };
+
+            results.push(HitReaction {
+                index,
+                time: hit.time,
+                character: hit.character,
+                element: hit.element,
+                applied,
+                reactions,
+            });
+        }
+
+        ReactionSimResult {
+            hits: results,
+            ticks,
+        }
+    }
+
+    fn electro_charged(&self) -> SimReaction {
+        if self.moonsign {
+            SimReaction::Transformative(TransformativeType::LunarCharged)
+        } else {
+            SimReaction::Transformative(TransformativeType::ElectroCharged)
+        }
+    }
+
+    fn has_aura(&self, element: AuraElement) -> bool {
+        self.auras.iter().any(|x| x.element == element)
+    }
+
+    fn decay_to(&mut self, time: f64) {
+        let dt = time - self.time;
+        if dt > 0.0 {
+            for aura in self.auras.iter_mut() {
+                aura.decay(dt);
+            }
+            self.auras.retain(|x| !x.is_gone());
+            self.time = time;
+        }
+        if !(self.has_aura(AuraElement::Electro) && self.has_aura(AuraElement::Hydro)) {
+            self.next_ec_tick = None;
+        }
+    }
+
+    fn ec_tick(&mut self) {
+        for aura in self.auras.iter_mut() {
+            if aura.element == AuraElement::Electro || aura.element == AuraElement::Hydro {
+                aura.gauge -= 0.4;
+            }
+        }
+        self.auras.retain(|x| !x.is_gone());
+    }
+
+    fn advance(&mut self, time: f64, ticks: &mut Vec<(f64, SimReaction)>) {
+        while let Some(t) = self.next_ec_tick {
+            if t > time {
+                break;
+            }
+            self.decay_to(t);
+            if self.next_ec_tick.is_none() {
+                break;
+            }
+            self.ec_tick();
+            ticks.push((t, self.electro_charged()));
+            self.next_ec_tick = Some(t + 1.0);
+        }
+        self.decay_to(time);
+    }
+
+    fn react(&self, trigger: Element, aura: AuraElement) -> Option<ReactionOutcome> {
+        use ReactionOutcome::*;
+        let outcome = match (trigger, aura) {
+            (Element::Pyro, AuraElement::Cryo) | (Element::Pyro, AuraElement::Frozen) => Consume(SimReaction::Amplifying(AmplifyingType::Melt(Element::Pyro)), 2.0),
+            (Element::Cryo, AuraElement::Pyro) => Consume(SimReaction::Amplifying(AmplifyingType::Melt(Element::Cryo)), 0.5),
+            (Element::Hydro, AuraElement::Pyro) => Consume(SimReaction::Amplifying(AmplifyingType::Vaporize(Element::Hydro)), 2.0),
+            (Element::Pyro, AuraElement::Hydro) => Consume(SimReaction::Amplifying(AmplifyingType::Vaporize(Element::Pyro)), 0.5),
+            (Element::Pyro, AuraElement::Electro) | (Element::Electro, AuraElement::Pyro) => Consume(SimReaction::Transformative(TransformativeType::Overload), 1.0),
+            (Element::Cryo, AuraElement::Electro) | (Element::Electro, AuraElement::Cryo) => Consume(SimReaction::Transformative(TransformativeType::Superconduct), 1.0),
+            (Element::Electro, AuraElement::Frozen) => Consume(SimReaction::Transformative(TransformativeType::Superconduct), 1.0),
+            (Element::Hydro, AuraElement::Electro) | (Element::Electro, AuraElement::Hydro) => Coexist(self.electro_charged()),
+            (Element::Hydro, AuraElement::Cryo) | (Element::Cryo, AuraElement::Hydro) => Consume(SimReaction::Freeze, 1.0),
+            (Element::Geo, AuraElement::Frozen) => Consume(SimReaction::Transformative(TransformativeType::Shatter), 1.0),
+            (Element::Anemo, AuraElement::Pyro) => Consume(SimReaction::Transformative(TransformativeType::SwirlPyro), 0.5),
+            (Element::Anemo, AuraElement::Hydro) => Consume(SimReaction::Transformative(TransformativeType::SwirlHydro), 0.5),
+            (Element::Anemo, AuraElement::Electro) => Consume(SimReaction::Transformative(TransformativeType::SwirlElectro), 0.5),
+            (Element::Anemo, AuraElement::Cryo) | (Element::Anemo, AuraElement::Frozen) => Consume(SimReaction::Transformative(TransformativeType::SwirlCryo), 0.5),
+            (Element::Geo, AuraElement::Pyro) => Consume(SimReaction::Crystallize(Element::Pyro), 0.5),
+            (Element::Geo, AuraElement::Hydro) => Consume(SimReaction::Crystallize(Element::Hydro), 0.5),
+            (Element::Geo, AuraElement::Electro) => Consume(SimReaction::Crystallize(Element::Electro), 0.5),
+            (Element::Geo, AuraElement::Cryo) => Consume(SimReaction::Crystallize(Element::Cryo), 0.5),
+            (Element::Dendro, AuraElement::Hydro) => Consume(SimReaction::Transformative(TransformativeType::Bloom), 0.5),
+            (Element::Hydro, AuraElement::Dendro) | (Element::Hydro, AuraElement::Quicken) => Consume(SimReaction::Transformative(TransformativeType::Bloom), 2.0),
+            (Element::Dendro, AuraElement::Pyro) | (Element::Pyro, AuraElement::Dendro) | (Element::Pyro, AuraElement::Quicken) => Consume(SimReaction::Transformative(TransformativeType::Burning), 1.0),
+            (Element::Dendro, AuraElement::Electro) | (Element::Electro, AuraElement::Dendro) => Consume(SimReaction::Quicken, 1.0),
+            _ => return None
+        };
+        Some(outcome)
+    }
+
+    /// applies an element with `gauge` units, returns reactions triggered
+    pub fn apply(&mut self, element: Element, gauge: f64) -> Vec<SimReaction> {
+        let mut reactions = Vec::new();
+
+        // catalyze does not consume either quicken or the trigger
+        if self.has_aura(AuraElement::Quicken) {
+            if element == Element::Electro {
+                reactions.push(SimReaction::Catalyze(CatalyzeType::Aggravate));
+            } else if element == Element::Dendro {
+                reactions.push(SimReaction::Catalyze(CatalyzeType::Spread));
+            }
+        }
+
+        let mut remaining = gauge;
+        let mut consumed = false;
+        let mut new_auras = Vec::new();
+        for i in 0..self.auras.len() {
+            if remaining <= 0.0 {
+                break;
+            }
+            let aura_element = self.auras[i].element;
+            match self.react(element, aura_element) {
+                Some(ReactionOutcome::Consume(reaction, modifier)) => {
+                    let aura = &mut self.auras[i];
+                    let aura_gauge = aura.gauge;
+                    let needed = aura_gauge / modifier;
+                    let used = remaining.min(needed);
+                    aura.gauge -= used * modifier;
+                    remaining -= used;
+                    consumed = true;
+
+                    match reaction {
+                        SimReaction::Quicken => new_auras.push(Aura::new_quicken(gauge.min(aura_gauge))),
+                        SimReaction::Freeze => new_auras.push(Aura::new_frozen(2.0 * gauge.min(aura_gauge))),
+                        _ => {}
+                    }
+                    reactions.push(reaction);
+                },
+                Some(ReactionOutcome::Coexist(reaction)) => {
+                    if self.next_ec_tick.is_none() {
+                        reactions.push(reaction);
+                        self.next_ec_tick = Some(self.time + 1.0);
+                    }
+                },
+                None => {}
+            }
+        }
+        self.auras.retain(|x| !x.is_gone());
+
+        // a trigger that reacted with anything does not leave an aura of its own
+        if !consumed {
+            if let Some(aura_element) = AuraElement::from_element(element) {
+                match self.auras.iter_mut().find(|x| x.element == aura_element) {
+                    Some(aura) => aura.refill(gauge),
+                    None => self.auras.push(Aura::new(aura_element, gauge)),
+                }
+            }
+        }
+        self.auras.extend(new_auras);
+
+        // the first electro-charged tick happens right on the trigger
+        if reactions.iter().any(|x| *x == self.electro_charged()) {
+            self.ec_tick();
+        }
+
+        reactions
+    }
+}
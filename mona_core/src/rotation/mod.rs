@@ -0,0 +1,3 @@
+pub use team_rotation::{Rotation, RotationAction, RotationBuff, RotationMember, ActionReaction, RotationActionResult, RotationResult, RotationError};
+
+mod team_rotation;
@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use serde::{Serialize, Deserialize};
+use crate::artifacts::{Artifact, ArtifactList};
+use crate::artifacts::effect_config::ArtifactEffectConfig;
+use crate::attribute::{AttributeGraphError, AttributeUtils, SimpleAttributeGraph2};
+use crate::buffs::{Buff, BuffConfig};
+use crate::buffs::buff_name::BuffName;
+use crate::character::Character;
+use crate::character::skill_config::CharacterSkillConfig;
+use crate::common::{DamageResult, Element};
+use crate::damage::DamageContext;
+use crate::damage::multi_target::TargetHit;
+use crate::damage::damage_result::SimpleDamageResult;
+use crate::enemies::Enemy;
+use crate::weapon::Weapon;
+
+fn default_count() -> f64 {
+    1.0
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum ActionReaction {
+    #[default]
+    None,
+    Melt,
+    Vaporize,
+    Spread,
+    Aggravate,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RotationAction {
+    pub time: f64,
+    // index of the character in rotation members
+    pub character: usize,
+    pub skill_index: usize,
+    pub skill_config: CharacterSkillConfig,
+    #[serde(default)]
+    pub fumo: Option<Element>,
+    #[serde(default)]
+    pub reaction: ActionReaction,
+    // how many times this hit happens at the same time
+    #[serde(default = "default_count")]
+    pub count: f64,
+    // targets hit when evaluated against several enemies
+    #[serde(default)]
+    pub targets: TargetHit,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RotationBuff {
+    pub name: BuffName,
+    pub config: BuffConfig,
+    pub start: f64,
+    // None means the buff lasts for the whole rotation
+    pub duration: Option<f64>,
+    // indices of buffed characters, None means the whole team
+    pub targets: Option<Vec<usize>>,
+    // indices of enemies affected by res shred of this buff, None means all enemies
+    #[serde(default)]
+    pub enemy_targets: Option<Vec<usize>>,
+}
+
+impl RotationBuff {
+    pub fn is_active(&self, time: f64, character: usize) -> bool {
+        if time < self.start {
+            return false;
+        }
+        if let Some(duration) = self.duration {
+            if time >= self.start + duration {
+                return false;
+            }
+        }
+        match &self.targets {
+            Some(targets) => targets.contains(&character),
+            None => true
+        }
+    }
+}
+
+pub struct RotationMember<'a> {
+    pub character: &'a Character<SimpleAttributeGraph2>,
+    pub weapon: &'a Weapon<SimpleAttributeGraph2>,
+    pub artifacts: &'a [&'a Artifact],
+    pub artifact_config: &'a ArtifactEffectConfig,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Rotation {
+    pub actions: Vec<RotationAction>,
+    pub buffs: Vec<RotationBuff>,
+    // rotation length used for dps, defaults to the time of the last action
+    pub duration: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RotationActionResult {
+    pub index: usize,
+    pub time: f64,
+    pub character: usize,
+    pub skill_index: usize,
+    // damage of all `count` hits
+    pub damage: DamageResult,
+    // indices of buffs active on this action
+    pub buffs: Vec<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RotationResult {
+    pub total_damage: f64,
+    pub dps: f64,
+    pub duration: f64,
+    pub damage_by_character: Vec<f64>,
+    pub actions: Vec<RotationActionResult>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RotationError {
+    // time of the action is NaN or infinite
+    InvalidTime { action: usize },
+    // the action refers to a character not in rotation members
+    InvalidCharacter { action: usize, character: usize },
+    // the skill index is out of the damage skills of the character
+    InvalidSkill { action: usize, skill_index: usize },
+    // the edges of the attribute of a character with the active buffs are invalid
+    AttributeGraph(AttributeGraphError),
+}
+
+impl Display for RotationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RotationError::InvalidTime { action } => write!(f, "Rotation Error: action {} has an invalid time", action),
+            RotationError::InvalidCharacter { action, character } => write!(f, "Rotation Error: action {} refers to unknown character {}", action, character),
+            RotationError::InvalidSkill { action, skill_index } => write!(f, "Rotation Error: action {} has an invalid skill index {}", action, skill_index),
+            RotationError::AttributeGraph(e) => write!(f, "Rotation Error: {}", e),
+        }
+    }
+}
+
+impl Error for RotationError {}
+
+impl From<AttributeGraphError> for RotationError {
+    fn from(e: AttributeGraphError) -> Self {
+        RotationError::AttributeGraph(e)
+    }
+}
+
+struct Snapshot {
+    attribute: SimpleAttributeGraph2,
+    enemies: Vec<Enemy>,
+}
+
+impl Rotation {
+    fn create_snapshot(&self, member: &RotationMember, enemies: &[Enemy], buff_indices: &[usize]) -> Result<Snapshot, AttributeGraphError> {
+        let buffs: Vec<Box<dyn Buff<SimpleAttributeGraph2>>> = buff_indices.iter()
+            .map(|&i| self.buffs[i].name.create(&self.buffs[i].config))
+            .collect();
+        let enemy_targets: Vec<&Option<Vec<usize>>> = buff_indices.iter().map(|&i| &self.buffs[i].enemy_targets).collect();
+
+        let attribute = AttributeUtils::create_attribute_from_big_config(
+            &ArtifactList {
+                artifacts: member.artifacts
+            },
+            member.artifact_config,
+            member.character,
+            member.weapon,
+            &buffs
+        )?;
+
+        let mut enemies = enemies.to_vec();
+        for (index, enemy) in enemies.iter_mut().enumerate() {
+            for (buff, targets) in buffs.iter().zip(enemy_targets.iter()) {
+                let affected = match targets {
+                    Some(t) => t.contains(&index),
+                    None => true
+                };
+                if affected {
+                    buff.change_enemy(enemy);
+                }
+            }
+        }
+
+        Ok(Snapshot {
+            attribute,
+            enemies
+        })
+    }
+
+    fn pick_reaction(result: &SimpleDamageResult, reaction: ActionReaction) -> DamageResult {
+        let picked = match reaction {
+            ActionReaction::None => None,
+            ActionReaction::Melt => result.melt,
+            ActionReaction::Vaporize => result.vaporize,
+            ActionReaction::Spread => result.spread,
+            ActionReaction::Aggravate => result.aggravate,
+        };
+        // fallback to no reaction if the hit's element cannot trigger it
+        picked.unwrap_or(result.normal)
+    }
+
+    pub fn validate(&self, members: &[RotationMember]) -> Result<(), RotationError> {
+        for (index, action) in self.actions.iter().enumerate() {
+            if !action.time.is_finite() {
+                return Err(RotationError::InvalidTime { action: index });
+            }
+            if action.character >= members.len() {
+                return Err(RotationError::InvalidCharacter { action: index, character: action.character });
+            }
+            if action.skill_index >= members[action.character].character.common_data.name.get_skill_len() {
+                return Err(RotationError::InvalidSkill { action: index, skill_index: action.skill_index });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn evaluate(&self, members: &[RotationMember], enemy: &Enemy) -> Result<RotationResult, RotationError> {
+        self.evaluate_multi_target(members, std::slice::from_ref(enemy))
+    }
+
+    /// damage of each action is summed over the targets it hits, the first enemy is the primary target
+    pub fn evaluate_multi_target(&self, members: &[RotationMember], enemies: &[Enemy]) -> Result<RotationResult, RotationError> {
+        self.validate(members)?;
+
+        let mut order: Vec<usize> = (0..self.actions.len()).collect();
+        order.sort_by(|a, b| self.actions[*a].time.total_cmp(&self.actions[*b].time));
+
+        // attribute snapshots are shared by actions with the same character and active buffs
+        let mut snapshots: HashMap<(usize, Vec<usize>), Snapshot> = HashMap::new();
+        let mut results = Vec::with_capacity(self.actions.len());
+        let mut damage_by_character = vec![0.0; members.len()];
+        let mut total_damage = 0.0;
+
+        for index in order {
+            let action = &self.actions[index];
+            let member = &members[action.character];
+
+            let active: Vec<usize> = (0..self.buffs.len())
+                .filter(|&i| self.buffs[i].is_active(action.time, action.character))
+                .collect();
+            let key = (action.character, active.clone());
+            if !snapshots.contains_key(&key) {
+                let snapshot = self.create_snapshot(member, enemies, &active)?;
+                snapshots.insert(key.clone(), snapshot);
+            }
+            let snapshot = &snapshots[&key];
+
+            // no enemies give no damage
+            let result = match snapshot.enemies.split_first() {
+                Some((enemy, other_enemies)) => DamageContext {
+                    character_common_data: &member.character.common_data,
+                    attribute: &snapshot.attribute,
+                    enemy,
+                    other_enemies,
+                }.damage_total(action.skill_index, &action.skill_config, action.fumo, action.targets),
+                None => SimpleDamageResult::default()
+            };
+            let dmg = Rotation::pick_reaction(&result, action.reaction) * action.count;
+            // heal and shield are reported but not counted as damage
+            let dmg = DamageResult {
+                is_heal: result.is_heal,
+                is_shield: result.is_shield,
+                ..dmg
+            };
+
+            if !dmg.is_heal && !dmg.is_shield {
+                total_damage += dmg.expectation;
+                damage_by_character[action.character] += dmg.expectation;
+            }
+
+            results.push(RotationActionResult {
+                index,
+                time: action.time,
+                character: action.character,
+                skill_index: action.skill_index,
+                damage: dmg,
+                buffs: active,
+            });
+        }
+
+        let duration = match self.duration {
+            Some(d) => d,
+            None => results.last().map(|x| x.time).unwrap_or(0.0)
+        };
+        let dps = if duration > 0.0 { total_damage / duration } else { total_damage };
+
+        Ok(RotationResult {
+            total_damage,
+            dps,
+            duration,
+            damage_by_character,
+            actions: results,
+        })
+    }
+}
@@ -33,6 +33,7 @@ pub trait TargetFunction {
         character: &Character<SimpleAttributeGraph2>,
         weapon: &Weapon<SimpleAttributeGraph2>,
         artifacts: &[&Artifact],
-        enemy: &Enemy
+        enemy: &Enemy,
+        other_enemies: &[Enemy]
     ) -> f64;
 }
\ No newline at end of file
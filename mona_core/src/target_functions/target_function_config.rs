@@ -1,9 +1,14 @@
 use serde::{Deserialize, Serialize};
 
+use crate::character::skill_config::CharacterSkillConfig;
 use crate::common::{Element, SkillType};
 use crate::target_functions::target_functions::cryo::rosaria_default::RosariaDefaultTFConfig;
 
-#[derive(Serialize, Deserialize)]
+fn default_skill_config() -> CharacterSkillConfig {
+    CharacterSkillConfig::NoConfig
+}
+
+#[derive(Serialize, Deserialize, Clone)]
 pub enum TargetFunctionConfig {
     PyroDamage { t: usize },
     CryoDamage { t: usize },
@@ -63,5 +68,56 @@ pub enum TargetFunctionConfig {
     MikaDefault { recharge_demand: f64, crit_demand: f64 },
     WriothesleyDefault { punch_ratio: f64, melt_rate:f64 },
     MualaniDefault { vaporize_rate: f64 },
+    DamagePercentile {
+        skill: usize,
+        hits: usize,
+        percentile: f64,
+        // the skill config of the character, filled in from the calculator page
+        #[serde(default = "default_skill_config")]
+        skill_config: CharacterSkillConfig,
+        // 0: none, 1: melt, 2: vaporize, 3: aggravate, 4: spread
+        #[serde(default)]
+        reaction: usize,
+    },
     NoConfig,
 }
+
+impl TargetFunctionConfig {
+    /// overrides the energy recharge requirement, returns false if the target function has none
+    pub fn set_recharge_demand(&mut self, value: f64) -> bool {
+        match self {
+            TargetFunctionConfig::BennettDefault { recharge_demand, .. } |
+            TargetFunctionConfig::GorouDefault { recharge_demand, .. } |
+            TargetFunctionConfig::DionaDefault { recharge_demand, .. } |
+            TargetFunctionConfig::JeanDefault { recharge_demand, .. } |
+            TargetFunctionConfig::KaedeharaKazuhaDamage { recharge_demand, .. } |
+            TargetFunctionConfig::KaedeharaKazuhaDefault { recharge_demand, .. } |
+            TargetFunctionConfig::KamisatoAyakaDefault { recharge_demand, .. } |
+            TargetFunctionConfig::KleeDefault { recharge_demand, .. } |
+            TargetFunctionConfig::LisaDefault { recharge_demand, .. } |
+            TargetFunctionConfig::MonaDefault { recharge_demand, .. } |
+            TargetFunctionConfig::QiqiDefault { recharge_demand, .. } |
+            TargetFunctionConfig::RaidenShogunDefault { recharge_demand, .. } |
+            TargetFunctionConfig::SayuDefault { recharge_demand, .. } |
+            TargetFunctionConfig::ShenheDefault { recharge_demand, .. } |
+            TargetFunctionConfig::SucroseDefault { recharge_demand, .. } |
+            TargetFunctionConfig::ThomaDefault { recharge_demand, .. } |
+            TargetFunctionConfig::XianglingDefault { recharge_demand, .. } |
+            TargetFunctionConfig::XingqiuDefault { recharge_demand, .. } |
+            TargetFunctionConfig::XinyanDefault { recharge_demand, .. } |
+            TargetFunctionConfig::YelanDefault { recharge_demand, .. } |
+            TargetFunctionConfig::YunjinDefault { recharge_demand, .. } |
+            TargetFunctionConfig::ZhongliDefault { recharge_demand, .. } |
+            TargetFunctionConfig::KujouSaraDamage { recharge_demand, .. } |
+            TargetFunctionConfig::BennettDamage { recharge_demand, .. } |
+            TargetFunctionConfig::FaruzanDamage { recharge_demand, .. } |
+            TargetFunctionConfig::MikaDefault { recharge_demand, .. } |
+            TargetFunctionConfig::YaeMikoDefault { recharge_requirement: recharge_demand, .. } |
+            TargetFunctionConfig::CynoDefault { recharge_requirement: recharge_demand, .. } => {
+                *recharge_demand = value;
+                true
+            },
+            _ => false
+        }
+    }
+}
@@ -111,4 +111,5 @@ pub enum TargetFunctionName {
     CitlaliDefault,
     YumemizukiMizukiDefault,
     VaresaDefault,
+    DamagePercentile,
 }
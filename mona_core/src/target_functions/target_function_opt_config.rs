@@ -1,7 +1,29 @@
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
 use crate::common::{StatName, SUB_STAT_VALUE_5};
-use crate::artifacts::{Artifact, ArtifactSetName, ArtifactSlotName};
+use crate::artifacts::{Artifact, ArtifactList, ArtifactSetName, ArtifactSlotName};
+use crate::artifacts::effect_config::ArtifactEffectConfig;
+use crate::attribute::{stat_gradient, AttributeGraphError, AttributeUtils, SimpleAttributeGraph2};
+use crate::buffs::Buff;
+use crate::character::Character;
+use crate::enemies::Enemy;
+use crate::target_functions::TargetFunction;
+use crate::weapon::Weapon;
+use crate::artifacts::artifact_generator::artifact_max_level;
+use crate::artifacts::eff::get_artifact_eff;
+use crate::artifacts::roll_analysis::artifact_upgrade_count;
+
+/// everything but the artifacts needed to take the gradient of a target function
+pub struct TargetGradientSetup<'a> {
+    pub character: &'a Character<SimpleAttributeGraph2>,
+    pub weapon: &'a Weapon<SimpleAttributeGraph2>,
+    pub target_function: &'a dyn TargetFunction,
+    pub enemy: &'a Enemy,
+    pub other_enemies: &'a [Enemy],
+    pub buffs: &'a [Box<dyn Buff<SimpleAttributeGraph2>>],
+    pub artifact_config: &'a ArtifactEffectConfig,
+}
 
 pub struct TargetFunctionOptConfig {
     pub atk_fixed: f64,
@@ -77,16 +99,156 @@ impl TargetFunctionOptConfig {
     }
 
     pub fn score_normalized(&self, artifact: &Artifact) -> f64 {
-        let sum = self.atk_percentage + self.atk_fixed + self.hp_percentage + self.hp_fixed + self.def_percentage + self.def_fixed
-            + self.recharge + self.elemental_mastery + self.critical + self.critical_damage
-            + self.healing_bonus
-            + self.bonus_anemo + self.bonus_geo + self.bonus_cryo + self.bonus_pyro + self.bonus_hydro + self.bonus_dendro + self.bonus_electro
-            + self.bonus_physical;
+        let sum = self.score_normalized_denominator();
         let score1 = self.score(artifact);
 
         score1 / sum
     }
 
+    /// a config from d(target)/d(stat) of any scale, each sub stat is weighted by the gain of its max roll,
+    /// and main stats within half the gain of the best one of the slot are accepted
+    pub fn from_stat_gains(gains: &HashMap<StatName, f64>) -> TargetFunctionOptConfig {
+        use StatName::*;
+        let gain = |stat: StatName| gains.get(&stat).cloned().unwrap_or(0.0).max(0.0);
+
+        let roll_gains = [
+            gain(ATKFixed) * SUB_STAT_VALUE_5.atk_fixed[3],
+            gain(ATKPercentage) * SUB_STAT_VALUE_5.atk_percentage[3],
+            gain(HPFixed) * SUB_STAT_VALUE_5.hp_fixed[3],
+            gain(HPPercentage) * SUB_STAT_VALUE_5.hp_percentage[3],
+            gain(DEFFixed) * SUB_STAT_VALUE_5.def_fixed[3],
+            gain(DEFPercentage) * SUB_STAT_VALUE_5.def_percentage[3],
+            gain(Recharge) * SUB_STAT_VALUE_5.recharge[3],
+            gain(ElementalMastery) * SUB_STAT_VALUE_5.elemental_mastery[3],
+            gain(CriticalRate) * SUB_STAT_VALUE_5.critical_rate[3],
+            gain(CriticalDamage) * SUB_STAT_VALUE_5.critical_damage[3],
+        ];
+        let max_roll_gain = roll_gains.iter().cloned().fold(0.0, f64::max);
+        let scale = if max_roll_gain > 0.0 { 1.0 / max_roll_gain } else { 0.0 };
+        let weight = |stat: StatName, max_roll: f64| gain(stat) * max_roll * scale;
+
+        let main_stats = |slot: usize| -> Vec<StatName> {
+            let candidates = &StatName::get_slot_main_stats()[slot];
+            let main_stat_gain = |stat: StatName| gain(stat) * StatName::artifact_main_stat_max_value(stat);
+            let best = candidates.iter().map(|&x| main_stat_gain(x)).fold(0.0, f64::max);
+            candidates.iter().cloned().filter(|&x| best > 0.0 && main_stat_gain(x) >= best * 0.5).collect()
+        };
+
+        TargetFunctionOptConfig {
+            atk_fixed: weight(ATKFixed, SUB_STAT_VALUE_5.atk_fixed[3]),
+            atk_percentage: weight(ATKPercentage, SUB_STAT_VALUE_5.atk_percentage[3]),
+            hp_fixed: weight(HPFixed, SUB_STAT_VALUE_5.hp_fixed[3]),
+            hp_percentage: weight(HPPercentage, SUB_STAT_VALUE_5.hp_percentage[3]),
+            def_fixed: weight(DEFFixed, SUB_STAT_VALUE_5.def_fixed[3]),
+            def_percentage: weight(DEFPercentage, SUB_STAT_VALUE_5.def_percentage[3]),
+            recharge: weight(Recharge, SUB_STAT_VALUE_5.recharge[3]),
+            elemental_mastery: weight(ElementalMastery, SUB_STAT_VALUE_5.elemental_mastery[3]),
+            critical: weight(CriticalRate, SUB_STAT_VALUE_5.critical_rate[3]),
+            critical_damage: weight(CriticalDamage, SUB_STAT_VALUE_5.critical_damage[3]),
+            healing_bonus: weight(HealingBonus, SUB_STAT_VALUE_5.healing_bonus[3]),
+            bonus_electro: weight(ElectroBonus, SUB_STAT_VALUE_5.elemental_bonus[3]),
+            bonus_pyro: weight(PyroBonus, SUB_STAT_VALUE_5.elemental_bonus[3]),
+            bonus_hydro: weight(HydroBonus, SUB_STAT_VALUE_5.elemental_bonus[3]),
+            bonus_anemo: weight(AnemoBonus, SUB_STAT_VALUE_5.elemental_bonus[3]),
+            bonus_cryo: weight(CryoBonus, SUB_STAT_VALUE_5.elemental_bonus[3]),
+            bonus_geo: weight(GeoBonus, SUB_STAT_VALUE_5.elemental_bonus[3]),
+            bonus_dendro: weight(DendroBonus, SUB_STAT_VALUE_5.elemental_bonus[3]),
+            bonus_physical: weight(PhysicalBonus, SUB_STAT_VALUE_5.physical_bonus[3]),
+            sand_main_stats: main_stats(2),
+            goblet_main_stats: main_stats(3),
+            head_main_stats: main_stats(4),
+            set_names: None,
+            very_critical_set_names: None,
+            normal_threshold: TargetFunctionOptConfig::DEFAULT_NORMAL_THRESHOLD,
+            critical_threshold: TargetFunctionOptConfig::DEFAULT_CRITICAL_THRESHOLD,
+            very_critical_threshold: TargetFunctionOptConfig::DEFAULT_VERY_CRITICAL_THRESHOLD,
+        }
+    }
+
+    /// `from_stat_gains` with the gradient of the target at `artifacts`, for target functions without an opt config
+    pub fn from_target_gradient(setup: &TargetGradientSetup, artifacts: &[&Artifact]) -> Result<TargetFunctionOptConfig, AttributeGraphError> {
+        let list = ArtifactList { artifacts };
+        let attribute: SimpleAttributeGraph2 = AttributeUtils::create_attribute_from_big_config(
+            &list,
+            setup.artifact_config,
+            setup.character,
+            setup.weapon,
+            setup.buffs
+        )?;
+
+        let gains = stat_gradient(&attribute, |x| setup.target_function.target(x, setup.character, setup.weapon, artifacts, setup.enemy, setup.other_enemies))?;
+        Ok(TargetFunctionOptConfig::from_stat_gains(&gains))
+    }
+
+    /// score of one unit of `stat`, consistent with `score`
+    pub fn unit_score(&self, stat: StatName) -> f64 {
+        use StatName::*;
+        match stat {
+            ATKFixed => self.atk_fixed / SUB_STAT_VALUE_5.atk_fixed[3],
+            ATKPercentage => self.atk_percentage / SUB_STAT_VALUE_5.atk_percentage[3],
+            HPFixed => self.hp_fixed / SUB_STAT_VALUE_5.hp_fixed[3],
+            HPPercentage => self.hp_percentage / SUB_STAT_VALUE_5.hp_percentage[3],
+            DEFFixed => self.def_fixed / SUB_STAT_VALUE_5.def_fixed[3],
+            DEFPercentage => self.def_percentage / SUB_STAT_VALUE_5.def_percentage[3],
+            Recharge => self.recharge / SUB_STAT_VALUE_5.recharge[3],
+            ElementalMastery => self.elemental_mastery / SUB_STAT_VALUE_5.elemental_mastery[3],
+            CriticalRate => self.critical / SUB_STAT_VALUE_5.critical_rate[3],
+            CriticalDamage => self.critical_damage / SUB_STAT_VALUE_5.critical_damage[3],
+            HealingBonus => self.healing_bonus / SUB_STAT_VALUE_5.healing_bonus[3],
+            HydroBonus => self.bonus_hydro / SUB_STAT_VALUE_5.elemental_bonus[3],
+            AnemoBonus => self.bonus_anemo / SUB_STAT_VALUE_5.elemental_bonus[3],
+            CryoBonus => self.bonus_cryo / SUB_STAT_VALUE_5.elemental_bonus[3],
+            ElectroBonus => self.bonus_electro / SUB_STAT_VALUE_5.elemental_bonus[3],
+            PyroBonus => self.bonus_pyro / SUB_STAT_VALUE_5.elemental_bonus[3],
+            GeoBonus => self.bonus_geo / SUB_STAT_VALUE_5.elemental_bonus[3],
+            DendroBonus => self.bonus_dendro / SUB_STAT_VALUE_5.elemental_bonus[3],
+            PhysicalBonus => self.bonus_physical / SUB_STAT_VALUE_5.physical_bonus[3],
+        }
+    }
+
+    /// expected `score_normalized` once the artifact is leveled to max, the main stat grows to its max value
+    /// and each remaining upgrade adds a mean roll to a random sub stat
+    pub fn expected_score_normalized(&self, artifact: &Artifact) -> f64 {
+        let eff = get_artifact_eff(artifact.star);
+        let roll_score = |stat: StatName| self.unit_score(stat) * eff.mean_value(stat);
+
+        let mut sub_stat_scores: Vec<f64> = artifact.sub_stats.iter().map(|x| roll_score(x.0)).collect();
+        let new_sub_stat_score = match artifact.get_next_stat_name_dist() {
+            Some(dist) => dist.iter().map(|&(stat, p)| p * roll_score(stat)).sum(),
+            None => 0.0
+        };
+
+        let upgrades = artifact_upgrade_count(artifact.star, artifact_max_level(artifact.star)) - artifact_upgrade_count(artifact.star, artifact.level);
+        let mut gain = 0.0;
+        for _ in 0..upgrades {
+            if sub_stat_scores.len() < 4 {
+                sub_stat_scores.push(new_sub_stat_score);
+                gain += new_sub_stat_score;
+            } else {
+                gain += sub_stat_scores.iter().sum::<f64>() / 4.0;
+            }
+        }
+
+        let score = match artifact.projected_to_max() {
+            Some(x) => self.score(&x),
+            None => self.score(artifact)
+        } + gain;
+        score / self.score_normalized_denominator()
+    }
+
+    /// no sub stat has a positive weight, normalized scores are meaningless
+    pub fn wants_nothing(&self) -> bool {
+        self.score_normalized_denominator().partial_cmp(&0.0) != Some(Ordering::Greater)
+    }
+
+    fn score_normalized_denominator(&self) -> f64 {
+        self.atk_percentage + self.atk_fixed + self.hp_percentage + self.hp_fixed + self.def_percentage + self.def_fixed
+            + self.recharge + self.elemental_mastery + self.critical + self.critical_damage
+            + self.healing_bonus
+            + self.bonus_anemo + self.bonus_geo + self.bonus_cryo + self.bonus_pyro + self.bonus_hydro + self.bonus_dendro + self.bonus_electro
+            + self.bonus_physical
+    }
+
     pub fn is_critical_set_name(&self, set_name: ArtifactSetName) -> bool {
         match self.set_names {
             None => true,
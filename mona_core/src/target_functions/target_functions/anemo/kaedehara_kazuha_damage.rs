@@ -9,7 +9,8 @@ use crate::character::traits::CharacterTrait;
 use crate::common::i18n::locale;
 use crate::common::item_config_type::{ItemConfig, ItemConfigType};
 use crate::common::StatName;
-use crate::damage::{DamageContext, SimpleDamageBuilder};
+use crate::damage::DamageContext;
+use crate::damage::multi_target::TargetHit;
 use crate::enemies::Enemy;
 use crate::target_functions::target_function::TargetFunctionMetaTrait;
 use crate::target_functions::target_function_meta::{TargetFunctionFor, TargetFunctionMeta, TargetFunctionMetaImage};
@@ -140,17 +141,18 @@ impl TargetFunction for KaedeharaKazuhaDamageTargetFunction {
         Default::default()
     }
 
-    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy) -> f64 {
+    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy, other_enemies: &[Enemy]) -> f64 {
         let r = attribute.get_value(AttributeName::Recharge).min(self.recharge_demand) / self.recharge_demand;
         // let em = attribute.get_value(AttributeName::ElementalMastery);
         let em = attribute.get_em_all();
 
         let context: DamageContext<'_, SimpleAttributeGraph2> = DamageContext {
             character_common_data: &character.common_data,
-            attribute, enemy
+            attribute, enemy, other_enemies
         };
 
-        let swirl = context.swirl_without_element();
+        // the burst, plunge and swirl hit every target
+        let swirl = (0..context.target_count()).map(|i| context.target_context(i).swirl_without_element()).sum::<f64>();
 
         type S = <KaedeharaKazuha as CharacterTrait>::DamageEnumType;
 
@@ -159,8 +161,8 @@ impl TargetFunction for KaedeharaKazuhaDamageTargetFunction {
         } else {
             CharacterSkillConfig::KaedeharaKazuha { after_e_or_q: false }
         };
-        let dmg_q = KaedeharaKazuha::damage::<SimpleDamageBuilder>(&context, S::Q2, &CharacterSkillConfig::NoConfig, None).normal.expectation;
-        let dmg_plunging_e = KaedeharaKazuha::damage::<SimpleDamageBuilder>(&context, S::PlungingE2, &skill_config, None).normal.expectation;
+        let dmg_q = context.damage_total(S::Q2.into(), &CharacterSkillConfig::NoConfig, None, TargetHit::All).normal.expectation;
+        let dmg_plunging_e = context.damage_total(S::PlungingE2.into(), &skill_config, None, TargetHit::All).normal.expectation;
 
         let bonus_for_other = if character.common_data.has_talent2 {
             em * 0.0004
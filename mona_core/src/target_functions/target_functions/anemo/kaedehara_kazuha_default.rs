@@ -108,7 +108,7 @@ impl TargetFunction for KaedeharaKazuhaDefaultTargetFunction {
         Default::default()
     }
 
-    fn target(&self, attribute: &SimpleAttributeGraph2, _character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, artifacts: &[&Artifact], _enemy: &Enemy) -> f64 {
+    fn target(&self, attribute: &SimpleAttributeGraph2, _character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, artifacts: &[&Artifact], _enemy: &Enemy, _other_enemies: &[Enemy]) -> f64 {
         let mut vv_count = 0;
         for artifact in artifacts.iter() {
             if artifact.set_name == ArtifactSetName::ViridescentVenerer {
@@ -9,7 +9,8 @@ use crate::character::traits::CharacterTrait;
 use crate::common::i18n::locale;
 use crate::common::item_config_type::{ItemConfig, ItemConfigType};
 use crate::common::StatName;
-use crate::damage::{DamageContext, SimpleDamageBuilder};
+use crate::damage::DamageContext;
+use crate::damage::multi_target::TargetHit;
 use crate::enemies::Enemy;
 use crate::target_functions::target_function_opt_config::TargetFunctionOptConfig;
 use crate::target_functions::{TargetFunction, TargetFunctionConfig, TargetFunctionName};
@@ -124,10 +125,10 @@ impl TargetFunction for VentiDefaultTargetFunction {
         Default::default()
     }
 
-    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, artifacts: &[&Artifact], enemy: &Enemy) -> f64 {
+    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, artifacts: &[&Artifact], enemy: &Enemy, other_enemies: &[Enemy]) -> f64 {
         let context: DamageContext<'_, SimpleAttributeGraph2> = DamageContext {
             character_common_data: &character.common_data,
-            attribute, enemy
+            attribute, enemy, other_enemies
         };
 
         let mut vv_count = 0;
@@ -138,8 +139,9 @@ impl TargetFunction for VentiDefaultTargetFunction {
         }
 
         type S = <Venti as CharacterTrait>::DamageEnumType;
-        let dmg_q = Venti::damage::<SimpleDamageBuilder>(&context, S::Q1, &CharacterSkillConfig::NoConfig, None).normal.expectation;
-        let dmg_swirl = context.swirl_without_element();
+        // the burst and swirl hit every target
+        let dmg_q = context.damage_total(S::Q1.into(), &CharacterSkillConfig::NoConfig, None, TargetHit::All).normal.expectation;
+        let dmg_swirl = (0..context.target_count()).map(|i| context.target_context(i).swirl_without_element()).sum::<f64>();
 
         let vv_ratio = if vv_count >= 4 {
             1.278
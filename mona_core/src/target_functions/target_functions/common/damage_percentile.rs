@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use crate::artifacts::Artifact;
+use crate::artifacts::effect_config::ArtifactEffectConfig;
+use crate::attribute::SimpleAttributeGraph2;
+use crate::character::Character;
+use crate::character::character_common_data::CharacterCommonData;
+use crate::character::characters::damage;
+use crate::character::skill_config::CharacterSkillConfig;
+use crate::common::{Element, StatName, SUB_STAT_VALUE_5};
+use crate::common::i18n::locale;
+use crate::common::item_config_type::{ItemConfig, ItemConfigType};
+use crate::damage::{DamageContext, SimpleDamageBuilder};
+use crate::damage::damage_distribution::DamageDistribution;
+use crate::enemies::Enemy;
+use crate::target_functions::target_function::TargetFunctionMetaTrait;
+use crate::target_functions::target_function_meta::{TargetFunctionFor, TargetFunctionMeta, TargetFunctionMetaImage};
+use crate::target_functions::{TargetFunction, TargetFunctionConfig, TargetFunctionName};
+use crate::target_functions::target_function_opt_config::TargetFunctionOptConfig;
+use crate::team::TeamQuantization;
+use crate::weapon::Weapon;
+use crate::weapon::weapon_common_data::WeaponCommonData;
+
+pub struct DamagePercentileTargetFunction {
+    // None if the configured index is not a skill of the character
+    pub skill: Option<usize>,
+    pub hits: usize,
+    pub percentile: f64,
+    pub skill_config: CharacterSkillConfig,
+    // 0: none, 1: melt, 2: vaporize, 3: aggravate, 4: spread
+    pub reaction: usize,
+    pub element: Element,
+}
+
+impl TargetFunctionMetaTrait for DamagePercentileTargetFunction {
+    #[cfg(not(target_family = "wasm"))]
+    const META_DATA: TargetFunctionMeta = TargetFunctionMeta {
+        name: TargetFunctionName::DamagePercentile,
+        name_locale: locale!(
+            zh_cn: "伤害分位数",
+            en: "DMG Percentile"
+        ),
+        description: locale!(
+            zh_cn: "最大化某技能连续命中若干次总伤害的分位数，而非期望",
+            en: "Maximize a percentile of the total damage of several hits of a skill instead of the mean"
+        ),
+        tags: "输出",
+        four: TargetFunctionFor::Common,
+        image: TargetFunctionMetaImage::Custom("misc/sword")
+    };
+
+    #[cfg(not(target_family = "wasm"))]
+    const CONFIG: Option<&'static [ItemConfig]> = Some(&[
+        ItemConfig {
+            name: "skill",
+            title: locale!(
+                zh_cn: "技能序号",
+                en: "Skill Index"
+            ),
+            config: ItemConfigType::IntInput { min: 0, max: 100, default: 0 }
+        },
+        ItemConfig {
+            name: "hits",
+            title: locale!(
+                zh_cn: "命中次数",
+                en: "Hits"
+            ),
+            config: ItemConfigType::IntInput { min: 1, max: 1000, default: 10 }
+        },
+        ItemConfig {
+            name: "percentile",
+            title: locale!(
+                zh_cn: "分位数",
+                en: "Percentile"
+            ),
+            config: ItemConfigType::Float { min: 0.0, max: 1.0, default: 0.5 }
+        },
+        ItemConfig {
+            name: "reaction",
+            title: locale!(
+                zh_cn: "反应",
+                en: "Reaction"
+            ),
+            config: ItemConfigType::Option { options: "无,融化,蒸发,超激化,蔓激化", default: 0 }
+        },
+    ]);
+
+    fn create(character: &CharacterCommonData, _weapon: &WeaponCommonData, config: &TargetFunctionConfig) -> Box<dyn TargetFunction> {
+        let (skill, hits, percentile, skill_config, reaction) = match *config {
+            TargetFunctionConfig::DamagePercentile { skill, hits, percentile, ref skill_config, reaction } => (skill, hits, percentile, skill_config.clone(), reaction),
+            _ => (0, 10, 0.5, CharacterSkillConfig::NoConfig, 0)
+        };
+
+        Box::new(DamagePercentileTargetFunction {
+            skill: if skill < character.name.get_skill_len() { Some(skill) } else { None },
+            hits,
+            percentile,
+            skill_config,
+            reaction,
+            element: character.static_data.element,
+        })
+    }
+}
+
+impl TargetFunction for DamagePercentileTargetFunction {
+    /// assumes an atk scaling skill of the element of the character
+    fn get_target_function_opt_config(&self) -> TargetFunctionOptConfig {
+        // gains of one unit, so that a max roll of each stat is worth the same
+        let mut gains = HashMap::from([
+            (StatName::ATKPercentage, 1.0 / SUB_STAT_VALUE_5.atk_percentage[3]),
+            (StatName::CriticalRate, 1.0 / SUB_STAT_VALUE_5.critical_rate[3]),
+            (StatName::CriticalDamage, 1.0 / SUB_STAT_VALUE_5.critical_damage[3]),
+            (StatName::stat_name_bonus_from_element(self.element), 1.0 / SUB_STAT_VALUE_5.elemental_bonus[3]),
+        ]);
+        if self.reaction != 0 {
+            gains.insert(StatName::ElementalMastery, 1.0 / SUB_STAT_VALUE_5.elemental_mastery[3]);
+        }
+
+        TargetFunctionOptConfig::from_stat_gains(&gains)
+    }
+
+    fn get_default_artifact_config(&self, _team_config: &TeamQuantization) -> ArtifactEffectConfig {
+        Default::default()
+    }
+
+    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy, other_enemies: &[Enemy]) -> f64 {
+        let skill = match self.skill {
+            Some(x) => x,
+            None => return 0.0
+        };
+
+        let context = DamageContext {
+            character_common_data: &character.common_data,
+            attribute,
+            enemy,
+            other_enemies
+        };
+        let result = damage::<SimpleDamageBuilder>(&context, skill, &self.skill_config, None);
+
+        // a reaction the skill cannot trigger falls back to the damage without reaction
+        let reaction_result = match self.reaction {
+            1 => result.melt.as_ref(),
+            2 => result.vaporize.as_ref(),
+            3 => result.aggravate.as_ref(),
+            4 => result.spread.as_ref(),
+            _ => None
+        };
+
+        let mut distribution = DamageDistribution::new();
+        distribution.add_result(reaction_result.unwrap_or(&result.normal), self.hits);
+        distribution.percentile(self.percentile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::attribute::{AttributeUtils, SimpleAttributeGraph2};
+    use crate::character::{Character, CharacterConfig, CharacterName};
+    use crate::character::skill_config::CharacterSkillConfig;
+    use crate::enemies::Enemy;
+    use crate::target_functions::{TargetFunctionConfig, TargetFunctionName, TargetFunctionUtils};
+    use crate::weapon::{Weapon, WeaponConfig, WeaponName};
+
+    fn score(skill: usize) -> f64 {
+        let character: Character<SimpleAttributeGraph2> = Character::new(
+            CharacterName::Diluc, 90, false, 0, 9, 9, 9, &CharacterConfig::NoConfig
+        );
+        let weapon = Weapon::new(WeaponName::WolfsGravestone, 90, false, 1, &WeaponConfig::NoConfig, &character);
+        let attribute = AttributeUtils::create_attribute_from_c_w_bs(&character, &weapon, &Vec::new()).unwrap();
+
+        let config = TargetFunctionConfig::DamagePercentile {
+            skill,
+            hits: 10,
+            percentile: 0.5,
+            skill_config: CharacterSkillConfig::NoConfig,
+            reaction: 0,
+        };
+        let target_function = TargetFunctionUtils::new_target_function(TargetFunctionName::DamagePercentile, &character, &weapon, &config);
+
+        target_function.target(&attribute, &character, &weapon, &[], &Enemy::default(), &[])
+    }
+
+    #[test]
+    fn skill_in_range() {
+        assert!(score(0) > 0.0);
+    }
+
+    #[test]
+    fn skill_out_of_range() {
+        assert_eq!(score(100), 0.0);
+    }
+}
@@ -15,6 +15,7 @@ pub mod expect_vaporize;
 pub mod expect_melt;
 pub mod dendro_damage;
 pub mod max_recharge;
+pub mod damage_percentile;
 
 pub use max_atk::MaxATKTargetFunction;
 pub use max_def::MaxDEFTargetFunction;
@@ -33,3 +34,4 @@ pub use expect_vaporize::ExpectVaporizeTargetFunction;
 pub use expect_melt::ExpectMeltTargetFunction;
 pub use dendro_damage::DendroDamageTargetFunction;
 pub use max_recharge::MaxRechargeTargetFunction;
+pub use damage_percentile::DamagePercentileTargetFunction;
@@ -97,7 +97,7 @@ impl TargetFunction for ChongyunDefaultTargetFunction {
             .build()
     }
 
-    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, artifacts: &[&Artifact], enemy: &Enemy) -> f64 {
+    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, artifacts: &[&Artifact], enemy: &Enemy, other_enemies: &[Enemy]) -> f64 {
         let mut noblesse_count = 0;
         for artifact in artifacts.iter() {
             if artifact.set_name == ArtifactSetName::NoblesseOblige {
@@ -107,7 +107,7 @@ impl TargetFunction for ChongyunDefaultTargetFunction {
 
         let context: DamageContext<'_, SimpleAttributeGraph2> = DamageContext {
             character_common_data: &character.common_data,
-            attribute, enemy
+            attribute, enemy, other_enemies
         };
 
         type S = <Chongyun as CharacterTrait>::DamageEnumType;
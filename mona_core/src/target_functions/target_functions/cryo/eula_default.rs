@@ -9,6 +9,7 @@ use crate::character::traits::CharacterTrait;
 use crate::common::item_config_type::ItemConfig;
 use crate::common::StatName;
 use crate::damage::{DamageContext, SimpleDamageBuilder};
+use crate::damage::multi_target::TargetHit;
 use crate::enemies::Enemy;
 use crate::target_functions::target_function_meta::{TargetFunctionFor, TargetFunctionMeta, TargetFunctionMetaImage};
 use crate::target_functions::target_function_opt_config::TargetFunctionOptConfig;
@@ -114,10 +115,10 @@ impl TargetFunction for EulaDefaultTargetFunction {
             .build()
     }
 
-    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy) -> f64 {
+    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy, other_enemies: &[Enemy]) -> f64 {
         let context: DamageContext<'_, SimpleAttributeGraph2> = DamageContext {
             character_common_data: &character.common_data,
-            attribute, enemy
+            attribute, enemy, other_enemies
         };
 
         let skill_config = if character.common_data.constellation < 6 {
@@ -127,8 +128,9 @@ impl TargetFunction for EulaDefaultTargetFunction {
         };
 
         type S = <Eula as CharacterTrait>::DamageEnumType;
-        let dmg_q = Eula::damage::<SimpleDamageBuilder>(
-            &context, S::QLightfall, &skill_config, None
+        // lightfall sword hits every target
+        let dmg_q = context.damage_total(
+            S::QLightfall.into(), &skill_config, None, TargetHit::All
         ).normal.expectation;
         let dmg_a = Eula::damage::<SimpleDamageBuilder>(
             &context, S::Normal1, &skill_config, None
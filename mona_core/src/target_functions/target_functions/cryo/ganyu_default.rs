@@ -10,6 +10,7 @@ use crate::common::{Element, StatName};
 use crate::common::i18n::locale;
 use crate::common::item_config_type::{ItemConfig};
 use crate::damage::{DamageContext, SimpleDamageBuilder};
+use crate::damage::multi_target::TargetHit;
 use crate::enemies::Enemy;
 use crate::target_functions::{TargetFunction, TargetFunctionConfig, TargetFunctionName};
 use crate::target_functions::target_function::TargetFunctionMetaTrait;
@@ -127,26 +128,28 @@ impl TargetFunction for GanyuDefaultTargetFunction {
             .build()
     }
 
-    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy) -> f64 {
+    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy, other_enemies: &[Enemy]) -> f64 {
         let context = DamageContext {
             enemy,
             character_common_data: &character.common_data,
-            attribute
+            attribute,
+            other_enemies
         };
 
         type S = <Ganyu as CharacterTrait>::DamageEnumType;
         let charged_dmg3 = Ganyu::damage::<SimpleDamageBuilder>(
             &context, S::Charged3, &CharacterSkillConfig::Ganyu { talent1_rate: 1.0 }, None
         );
-        let charged_dmg4 = Ganyu::damage::<SimpleDamageBuilder>(
-            &context, S::Charged4, &CharacterSkillConfig::Ganyu { talent1_rate: 1.0 }, None
+        // frostflake bloom and the burst hit every target
+        let charged_dmg4 = context.damage_total(
+            S::Charged4.into(), &CharacterSkillConfig::Ganyu { talent1_rate: 1.0 }, None, TargetHit::All
         );
 
         let charged_mean = (1.0 - self.melt_rate) * (charged_dmg3.normal.expectation * 0.8 + charged_dmg4.normal.expectation * 1.2)
             + self.melt_rate * (charged_dmg3.melt.unwrap().expectation * 0.8 + charged_dmg4.melt.unwrap().expectation * 1.2);
 
-        let q_dmg = Ganyu::damage::<SimpleDamageBuilder>(
-            &context, S::Q1, &CharacterSkillConfig::NoConfig, None
+        let q_dmg = context.damage_total(
+            S::Q1.into(), &CharacterSkillConfig::NoConfig, None, TargetHit::All
         );
         let q_mean = (1.0 - self.melt_rate) * q_dmg.normal.expectation + self.melt_rate * q_dmg.melt.unwrap().expectation;
 
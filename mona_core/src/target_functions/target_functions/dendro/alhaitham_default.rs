@@ -23,12 +23,14 @@ impl TargetFunction for AlhaithamDefaultTargetFunction {
             character: &crate::character::Character<crate::attribute::SimpleAttributeGraph2>,
             weapon: &crate::weapon::Weapon<crate::attribute::SimpleAttributeGraph2>,
             artifacts: &[&crate::artifacts::Artifact],
-            enemy: &crate::enemies::Enemy
+            enemy: &crate::enemies::Enemy,
+            other_enemies: &[crate::enemies::Enemy]
         ) -> f64 {
         let context: DamageContext<'_, SimpleAttributeGraph2> = DamageContext {
             character_common_data: &character.common_data,
             enemy: enemy,
             attribute: &attribute,
+            other_enemies,
         };
 
         type S = <Alhaitham as CharacterTrait>::DamageEnumType;
@@ -9,7 +9,8 @@ use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::CharacterTrait;
 use crate::common::i18n::locale;
 use crate::common::item_config_type::{ItemConfig, ItemConfigType};
-use crate::damage::{DamageContext, SimpleDamageBuilder};
+use crate::damage::DamageContext;
+use crate::damage::multi_target::TargetHit;
 use crate::enemies::Enemy;
 use crate::target_functions::{TargetFunction, TargetFunctionConfig, TargetFunctionName};
 use crate::target_functions::target_function::TargetFunctionMetaTrait;
@@ -138,11 +139,12 @@ impl TargetFunction for NahidaDefaultTargetFunction {
             .build()
     }
 
-    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, weapon: &Weapon<SimpleAttributeGraph2>, artifacts: &[&Artifact], enemy: &Enemy) -> f64 {
+    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, weapon: &Weapon<SimpleAttributeGraph2>, artifacts: &[&Artifact], enemy: &Enemy, other_enemies: &[Enemy]) -> f64 {
         let context: DamageContext<'_, SimpleAttributeGraph2> = DamageContext {
             character_common_data: &character.common_data,
             attribute: &attribute,
             enemy,
+            other_enemies,
         };
         let em_req = if self.em_requirement == 0 { self.em_requirement + 1 } else { self.em_requirement } as f64;
         type S = <Nahida as CharacterTrait>::DamageEnumType;
@@ -158,7 +160,8 @@ impl TargetFunction for NahidaDefaultTargetFunction {
             q_bonus: if self.pryo_teammate_count > 0 { true } else { false },
             q_bonus_count: self.pryo_teammate_count,
         };
-        let dmg_e3 = Nahida::damage::<SimpleDamageBuilder>(&context, S::E3, &skill_config, None);
+        // tri-karma purification hits every marked target
+        let dmg_e3 = context.damage_total(S::E3.into(), &skill_config, None, TargetHit::All);
         let trans = context.transformative();
 
         (dmg_e3.spread.unwrap().expectation * self.spread_rate
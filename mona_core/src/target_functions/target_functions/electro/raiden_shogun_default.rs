@@ -8,7 +8,8 @@ use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::CharacterTrait;
 use crate::common::item_config_type::{ItemConfig, ItemConfigType};
 use crate::common::StatName;
-use crate::damage::{DamageContext, SimpleDamageBuilder};
+use crate::damage::DamageContext;
+use crate::damage::multi_target::TargetHit;
 use crate::enemies::Enemy;
 use crate::target_functions::target_function_meta::{TargetFunctionFor, TargetFunctionMeta, TargetFunctionMetaImage};
 use crate::target_functions::target_function_opt_config::TargetFunctionOptConfig;
@@ -115,15 +116,16 @@ impl TargetFunction for RaidenShogunDefaultTargetFunction {
         Default::default()
     }
 
-    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy) -> f64 {
+    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy, other_enemies: &[Enemy]) -> f64 {
         let context: DamageContext<'_, SimpleAttributeGraph2> = DamageContext {
             character_common_data: &character.common_data,
-            attribute, enemy
+            attribute, enemy, other_enemies
         };
 
         let config = CharacterSkillConfig::RaidenShogun { under_e: true, resolve_stack: 40 };
         type S = <RaidenShogun as CharacterTrait>::DamageEnumType;
-        let dmg_q1 = RaidenShogun::damage::<SimpleDamageBuilder>(&context, S::Q1, &config, None).normal.expectation;
+        // the musou no hitotachi slash hits every target
+        let dmg_q1 = context.damage_total(S::Q1.into(), &config, None, TargetHit::All).normal.expectation;
 
         let r = attribute.get_value(AttributeName::Recharge).min(self.recharge_demand);
 
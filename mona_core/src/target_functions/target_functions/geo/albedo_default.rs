@@ -105,11 +105,13 @@ impl TargetFunction for AlbedoDefaultTargetFunction {
         character: &Character<SimpleAttributeGraph2>,
         _weapon: &Weapon<SimpleAttributeGraph2>,
         _artifacts: &[&Artifact],
-        enemy: &Enemy) -> f64 {
+        enemy: &Enemy,
+        other_enemies: &[Enemy]) -> f64 {
         let context = DamageContext {
             character_common_data: &character.common_data,
             attribute,
-            enemy
+            enemy,
+            other_enemies
         };
 
         type SkillEnum = <Albedo as CharacterTrait>::DamageEnumType;
@@ -37,10 +37,11 @@ impl TargetFunction for NilouDefaultTargetFunction {
         Default::default()
     }
 
-    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, weapon: &Weapon<SimpleAttributeGraph2>, artifacts: &[&Artifact], enemy: &Enemy) -> f64 {
+    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, weapon: &Weapon<SimpleAttributeGraph2>, artifacts: &[&Artifact], enemy: &Enemy, other_enemies: &[Enemy]) -> f64 {
         let context = DamageContext {
             character_common_data: &character.common_data,
-            enemy, attribute
+            enemy, attribute,
+            other_enemies
         };
 
         type S = <Nilou as CharacterTrait>::DamageEnumType;
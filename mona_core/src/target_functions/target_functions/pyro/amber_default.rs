@@ -99,12 +99,14 @@ impl TargetFunction for AmberDefaultTargetFunction {
         character: &Character<SimpleAttributeGraph2>,
         _weapon: &Weapon<SimpleAttributeGraph2>,
         _artifacts: &[&Artifact],
-        enemy: &Enemy
+        enemy: &Enemy,
+        other_enemies: &[Enemy]
     ) -> f64 {
         let context: DamageContext<'_, SimpleAttributeGraph2> = DamageContext {
             character_common_data: &character.common_data,
             attribute,
-            enemy
+            enemy,
+            other_enemies
         };
 
         type S = <Amber as CharacterTrait>::DamageEnumType;
@@ -130,7 +130,7 @@ impl TargetFunction for BennettDamageTargetFunction {
             .build()
     }
 
-    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy) -> f64 {
+    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy, other_enemies: &[Enemy]) -> f64 {
         let bonus_for_other = attribute.get_value(AttributeName::ATKBonusForOther);
         // if bonus_for_other > 1e-6 {
         //     crate::utils::log!("666 {}", bonus_for_other);
@@ -143,7 +143,8 @@ impl TargetFunction for BennettDamageTargetFunction {
         let context: DamageContext<'_, SimpleAttributeGraph2> = DamageContext {
             character_common_data: &character.common_data,
             attribute,
-            enemy
+            enemy,
+            other_enemies
         };
 
         type S = <Bennett as CharacterTrait>::DamageEnumType;
@@ -10,6 +10,7 @@ use crate::common::{Element, StatName};
 use crate::common::item_config_type::{ItemConfig, ItemConfigType};
 use crate::damage::{DamageContext, SimpleDamageBuilder};
 use crate::enemies::Enemy;
+use crate::reaction_sim::ReactionSimResult;
 use crate::target_functions::target_function_opt_config::TargetFunctionOptConfig;
 use crate::target_functions::{TargetFunction, TargetFunctionConfig, TargetFunctionName};
 use crate::target_functions::target_function::TargetFunctionMetaTrait;
@@ -33,6 +34,15 @@ impl HuTaoDefaultTargetFunction {
             vaporize_rate, melt_rate
         }
     }
+
+    /// reaction rates of the pyro hits of the character at `character` in a simulated rotation
+    pub fn from_simulation(result: &ReactionSimResult, character: usize) -> HuTaoDefaultTargetFunction {
+        let rates = result.rates_of_character_element(character, Element::Pyro);
+        HuTaoDefaultTargetFunction {
+            vaporize_rate: rates.vaporize_rate,
+            melt_rate: rates.melt_rate,
+        }
+    }
 }
 
 impl TargetFunctionMetaTrait for HuTaoDefaultTargetFunction {
@@ -138,11 +148,12 @@ impl TargetFunction for HuTaoDefaultTargetFunction {
             .build()
     }
 
-    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy) -> f64 {
+    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy, other_enemies: &[Enemy]) -> f64 {
         let context = DamageContext {
             character_common_data: &character.common_data,
             enemy,
-            attribute
+            attribute,
+            other_enemies
         };
 
         type S = <HuTao as CharacterTrait>::DamageEnumType;
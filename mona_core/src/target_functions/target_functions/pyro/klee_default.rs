@@ -9,6 +9,7 @@ use crate::character::traits::CharacterTrait;
 use crate::common::{Element, StatName};
 use crate::common::item_config_type::{ItemConfig, ItemConfigType};
 use crate::damage::{DamageContext, SimpleDamageBuilder};
+use crate::damage::multi_target::TargetHit;
 use crate::enemies::Enemy;
 use crate::target_functions::target_function_meta::{TargetFunctionFor, TargetFunctionMeta, TargetFunctionMetaImage};
 use crate::target_functions::target_function_opt_config::TargetFunctionOptConfig;
@@ -118,19 +119,20 @@ impl TargetFunction for KleeDefaultTargetFunction {
             .build()
     }
 
-    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy) -> f64 {
+    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy, other_enemies: &[Enemy]) -> f64 {
         let context: DamageContext<'_, SimpleAttributeGraph2> = DamageContext {
             character_common_data: &character.common_data,
-            attribute, enemy
+            attribute, enemy, other_enemies
         };
 
         let config = CharacterSkillConfig::NoConfig;
         type S = <Klee as CharacterTrait>::DamageEnumType;
         let dmg_q = Klee::damage::<SimpleDamageBuilder>(&context, S::Q1, &config, None).normal.expectation;
+        // the charged attack explodes on every target
         let dmg_charged = if character.common_data.has_talent1 {
-            Klee::damage::<SimpleDamageBuilder>(&context, S::ChargedWithTalent, &config, None).normal.expectation
+            context.damage_total(S::ChargedWithTalent.into(), &config, None, TargetHit::All).normal.expectation
         } else {
-            Klee::damage::<SimpleDamageBuilder>(&context, S::Charged, &config, None).normal.expectation
+            context.damage_total(S::Charged.into(), &config, None, TargetHit::All).normal.expectation
         };
 
         let r = attribute.get_value(AttributeName::Recharge).min(self.recharge_demand);
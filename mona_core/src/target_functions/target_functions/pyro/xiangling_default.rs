@@ -8,7 +8,8 @@ use crate::character::skill_config::CharacterSkillConfig;
 use crate::character::traits::CharacterTrait;
 use crate::common::item_config_type::{ItemConfig, ItemConfigType};
 use crate::common::StatName;
-use crate::damage::{DamageContext, SimpleDamageBuilder};
+use crate::damage::DamageContext;
+use crate::damage::multi_target::TargetHit;
 use crate::enemies::Enemy;
 use crate::target_functions::target_function_opt_config::TargetFunctionOptConfig;
 use crate::target_functions::{TargetFunction, TargetFunctionConfig, TargetFunctionName};
@@ -170,14 +171,15 @@ impl TargetFunction for XianglingDefaultTargetFunction {
             .build()
     }
 
-    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy) -> f64 {
+    fn target(&self, attribute: &SimpleAttributeGraph2, character: &Character<SimpleAttributeGraph2>, _weapon: &Weapon<SimpleAttributeGraph2>, _artifacts: &[&Artifact], enemy: &Enemy, other_enemies: &[Enemy]) -> f64 {
         let context: DamageContext<'_, SimpleAttributeGraph2> = DamageContext {
             character_common_data: &character.common_data,
-            attribute, enemy
+            attribute, enemy, other_enemies
         };
 
         type S = <Xiangling as CharacterTrait>::DamageEnumType;
-        let dmg_q = Xiangling::damage::<SimpleDamageBuilder>(&context, S::Q4, &CharacterSkillConfig::NoConfig, None);
+        // pyronado and overload hit every target
+        let dmg_q = context.damage_total(S::Q4.into(), &CharacterSkillConfig::NoConfig, None, TargetHit::All);
 
         let normal = dmg_q.normal.expectation;
         let melt = dmg_q.melt.unwrap().expectation;
@@ -185,8 +187,7 @@ impl TargetFunction for XianglingDefaultTargetFunction {
 
         let normal_rate = (1.0 - self.melt_rate - self.vaporize_rate).clamp(0.0, 1.0);
 
-        let transformative = context.transformative();
-        let overload = transformative.overload;
+        let overload: f64 = context.transformative_targets().iter().map(|x| x.overload).sum();
 
         let r = attribute.get_value(AttributeName::Recharge).min(self.recharge_demand);
 
@@ -1,3 +1,4 @@
+use rand::{Rng, thread_rng};
 use crate::artifacts::Artifact;
 use crate::upgrade_predicate::predicates::all_true_predicate::AllTruePredicate;
 use crate::upgrade_predicate::upgrade_predicate::UpgradePredicate;
@@ -15,10 +16,20 @@ impl Default for ArtifactUpgrader {
 }
 
 impl ArtifactUpgrader {
+    pub fn new(predicate: Box<dyn UpgradePredicate>) -> ArtifactUpgrader {
+        ArtifactUpgrader {
+            predicate
+        }
+    }
+
     pub fn upgrade(&self, a: &mut Artifact) -> bool {
+        self.upgrade_with_rng(a, &mut thread_rng())
+    }
+
+    pub fn upgrade_with_rng<R: Rng + ?Sized>(&self, a: &mut Artifact, rng: &mut R) -> bool {
         while !a.is_max_level() {
             if self.predicate.can_do_upgrade(&a) {
-                a.upgrade();
+                a.upgrade_with_rng(rng);
             } else {
                 return false;
             }
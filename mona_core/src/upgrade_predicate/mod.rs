@@ -1,3 +1,4 @@
 pub mod upgrade_predicate;
 pub mod predicates;
 pub mod artifact_upgrader;
+pub mod upgrade_predicate_config;
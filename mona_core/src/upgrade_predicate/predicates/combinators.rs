@@ -0,0 +1,34 @@
+use crate::artifacts::Artifact;
+use crate::upgrade_predicate::upgrade_predicate::UpgradePredicate;
+
+/// upgrades when every predicate does, also when there is none
+pub struct AndPredicate {
+    pub predicates: Vec<Box<dyn UpgradePredicate>>,
+}
+
+impl UpgradePredicate for AndPredicate {
+    fn can_do_upgrade(&self, a: &Artifact) -> bool {
+        self.predicates.iter().all(|p| p.can_do_upgrade(a))
+    }
+}
+
+/// upgrades when any predicate does
+pub struct OrPredicate {
+    pub predicates: Vec<Box<dyn UpgradePredicate>>,
+}
+
+impl UpgradePredicate for OrPredicate {
+    fn can_do_upgrade(&self, a: &Artifact) -> bool {
+        self.predicates.iter().any(|p| p.can_do_upgrade(a))
+    }
+}
+
+pub struct NotPredicate {
+    pub predicate: Box<dyn UpgradePredicate>,
+}
+
+impl UpgradePredicate for NotPredicate {
+    fn can_do_upgrade(&self, a: &Artifact) -> bool {
+        !self.predicate.can_do_upgrade(a)
+    }
+}
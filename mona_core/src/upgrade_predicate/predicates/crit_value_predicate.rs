@@ -0,0 +1,26 @@
+use crate::artifacts::Artifact;
+use crate::common::StatName;
+use crate::upgrade_predicate::upgrade_predicate::UpgradePredicate;
+
+/// crit rate counted twice plus crit damage of the sub stats, 0.2 for 20 crit value
+pub fn artifact_crit_value(a: &Artifact) -> f64 {
+    a.sub_stats.iter()
+        .map(|&(stat, value)| match stat {
+            StatName::CriticalRate => value * 2.0,
+            StatName::CriticalDamage => value,
+            _ => 0.0
+        })
+        .sum()
+}
+
+/// once the artifact reaches `level`, keeps leveling only with a crit value of at least `min_crit_value`
+pub struct CritValuePredicate {
+    pub level: i32,
+    pub min_crit_value: f64,
+}
+
+impl UpgradePredicate for CritValuePredicate {
+    fn can_do_upgrade(&self, a: &Artifact) -> bool {
+        a.level < self.level || artifact_crit_value(a) >= self.min_crit_value
+    }
+}
@@ -0,0 +1,26 @@
+use crate::artifacts::{Artifact, ArtifactRollAnalysis};
+use crate::common::StatName;
+use crate::upgrade_predicate::upgrade_predicate::UpgradePredicate;
+
+/// number of rolls that went into `stats`, initial sub stats included
+pub fn effective_roll_count(a: &Artifact, stats: &[StatName]) -> usize {
+    ArtifactRollAnalysis::new(a).sub_stats.iter()
+        .flatten()
+        .filter(|x| stats.contains(&x.stat))
+        .map(|x| x.roll_count())
+        .sum()
+}
+
+/// once the artifact reaches `level`, keeps leveling only with at least `min_rolls` rolls in `effective_stats`,
+/// e.g. stop at +4 unless 3 rolls went into crit
+pub struct EffectiveRollsPredicate {
+    pub level: i32,
+    pub min_rolls: usize,
+    pub effective_stats: Vec<StatName>,
+}
+
+impl UpgradePredicate for EffectiveRollsPredicate {
+    fn can_do_upgrade(&self, a: &Artifact) -> bool {
+        a.level < self.level || effective_roll_count(a, &self.effective_stats) >= self.min_rolls
+    }
+}
@@ -1 +1,6 @@
 pub mod all_true_predicate;
+pub mod effective_rolls_predicate;
+pub mod crit_value_predicate;
+pub mod requires_crit_predicate;
+pub mod potential_predicate;
+pub mod combinators;
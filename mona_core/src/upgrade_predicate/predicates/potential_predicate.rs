@@ -0,0 +1,16 @@
+use crate::artifacts::Artifact;
+use crate::potential_function::potential_function::{calc_potential, PotentialFunction};
+use crate::upgrade_predicate::upgrade_predicate::UpgradePredicate;
+
+/// once the artifact reaches `level`, keeps leveling only with a potential of at least `min_potential`
+pub struct PotentialPredicate {
+    pub level: i32,
+    pub min_potential: f64,
+    pub potential_function: Box<dyn PotentialFunction>,
+}
+
+impl UpgradePredicate for PotentialPredicate {
+    fn can_do_upgrade(&self, a: &Artifact) -> bool {
+        a.level < self.level || calc_potential(&self.potential_function, a) >= self.min_potential
+    }
+}
@@ -0,0 +1,12 @@
+use crate::artifacts::Artifact;
+use crate::common::StatName;
+use crate::upgrade_predicate::upgrade_predicate::UpgradePredicate;
+
+/// levels only artifacts with a crit rate or crit damage sub stat
+pub struct RequiresCritPredicate;
+
+impl UpgradePredicate for RequiresCritPredicate {
+    fn can_do_upgrade(&self, a: &Artifact) -> bool {
+        a.sub_stats.iter().any(|x| x.0 == StatName::CriticalRate || x.0 == StatName::CriticalDamage)
+    }
+}
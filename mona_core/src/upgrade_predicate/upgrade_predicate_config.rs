@@ -0,0 +1,71 @@
+use serde::{Serialize, Deserialize};
+use crate::common::StatName;
+use crate::potential_function::potential_function_config::PotentialFunctionConfig;
+use crate::potential_function::potential_function_name::PotentialFunctionName;
+use crate::upgrade_predicate::predicates::all_true_predicate::AllTruePredicate;
+use crate::upgrade_predicate::predicates::combinators::{AndPredicate, NotPredicate, OrPredicate};
+use crate::upgrade_predicate::predicates::crit_value_predicate::CritValuePredicate;
+use crate::upgrade_predicate::predicates::effective_rolls_predicate::EffectiveRollsPredicate;
+use crate::upgrade_predicate::predicates::potential_predicate::PotentialPredicate;
+use crate::upgrade_predicate::predicates::requires_crit_predicate::RequiresCritPredicate;
+use crate::upgrade_predicate::upgrade_predicate::UpgradePredicate;
+
+/// serializable leveling policy, e.g.
+/// `{"And": ["RequiresCrit", {"EffectiveRolls": {"level": 4, "min_rolls": 3, "effective_stats": ["CriticalRate", "CriticalDamage"]}}]}`
+#[derive(Serialize, Deserialize)]
+pub enum UpgradePredicateConfig {
+    AllTrue,
+    EffectiveRolls { level: i32, min_rolls: usize, effective_stats: Vec<StatName> },
+    CritValue { level: i32, min_crit_value: f64 },
+    RequiresCrit,
+    Potential {
+        level: i32,
+        min_potential: f64,
+        name: PotentialFunctionName,
+        config: Option<PotentialFunctionConfig>,
+    },
+    And(Vec<UpgradePredicateConfig>),
+    Or(Vec<UpgradePredicateConfig>),
+    Not(Box<UpgradePredicateConfig>),
+}
+
+impl Default for UpgradePredicateConfig {
+    fn default() -> Self {
+        UpgradePredicateConfig::AllTrue
+    }
+}
+
+impl UpgradePredicateConfig {
+    pub fn to_predicate(&self) -> Box<dyn UpgradePredicate> {
+        match self {
+            UpgradePredicateConfig::AllTrue => Box::new(AllTruePredicate),
+            UpgradePredicateConfig::EffectiveRolls { level, min_rolls, effective_stats } => Box::new(EffectiveRollsPredicate {
+                level: *level,
+                min_rolls: *min_rolls,
+                effective_stats: effective_stats.clone(),
+            }),
+            UpgradePredicateConfig::CritValue { level, min_crit_value } => Box::new(CritValuePredicate {
+                level: *level,
+                min_crit_value: *min_crit_value,
+            }),
+            UpgradePredicateConfig::RequiresCrit => Box::new(RequiresCritPredicate),
+            UpgradePredicateConfig::Potential { level, min_potential, name, config } => {
+                let no_config = PotentialFunctionConfig::NoConfig;
+                Box::new(PotentialPredicate {
+                    level: *level,
+                    min_potential: *min_potential,
+                    potential_function: name.create(config.as_ref().unwrap_or(&no_config)),
+                })
+            },
+            UpgradePredicateConfig::And(x) => Box::new(AndPredicate {
+                predicates: x.iter().map(|p| p.to_predicate()).collect()
+            }),
+            UpgradePredicateConfig::Or(x) => Box::new(OrPredicate {
+                predicates: x.iter().map(|p| p.to_predicate()).collect()
+            }),
+            UpgradePredicateConfig::Not(x) => Box::new(NotPredicate {
+                predicate: x.to_predicate()
+            }),
+        }
+    }
+}
@@ -21,7 +21,7 @@ impl<A: Attribute> WeaponEffect<A> for AstralVulturesCrimsonPlumageEffect {
         let refine = data.refine as f64;
 
         let atk_bonus = 0.18 + 0.06 * refine;
-        attribute.add_atk_percentage("星鹫赤羽被动", atk_bonus * self.rate);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("星鹫赤羽被动", atk_bonus * self.rate));
 
         let charged_bonus = if self.different_count == 0 {
             0.0
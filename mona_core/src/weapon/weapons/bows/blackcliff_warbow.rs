@@ -31,7 +31,7 @@ impl BlackcliffWarbowEffect {
 impl<T: Attribute> WeaponEffect<T> for BlackcliffWarbowEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let atk_bonus = (data.refine as f64 * 0.03 + 0.09) * self.stack;
-        attribute.add_atk_percentage("黑岩战弓被动等效", atk_bonus);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("黑岩战弓被动等效", atk_bonus));
     }
 }
 
@@ -20,9 +20,9 @@ impl<A: Attribute> WeaponEffect<A> for ChainBreakerEffect {
         let refine = data.refine as f64;
 
         let step = 0.036 + 0.012 * refine;
-        attribute.add_atk_percentage("碎链被动", step * self.count as f64);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("碎链被动", step * self.count as f64));
         if self.count >= 3 {
-            attribute.set_value_by(AttributeName::ElementalMastery, "碎链被动", 18.0 + 6.0 * refine);
+            attribute.set_combat_value_by(AttributeName::ElementalMastery, "碎链被动", 18.0 + 6.0 * refine);
         }
     }
 }
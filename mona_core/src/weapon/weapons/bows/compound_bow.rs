@@ -33,7 +33,7 @@ impl<T: Attribute> WeaponEffect<T> for CompoundBowEffect {
         let refine = data.refine as f64;
         let atk_bonus = (refine * 0.01 + 0.03) * self.stack;
         let speed_bonus = (refine * 0.003 + 0.009) * self.stack;
-        attribute.add_atk_percentage("钢轮弓被动等效", atk_bonus);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("钢轮弓被动等效", atk_bonus));
         attribute.set_value_by(AttributeName::SpeedNormalAttack, "钢轮弓被动等效", speed_bonus);
     }
 }
@@ -31,10 +31,11 @@ impl ElegyOfTheEndEffect {
 impl<T: Attribute> WeaponEffect<T> for ElegyOfTheEndEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let refine = data.refine as f64;
-        let em_bonus = refine * 15.0 + 45.0 + (refine * 25.0 + 75.0) * self.rate;
-        attribute.set_value_by(AttributeName::ElementalMastery, "终末嗟叹之诗被动等效", em_bonus);
+        attribute.set_value_by(AttributeName::ElementalMastery, "终末嗟叹之诗被动", refine * 15.0 + 45.0);
+        let em_bonus = (refine * 25.0 + 75.0) * self.rate;
+        attribute.set_combat_value_by(AttributeName::ElementalMastery, "终末嗟叹之诗被动等效", em_bonus);
         let atk_bonus = (refine * 0.05 + 0.15) * self.rate;
-        attribute.add_atk_percentage("终末嗟叹之诗被动等效", atk_bonus);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("终末嗟叹之诗被动等效", atk_bonus));
     }
 }
 
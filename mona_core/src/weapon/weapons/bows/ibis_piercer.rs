@@ -18,7 +18,7 @@ pub struct IbisPiercerEffect {
 impl<A: Attribute> WeaponEffect<A> for IbisPiercerEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let value = data.refine * 10 + 30;
-        attribute.set_value_by(AttributeName::ElementalMastery, "鹮穿之喙被动等效", value as f64 * self.stack);
+        attribute.set_combat_value_by(AttributeName::ElementalMastery, "鹮穿之喙被动等效", value as f64 * self.stack);
     }
 }
 
@@ -19,7 +19,7 @@ impl<A: Attribute> WeaponEffect<A> for KingsSquireEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
 
-        attribute.set_value_by(AttributeName::ElementalMastery, "王下近侍被动等效", self.rate * (refine * 20.0 + 40.0));
+        attribute.set_combat_value_by(AttributeName::ElementalMastery, "王下近侍被动等效", self.rate * (refine * 20.0 + 40.0));
     }
 }
 
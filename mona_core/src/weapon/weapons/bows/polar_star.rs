@@ -47,7 +47,7 @@ impl<T: Attribute> WeaponEffect<T> for PolarStarEffect {
             0.0
         };
 
-        attribute.add_atk_percentage("冬极白星被动等效", atk_bonus);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("冬极白星被动等效", atk_bonus));
     }
 }
 
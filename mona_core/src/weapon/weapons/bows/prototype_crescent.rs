@@ -30,7 +30,7 @@ impl PrototypeCrescentEffect {
 impl<T: Attribute> WeaponEffect<T> for PrototypeCrescentEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let value = (data.refine as f64 * 0.09 + 0.27) * self.rate;
-        attribute.add_atk_percentage("试作澹月被动等效", value);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("试作澹月被动等效", value));
     }
 }
 
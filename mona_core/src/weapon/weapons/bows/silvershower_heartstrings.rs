@@ -30,7 +30,7 @@ impl<A: Attribute> WeaponEffect<A> for SilvershowerHeartstringsEffect {
         } else {
             stack2 + (stack3 - stack2) * (self.stack - 2.0)
         };
-        attribute.add_hp_percentage("白雨心弦被动", value);
+        attribute.with_combat_sources(|attribute| attribute.add_hp_percentage("白雨心弦被动", value));
 
         attribute.set_value_by(AttributeName::CriticalElementalBurst, "白雨心弦被动", 0.21 + refine * 0.07);
     }
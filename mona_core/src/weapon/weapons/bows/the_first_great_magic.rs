@@ -23,7 +23,7 @@ impl<A: Attribute> WeaponEffect<A> for TheFirstGreatMagicEffect {
         attribute.set_value_by(AttributeName::BonusChargedAttack, "最初的大魔术被动", 0.04 * refine + 0.12);
         if self.diff_count > 0.0 {
             let interval = 0.04 * refine + 0.12;
-            attribute.add_atk_percentage("最初的大魔术被动", self.same_count * interval);
+            attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("最初的大魔术被动", self.same_count * interval));
         }
     }
 }
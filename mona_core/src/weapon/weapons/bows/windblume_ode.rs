@@ -30,7 +30,7 @@ impl WindblumeOdeEffect {
 impl<T: Attribute> WeaponEffect<T> for WindblumeOdeEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let atk_bonus = (data.refine as f64 * 0.04 + 0.12) * self.rate;
-        attribute.add_atk_percentage("风花之颂被动等效", atk_bonus);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("风花之颂被动等效", atk_bonus));
     }
 }
 
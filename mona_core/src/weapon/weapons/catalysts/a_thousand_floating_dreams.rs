@@ -23,8 +23,8 @@ impl<A: Attribute> WeaponEffect<A> for AThousandFloatingDreamsEffect {
         let em_delta = refine * 8.0 + 24.0;
         let bonus_delta = refine * 0.04 + 0.06;
 
-        attribute.set_value_by(AttributeName::ElementalMastery, "千夜浮梦被动", em_delta * self.same_count.min(3) as f64);
-        attribute.add_elemental_bonus("千夜浮梦被动", bonus_delta * self.diff_count.min(3) as f64);
+        attribute.set_combat_value_by(AttributeName::ElementalMastery, "千夜浮梦被动", em_delta * self.same_count.min(3) as f64);
+        attribute.with_combat_sources(|attribute| attribute.add_elemental_bonus("千夜浮梦被动", bonus_delta * self.diff_count.min(3) as f64));
     }
 }
 
@@ -30,7 +30,7 @@ impl BlackcliffAgateEffect {
 impl<T: Attribute> WeaponEffect<T> for BlackcliffAgateEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let atk_bonus = (data.refine as f64 * 0.03 + 0.09) * self.stack;
-        attribute.add_atk_percentage("黑岩绯玉被动等效", atk_bonus);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("黑岩绯玉被动等效", atk_bonus));
     }
 }
 
@@ -35,7 +35,7 @@ impl<T: Attribute> WeaponEffect<T> for DodocoTalesEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let refine = data.refine as f64;
         attribute.set_value_by(AttributeName::BonusChargedAttack, "嘟嘟可故事集被动等效", (refine * 0.04 + 0.12) * self.rate1);
-        attribute.add_atk_percentage("嘟嘟可故事集被动等效", (refine * 0.02 + 0.06) * self.rate2);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("嘟嘟可故事集被动等效", (refine * 0.02 + 0.06) * self.rate2));
     }
 }
 
@@ -24,7 +24,7 @@ impl<A: Attribute> WeaponEffect<A> for EmeraldOrbEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         let value = (refine * 0.05 + 0.15) * self.rate;
-        attribute.add_atk_percentage("异世界行记被动等效", value);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("异世界行记被动等效", value));
     }
 }
 
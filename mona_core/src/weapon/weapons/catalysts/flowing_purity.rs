@@ -20,7 +20,7 @@ pub struct FlowingPurityEffect {
 impl<A: Attribute> WeaponEffect<A> for FlowingPurityEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
-        attribute.add_elemental_bonus("纯水流华被动", self.rate1 * (0.02 * refine + 0.06));
+        attribute.with_combat_sources(|attribute| attribute.add_elemental_bonus("纯水流华被动", self.rate1 * (0.02 * refine + 0.06)));
 
         let bonus_elements = [
             AttributeName::BonusHydro,
@@ -35,18 +35,28 @@ impl<A: Attribute> WeaponEffect<A> for FlowingPurityEffect {
 
         let rate2 = self.rate2;
         for target in bonus_elements {
-            attribute.add_edge1(
-                AttributeName::HP,
-                target,
-                Box::new(move |hp, _| {
-                    let max = 0.03 * refine + 0.09;
-                    let step = 0.005 * refine + 0.015;
-                    let value = max.min((hp / 1000.0).floor() * step);
-                    value * rate2
-                }),
-                Box::new(|gx, gy, gz| (0.0, 0.0)),
-                "纯水流华被动"
-            )
+            attribute.with_combat_sources(|attribute| {
+                attribute.add_edge1(
+                    AttributeName::HP,
+                    target,
+                    Box::new(move |hp, _| {
+                        let max = 0.03 * refine + 0.09;
+                        let step = 0.005 * refine + 0.015;
+                        let value = max.min((hp / 1000.0).floor() * step);
+                        value * rate2
+                    }),
+                    Box::new(move |grad, hp, _| {
+                        let max = 0.03 * refine + 0.09;
+                        let step = 0.005 * refine + 0.015;
+                        if (hp / 1000.0).floor() * step < max {
+                            (grad * step / 1000.0 * rate2, 0.0)
+                        } else {
+                            (0.0, 0.0)
+                        }
+                    }),
+                    "纯水流华被动"
+                )
+            });
         }
     }
 }
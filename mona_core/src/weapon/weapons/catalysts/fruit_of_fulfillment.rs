@@ -18,8 +18,8 @@ impl<A: Attribute> WeaponEffect<A> for FruitOfFulfillmentEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
 
-        attribute.set_value_by(AttributeName::ElementalMastery, "盈满之实被动等效", self.stack * (refine * 3.0 + 21.0));
-        attribute.add_atk_percentage("盈满之实被动等效", self.stack * -0.05);
+        attribute.set_combat_value_by(AttributeName::ElementalMastery, "盈满之实被动等效", self.stack * (refine * 3.0 + 21.0));
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("盈满之实被动等效", self.stack * -0.05));
     }
 }
 
@@ -22,19 +22,30 @@ impl<A: Attribute> WeaponEffect<A> for JadeFallsSplendorEffect {
         let refine = data.refine as f64;
         let rate = self.rate;
 
-        attribute.add_edge1(
-            AttributeName::HP,
-            bonus_name,
-            Box::new(move |hp, _| {
-                let temp = (hp / 1000.0).floor();
-                let max = 0.08 * refine + 0.04;
-                let step = 0.002 * refine + 0.001;
-                let value = max.min(step * temp);
-                value * rate
-            }),
-            Box::new(|grad, x, y| (0.0, 0.0)), // todo
-            "碧落之珑被动等效"
-        );
+        attribute.with_combat_sources(|attribute| {
+            attribute.add_edge1(
+                AttributeName::HP,
+                bonus_name,
+                Box::new(move |hp, _| {
+                    let temp = (hp / 1000.0).floor();
+                    let max = 0.08 * refine + 0.04;
+                    let step = 0.002 * refine + 0.001;
+                    let value = max.min(step * temp);
+                    value * rate
+                }),
+                // the step function is treated as linear below the cap
+                Box::new(move |grad, hp, _| {
+                    let max = 0.08 * refine + 0.04;
+                    let step = 0.002 * refine + 0.001;
+                    if step * (hp / 1000.0).floor() < max {
+                        (grad * step / 1000.0 * rate, 0.0)
+                    } else {
+                        (0.0, 0.0)
+                    }
+                }),
+                "碧落之珑被动等效"
+            );
+        });
     }
 }
 
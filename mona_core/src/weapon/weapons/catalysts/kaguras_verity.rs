@@ -22,7 +22,7 @@ impl<A: Attribute> WeaponEffect<A> for KagurasVerityEffect {
         let value = refine * 0.03 + 0.09;
 
         attribute.set_value_by(AttributeName::BonusElementalSkill, "神乐之真意被动等效", value * self.stack);
-        attribute.add_elemental_bonus("神乐之真意被动等效", value * self.full_rate);
+        attribute.with_combat_sources(|attribute| attribute.add_elemental_bonus("神乐之真意被动等效", value * self.full_rate));
     }
 }
 
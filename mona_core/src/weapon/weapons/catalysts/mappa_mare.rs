@@ -30,7 +30,7 @@ impl MappaMareEffect {
 impl<T: Attribute> WeaponEffect<T> for MappaMareEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let value = (data.refine as f64 * 0.02 + 0.06) * self.stack;
-        attribute.add_elemental_bonus("万国诸海图谱被动等效", value);
+        attribute.with_combat_sources(|attribute| attribute.add_elemental_bonus("万国诸海图谱被动等效", value));
     }
 }
 
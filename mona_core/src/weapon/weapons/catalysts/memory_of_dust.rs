@@ -36,7 +36,7 @@ impl<T: Attribute> WeaponEffect<T> for MemoryOfDustEffect {
         let refine = data.refine as f64;
         attribute.set_value_by(AttributeName::ShieldStrength, "尘世之锁被动", refine * 0.05 + 0.15);
         let atk_bonus = (refine * 0.01 + 0.03) * self.stack * (1.0 + self.shield_rate);
-        attribute.add_atk_percentage("尘世之锁被动等效", atk_bonus);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("尘世之锁被动等效", atk_bonus));
     }
 }
 
@@ -18,7 +18,7 @@ impl<A: Attribute> WeaponEffect<A> for OathswornEyeEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         let recharge_bonus = (refine * 0.06 + 0.18) * self.rate;
-        attribute.set_value_by(AttributeName::Recharge, "证誓之明瞳被动等效", recharge_bonus);
+        attribute.set_combat_value_by(AttributeName::Recharge, "证誓之明瞳被动等效", recharge_bonus);
     }
 }
 
@@ -25,7 +25,13 @@ impl<A: Attribute> WeaponEffect<A> for RingOfYaxcheEffect {
             AttributeName::HP,
             AttributeName::BonusNormalAttack,
             Box::new(move |hp, _| ((hp / 1000.0).floor() * step).min(max)),
-            Box::new(|_x, _y, _grad| (0.0, 0.0)),
+            Box::new(move |grad, hp, _| {
+                if (hp / 1000.0).floor() * step < max {
+                    (grad * step / 1000.0, 0.0)
+                } else {
+                    (0.0, 0.0)
+                }
+            }),
             "木棉之环被动"
         );
     }
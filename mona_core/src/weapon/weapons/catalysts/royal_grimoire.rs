@@ -19,7 +19,10 @@ impl<A: Attribute> WeaponEffect<A> for RoyalGrimoireEffect {
             AttributeName::CriticalBase,
             AttributeName::CriticalAttacking,
             Box::new(move |x, _| royal_series_critical_bonus(refine, x)),
-            Box::new(|grad, _x1, _x2| (grad, 0.0)),
+            Box::new(move |grad, x, _| {
+                let d = (royal_series_critical_bonus(refine, x + 1e-4) - royal_series_critical_bonus(refine, x - 1e-4)) / 2e-4;
+                (grad * d, 0.0)
+            }),
             "宗室被动等效"
         )
     }
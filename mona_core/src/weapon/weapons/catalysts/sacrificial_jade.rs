@@ -18,8 +18,8 @@ pub struct SacrificialJadeEffect {
 impl<A: Attribute> WeaponEffect<A> for SacrificialJadeEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
-        attribute.add_hp_percentage("遗祀玉珑被动", self.rate * (0.08 * refine + 0.24));
-        attribute.set_value_by(AttributeName::ElementalMastery, "遗祀玉珑被动", self.rate * (10.0 * refine + 30.0));
+        attribute.with_combat_sources(|attribute| attribute.add_hp_percentage("遗祀玉珑被动", self.rate * (0.08 * refine + 0.24)));
+        attribute.set_combat_value_by(AttributeName::ElementalMastery, "遗祀玉珑被动", self.rate * (10.0 * refine + 30.0));
     }
 }
 
@@ -25,7 +25,7 @@ impl<A: Attribute> WeaponEffect<A> for SunnyMorningSleepInEffect {
         let bonus2 = 72.0 + 24.0 * refine;
         let bonus3 = 24.0 + 8.0 * refine;
 
-        attribute.set_value_by(AttributeName::ElementalMastery, "寝正月初晴被动",
+        attribute.set_combat_value_by(AttributeName::ElementalMastery, "寝正月初晴被动",
            bonus1 * self.rate1 + bonus2 * self.rate2 + bonus3 * self.rate3);
     }
 }
@@ -34,9 +34,11 @@ impl<T: Attribute> WeaponEffect<T> for TheWidsithEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let refine = data.refine as f64;
 
-        attribute.add_atk_percentage("流浪乐章被动等效（宣叙调）", (refine * 0.15 + 0.45) * self.r1);
-        attribute.add_elemental_bonus("流浪乐章被动等效（咏叹调）", (refine * 0.12 + 0.36) * self.r2);
-        attribute.set_value_by(AttributeName::ElementalMastery, "流浪乐章被动等效（间奏曲）", (refine * 60.0 + 180.0) * self.r3);
+        attribute.with_combat_sources(|attribute| {
+            attribute.add_atk_percentage("流浪乐章被动等效（宣叙调）", (refine * 0.15 + 0.45) * self.r1);
+            attribute.add_elemental_bonus("流浪乐章被动等效（咏叹调）", (refine * 0.12 + 0.36) * self.r2);
+        });
+        attribute.set_combat_value_by(AttributeName::ElementalMastery, "流浪乐章被动等效（间奏曲）", (refine * 60.0 + 180.0) * self.r3);
     }
 }
 
@@ -24,7 +24,7 @@ impl<A: Attribute> WeaponEffect<A> for TwinNephriteEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         let value = (refine * 0.02 + 0.1) * self.rate;
-        attribute.add_atk_percentage("甲级宝珏被动等效", value);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("甲级宝珏被动等效", value));
     }
 }
 
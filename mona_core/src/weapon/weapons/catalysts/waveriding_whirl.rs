@@ -22,7 +22,7 @@ impl<A: Attribute> WeaponEffect<A> for WaveridingWhirlEffect {
 
         let bonus = 0.15 + 0.05 * refine
             + (0.09 + 0.03 * refine) * (self.hydro_count.min(2) as f64);
-        attribute.add_hp_percentage("乘浪的回旋被动", bonus * self.rate);
+        attribute.with_combat_sources(|attribute| attribute.add_hp_percentage("乘浪的回旋被动", bonus * self.rate));
     }
 }
 
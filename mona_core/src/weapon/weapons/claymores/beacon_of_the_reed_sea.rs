@@ -35,8 +35,10 @@ impl<T: Attribute> WeaponEffect<T> for BeaconOfTheReedSeaEffect {
         let refine = data.refine as f64;
         let value1 = (refine * 0.10 + 0.30) * self.rate_atk;
         let value2 = (refine * 0.08 + 0.24) * self.rate_hp;
-        attribute.add_atk_percentage("苇海信标被动等效", value1);
-        attribute.add_hp_percentage("苇海信标被动等效", value2);
+        attribute.with_combat_sources(|attribute| {
+            attribute.add_atk_percentage("苇海信标被动等效", value1);
+            attribute.add_hp_percentage("苇海信标被动等效", value2);
+        });
     }
 }
 
@@ -30,7 +30,7 @@ impl BlackcliffSlasherEffect {
 impl<T: Attribute> WeaponEffect<T> for BlackcliffSlasherEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let value = (data.refine as f64 * 0.03 + 0.09) * self.stack;
-        attribute.add_atk_percentage("黑岩斩刀被动等效", value);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("黑岩斩刀被动等效", value));
     }
 }
 
@@ -32,9 +32,9 @@ impl<T: Attribute> WeaponEffect<T> for LithicBladeEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let refine = data.refine as f64;
         let atk_bonus = (refine * 0.01 + 0.06) * self.liyue_count as f64;
-        attribute.add_atk_percentage("千岩古剑被动", atk_bonus);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("千岩古剑被动", atk_bonus));
         let crit_bonus = (refine * 0.01 + 0.02) * self.liyue_count as f64;
-        attribute.set_value_by(AttributeName::CriticalBase, "千岩古剑被动", crit_bonus);
+        attribute.set_combat_value_by(AttributeName::CriticalBase, "千岩古剑被动", crit_bonus);
     }
 }
 
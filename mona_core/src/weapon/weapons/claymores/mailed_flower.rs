@@ -32,8 +32,8 @@ impl<T: Attribute> WeaponEffect<T> for MailedFlowerEffect {
         let refine = data.refine as f64;
         let value1 = (refine * 0.03 + 0.09)*self.rate;
         let value2 = (refine * 12.0 + 36.0)*self.rate;
-        attribute.add_atk_percentage("饰铁之花被动等效", value1);
-        attribute.set_value_by(AttributeName::ElementalMastery, "饰铁之花被动等效", value2);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("饰铁之花被动等效", value1));
+        attribute.set_combat_value_by(AttributeName::ElementalMastery, "饰铁之花被动等效", value2);
     }
 }
 
@@ -18,13 +18,15 @@ impl<A: Attribute> WeaponEffect<A> for MakhairaAquamarineEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let value = (0.06 * data.refine as f64 + 0.18) * self.rate;
 
-        attribute.add_edge1(
-            AttributeName::ElementalMastery,
-            AttributeName::ATKFixed,
-            Box::new(move |em, _| value * em),
-            Box::new(move |em, _, grad| (value, 0.0)),
-            "玛海菈的水色被动"
-        );
+        attribute.with_combat_sources(|attribute| {
+            attribute.add_edge1(
+                AttributeName::ElementalMastery,
+                AttributeName::ATKFixed,
+                Box::new(move |em, _| value * em),
+                Box::new(move |grad, _em, _| (grad * value, 0.0)),
+                "玛海菈的水色被动"
+            );
+        });
     }
 }
 
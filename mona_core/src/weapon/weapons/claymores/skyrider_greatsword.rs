@@ -24,7 +24,7 @@ impl<A: Attribute> WeaponEffect<A> for SkyriderGreatswordEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         let value = (refine * 0.01 + 0.05) * self.stack;
-        attribute.add_atk_percentage("飞天大御剑被动等效", value);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("飞天大御剑被动等效", value));
     }
 }
 
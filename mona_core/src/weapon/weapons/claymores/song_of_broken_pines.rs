@@ -30,8 +30,9 @@ impl SongOfBrokenPinesEffect {
 impl<T: Attribute> WeaponEffect<T> for SongOfBrokenPinesEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let refine = data.refine as f64;
-        let value = refine * 0.04 + 0.12 + (refine * 0.05 + 0.15) * self.rate;
-        attribute.add_atk_percentage("松籁响起之时被动等效", value);
+        attribute.add_atk_percentage("松籁响起之时被动", refine * 0.04 + 0.12);
+        let value = (refine * 0.05 + 0.15) * self.rate;
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("松籁响起之时被动等效", value));
     }
 }
 
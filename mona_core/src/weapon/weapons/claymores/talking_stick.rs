@@ -19,8 +19,10 @@ pub struct TalkingStickEffect {
 impl<A: Attribute> WeaponEffect<A> for TalkingStickEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
-        attribute.add_atk_percentage("聊聊棒被动", (0.04 * refine + 0.12) * self.rate1);
-        attribute.add_elemental_bonus("聊聊棒被动", (0.03 * refine + 0.09) * self.rate2);
+        attribute.with_combat_sources(|attribute| {
+            attribute.add_atk_percentage("聊聊棒被动", (0.04 * refine + 0.12) * self.rate1);
+            attribute.add_elemental_bonus("聊聊棒被动", (0.03 * refine + 0.09) * self.rate2);
+        });
     }
 }
 
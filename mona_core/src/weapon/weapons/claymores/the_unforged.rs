@@ -36,7 +36,7 @@ impl<T: Attribute> WeaponEffect<T> for TheUnforgedEffect {
         attribute.set_value_by(AttributeName::ShieldStrength, "无工之剑被动", refine * 0.05 + 0.15);
 
         let atk_bonus = (refine * 0.01 + 0.03) * self.stack * (1.0 + self.shield_rate);
-        attribute.add_atk_percentage("无工之剑被动等效", atk_bonus);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("无工之剑被动等效", atk_bonus));
     }
 }
 
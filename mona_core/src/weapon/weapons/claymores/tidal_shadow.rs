@@ -19,7 +19,7 @@ impl<A: Attribute> WeaponEffect<A> for TidalShadowEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         let value = 0.06 * refine + 0.18;
-        attribute.add_atk_percentage("浪影阔剑被动", value * self.rate);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("浪影阔剑被动", value * self.rate));
     }
 }
 
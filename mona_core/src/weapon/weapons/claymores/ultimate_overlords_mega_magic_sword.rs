@@ -19,7 +19,8 @@ impl<A: Attribute> WeaponEffect<A> for UltimateOverlordsMegaMagicSwordEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         let value = 0.03 * refine + 0.09;
-        attribute.add_atk_percentage("「究极霸王超级魔剑」被动", value + self.rate * value);
+        attribute.add_atk_percentage("「究极霸王超级魔剑」被动", value);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("「究极霸王超级魔剑」被动等效", self.rate * value));
     }
 }
 
@@ -30,8 +30,10 @@ impl WhiteblindEffect {
 impl<T: Attribute> WeaponEffect<T> for WhiteblindEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let value = (data.refine as f64 * 0.015 + 0.045) * self.stack;
-        attribute.add_atk_percentage("白影剑被动等效", value);
-        attribute.add_def_percentage("白影剑被动等效", value);
+        attribute.with_combat_sources(|attribute| {
+            attribute.add_atk_percentage("白影剑被动等效", value);
+            attribute.add_def_percentage("白影剑被动等效", value);
+        });
     }
 }
 
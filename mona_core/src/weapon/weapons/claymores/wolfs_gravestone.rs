@@ -32,7 +32,8 @@ impl<T: Attribute> WeaponEffect<T> for WolfsGravestoneEffect {
         let refine = data.refine as f64;
         let value1 = refine * 0.05 + 0.15;
         let value2 = (refine * 0.1 + 0.3) * self.rate;
-        attribute.add_atk_percentage("狼的末路被动等效", value1 + value2);
+        attribute.add_atk_percentage("狼的末路被动", value1);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("狼的末路被动等效", value2));
     }
 }
 
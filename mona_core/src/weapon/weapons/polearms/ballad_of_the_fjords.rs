@@ -19,7 +19,7 @@ impl<A: Attribute> WeaponEffect<A> for BalladOfTheFjordsEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         if self.use_effect {
             let value = 30 * data.refine + 90;
-            attribute.set_value_by(AttributeName::ElementalMastery, "峡湾长歌被动", value as f64);
+            attribute.set_combat_value_by(AttributeName::ElementalMastery, "峡湾长歌被动", value as f64);
         }
     }
 }
@@ -38,7 +38,7 @@ impl<T: Attribute> WeaponEffect<T> for CalamityQuellerEffect {
         attribute.add_elemental_bonus("息灾被动", elemental_bonus);
 
         let atk_bonus = (refine * 0.008 + 0.024) * self.stack * (1.0 + self.backend_rate);
-        attribute.add_atk_percentage("息灾被动等效", atk_bonus);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("息灾被动等效", atk_bonus));
     }
 }
 
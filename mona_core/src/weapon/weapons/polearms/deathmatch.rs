@@ -31,14 +31,16 @@ impl DeathmatchEffect {
 impl<T: Attribute> WeaponEffect<T> for DeathmatchEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let refine = data.refine as f64;
-        if self.ge2 {
-            let value = refine * 0.04 + 0.12;
-            attribute.add_atk_percentage("决斗之枪被动", value);
-            attribute.add_def_percentage("决斗之枪被动", value);
-        } else {
-            let value = refine * 0.06 + 0.18;
-            attribute.add_atk_percentage("决斗之枪被动", value);
-        }
+        attribute.with_combat_sources(|attribute| {
+            if self.ge2 {
+                let value = refine * 0.04 + 0.12;
+                attribute.add_atk_percentage("决斗之枪被动", value);
+                attribute.add_def_percentage("决斗之枪被动", value);
+            } else {
+                let value = refine * 0.06 + 0.18;
+                attribute.add_atk_percentage("决斗之枪被动", value);
+            }
+        });
     }
 }
 
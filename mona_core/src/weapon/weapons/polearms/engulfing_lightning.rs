@@ -31,7 +31,7 @@ impl<T: Attribute> WeaponEffect<T> for EngulfingLightningEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let refine = data.refine as f64;
         let recharge_bonus = (refine * 0.05 + 0.25) * self.rate;
-        attribute.set_value_by(AttributeName::Recharge, "薙草之稻光被动等效", recharge_bonus);
+        attribute.set_combat_value_by(AttributeName::Recharge, "薙草之稻光被动等效", recharge_bonus);
 
         let max_bonus = refine * 0.1 + 0.7;
         let value = refine * 0.07 + 0.21;
@@ -43,14 +43,14 @@ impl<T: Attribute> WeaponEffect<T> for EngulfingLightningEffect {
             Box::new(move |grad, atk_base, recharge| {
                 let grad_atk_base = grad * ((recharge - 1.0) * value).min(max_bonus);
                 let grad_recharge = if (recharge - 1.0) * value <= max_bonus {
-                    value * atk_base
+                    grad * value * atk_base
                 } else {
                     0.0
                 };
 
                 (grad_atk_base, grad_recharge)
             }),
-            "薙草之稻光被动等效"
+            "薙草之稻光被动"
         )
     }
 }
@@ -19,7 +19,7 @@ impl<A: Attribute> WeaponEffect<A> for FootprintOfTheRainbowEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         let bonus = 0.12 + 0.04 * refine;
-        attribute.add_def_percentage("虹的行迹被动", bonus * self.rate);
+        attribute.with_combat_sources(|attribute| attribute.add_def_percentage("虹的行迹被动", bonus * self.rate));
     }
 }
 
@@ -31,8 +31,8 @@ impl LithicSpearEffect {
 impl<T: Attribute> WeaponEffect<T> for LithicSpearEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let refine = data.refine as f64;
-        attribute.add_atk_percentage("千岩长枪被动", (refine * 0.01 + 0.06) * self.liyue_count as f64);
-        attribute.set_value_by(AttributeName::CriticalBase, "千岩长枪被动", (refine * 0.01 + 0.02) * self.liyue_count as f64);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("千岩长枪被动", (refine * 0.01 + 0.06) * self.liyue_count as f64));
+        attribute.set_combat_value_by(AttributeName::CriticalBase, "千岩长枪被动", (refine * 0.01 + 0.02) * self.liyue_count as f64);
     }
 }
 
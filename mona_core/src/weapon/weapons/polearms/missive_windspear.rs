@@ -17,8 +17,8 @@ pub struct MissiveWindspearEffect {
 impl<A: Attribute> WeaponEffect<A> for MissiveWindspearEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
-        attribute.add_atk_percentage("风信之锋被动等效", (0.03 * refine + 0.09) * self.rate);
-        attribute.set_value_by(AttributeName::ElementalMastery, "风信之锋被动等效", (12.0 * refine + 36.0) * self.rate);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("风信之锋被动等效", (0.03 * refine + 0.09) * self.rate));
+        attribute.set_combat_value_by(AttributeName::ElementalMastery, "风信之锋被动等效", (12.0 * refine + 36.0) * self.rate);
     }
 }
 
@@ -18,7 +18,7 @@ pub struct MoonpiercerEffect {
 impl<A: Attribute> WeaponEffect<A> for MoonpiercerEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
-        attribute.add_atk_percentage("贯月矢被动等效", self.rate * (refine * 0.04 + 0.12));
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("贯月矢被动等效", self.rate * (refine * 0.04 + 0.12)));
     }
 }
 
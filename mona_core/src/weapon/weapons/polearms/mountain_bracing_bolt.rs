@@ -21,7 +21,8 @@ impl<A: Attribute> WeaponEffect<A> for MountainBracingBoltEffect {
 
         let bonus = 0.09 + 0.03 * refine;
 
-        attribute.add_elemental_bonus("镇山之钉被动", bonus + bonus * self.rate);
+        attribute.add_elemental_bonus("镇山之钉被动", bonus);
+        attribute.with_combat_sources(|attribute| attribute.add_elemental_bonus("镇山之钉被动等效", bonus * self.rate));
     }
 }
 
@@ -35,7 +35,7 @@ impl<T: Attribute> WeaponEffect<T> for PrimordialJadeWingedSpearEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let refine = data.refine as f64;
         let atk_bonus = (refine * 0.007 + 0.025) * self.stack;
-        attribute.add_atk_percentage("和璞鸢被动等效", atk_bonus);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("和璞鸢被动等效", atk_bonus));
         let bonus = (refine * 0.03 + 0.09) * self.full_rate;
         attribute.set_value_by(AttributeName::BonusBase, "和璞鸢被动等效", bonus);
     }
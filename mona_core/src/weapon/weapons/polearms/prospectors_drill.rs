@@ -19,8 +19,10 @@ impl<A: Attribute> WeaponEffect<A> for ProspectorsDrillEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
 
-        attribute.add_atk_percentage("勘探钻机被动", (0.02 + 0.01 * refine) * self.stack);
-        attribute.add_elemental_bonus("勘探钻机被动", (0.055 + 0.015 * refine) * self.stack);
+        attribute.with_combat_sources(|attribute| {
+            attribute.add_atk_percentage("勘探钻机被动", (0.02 + 0.01 * refine) * self.stack);
+            attribute.add_elemental_bonus("勘探钻机被动", (0.055 + 0.015 * refine) * self.stack);
+        });
     }
 }
 
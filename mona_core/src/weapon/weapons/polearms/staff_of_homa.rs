@@ -33,14 +33,23 @@ impl<T: Attribute> WeaponEffect<T> for StaffOfHomaEffect {
         let refine = data.refine as f64;
         let hp_bonus = refine * 0.05 + 0.15;
         attribute.add_hp_percentage("护摩之杖被动", hp_bonus);
-        let atk_bonus_ratio = refine * 0.002 + 0.006 + (refine * 0.002 + 0.008) * self.be50_rate;
+        let atk_bonus_ratio = refine * 0.002 + 0.006;
         attribute.add_edge1(
             AttributeName::HP,
             AttributeName::ATKFixed,
             Box::new(move |x, _| x * atk_bonus_ratio),
             Box::new(move |grad, _x1, _x2| (grad * atk_bonus_ratio, 0.0)),
-            "护摩之杖被动等效"
+            "护摩之杖被动"
         );
+        // the bonus below 50% hp does not show on the panel
+        let atk_bonus_ratio = (refine * 0.002 + 0.008) * self.be50_rate;
+        attribute.with_combat_sources(|attribute| attribute.add_edge1(
+            AttributeName::HP,
+            AttributeName::ATKFixed,
+            Box::new(move |x, _| x * atk_bonus_ratio),
+            Box::new(move |grad, _x1, _x2| (grad * atk_bonus_ratio, 0.0)),
+            "护摩之杖被动等效"
+        ));
     }
 }
 
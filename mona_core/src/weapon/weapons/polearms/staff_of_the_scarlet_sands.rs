@@ -18,14 +18,22 @@ impl<A: Attribute> WeaponEffect<A> for StaffOfTheScarletSandsEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
 
-        let value = (0.13 * refine + 0.39) + (0.07 * refine + 0.21) * self.stack;
+        let value = 0.13 * refine + 0.39;
         attribute.add_edge1(
             AttributeName::ElementalMastery,
             AttributeName::ATKFixed,
             Box::new(move |em, _| em * value),
-            Box::new(move |em, _, grad| (value * grad, 0.0)),
-            "赤沙之杖被动等效",
+            Box::new(move |grad, _em, _| (grad * value, 0.0)),
+            "赤沙之杖被动",
         );
+        let value = (0.07 * refine + 0.21) * self.stack;
+        attribute.with_combat_sources(|attribute| attribute.add_edge1(
+            AttributeName::ElementalMastery,
+            AttributeName::ATKFixed,
+            Box::new(move |em, _| em * value),
+            Box::new(move |grad, _em, _| (grad * value, 0.0)),
+            "赤沙之杖被动等效",
+        ));
     }
 }
 
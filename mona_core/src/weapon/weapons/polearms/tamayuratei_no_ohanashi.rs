@@ -19,7 +19,7 @@ impl<A: Attribute> WeaponEffect<A> for TamayurateiNoOhanashiEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         let bonus = 0.15 + 0.05 * refine;
-        attribute.add_atk_percentage("且住亭御咄被动", bonus * self.rate);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("且住亭御咄被动", bonus * self.rate));
     }
 }
 
@@ -30,7 +30,7 @@ impl BlackcliffLongswordEffect {
 impl<T: Attribute> WeaponEffect<T> for BlackcliffLongswordEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let value = (data.refine as f64 * 0.03 + 0.09) * self.stack;
-        attribute.add_atk_percentage("黑岩长剑被动等效", value);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("黑岩长剑被动等效", value));
     }
 }
 
@@ -16,7 +16,7 @@ pub struct CursedBladeEffect {
 
 impl<A: Attribute> WeaponEffect<A> for CursedBladeEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
-        attribute.add_atk_percentage("笼钓瓶一心被动", 0.15 * self.rate);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("笼钓瓶一心被动", 0.15 * self.rate));
     }
 }
 
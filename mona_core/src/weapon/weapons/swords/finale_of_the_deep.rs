@@ -21,17 +21,27 @@ impl<A: Attribute> WeaponEffect<A> for FinaleOfTheDeepEffect {
         let refine = data.refine as f64;
         let rate2 = self.rate2;
 
-        attribute.add_atk_percentage("海渊终曲被动", (0.03 * refine + 0.09) * self.rate1);
-        attribute.add_edge1(
-            AttributeName::HP,
-            AttributeName::ATKFixed,
-            Box::new(move |hp, _| {
-                let max = 37.5 * refine + 112.5;
-                max.min(hp * 0.25 * (0.006 * refine + 0.018)) * rate2
-            }),
-            Box::new(|x, y, v| (0.0, 0.0)),
-            "海渊终曲被动"
-        );
+        attribute.with_combat_sources(|attribute| {
+            attribute.add_atk_percentage("海渊终曲被动", (0.03 * refine + 0.09) * self.rate1);
+            attribute.add_edge1(
+                AttributeName::HP,
+                AttributeName::ATKFixed,
+                Box::new(move |hp, _| {
+                    let max = 37.5 * refine + 112.5;
+                    max.min(hp * 0.25 * (0.006 * refine + 0.018)) * rate2
+                }),
+                Box::new(move |grad, hp, _| {
+                    let max = 37.5 * refine + 112.5;
+                    let ratio = 0.25 * (0.006 * refine + 0.018);
+                    if hp * ratio < max {
+                        (grad * ratio * rate2, 0.0)
+                    } else {
+                        (0.0, 0.0)
+                    }
+                }),
+                "海渊终曲被动"
+            );
+        });
     }
 }
 
@@ -19,7 +19,7 @@ impl<A: Attribute> WeaponEffect<A> for FleuveCendreFerrymanEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         attribute.set_value_by(AttributeName::CriticalElementalSkill, "灰河渡手被动", 0.02 * refine + 0.06);
-        attribute.set_value_by(AttributeName::Recharge, "灰河渡手被动", (0.04 * refine + 0.12) * self.rate);
+        attribute.set_combat_value_by(AttributeName::Recharge, "灰河渡手被动", (0.04 * refine + 0.12) * self.rate);
     }
 }
 
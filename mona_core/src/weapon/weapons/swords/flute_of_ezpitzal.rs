@@ -19,7 +19,7 @@ impl<A: Attribute> WeaponEffect<A> for FluteOfEzpitzalEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         let bonus = 0.12 + 0.04 * refine;
-        attribute.add_def_percentage("息燧之笛被动", bonus * self.rate);
+        attribute.with_combat_sources(|attribute| attribute.add_def_percentage("息燧之笛被动", bonus * self.rate));
     }
 }
 
@@ -37,7 +37,7 @@ impl<T: Attribute> WeaponEffect<T> for FreedomSwornEffect {
         attribute.set_value_by(AttributeName::BonusNormalAttack, "苍古自由之誓被动等效", dmg_bonus);
         attribute.set_value_by(AttributeName::BonusChargedAttack, "苍古自由之誓被动等效", dmg_bonus);
         attribute.set_value_by(AttributeName::BonusPlungingAttack, "苍古自由之誓被动等效", dmg_bonus);
-        attribute.add_atk_percentage("苍古自由之誓被动等效", atk_bonus);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("苍古自由之誓被动等效", atk_bonus));
     }
 }
 
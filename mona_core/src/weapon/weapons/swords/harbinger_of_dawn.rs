@@ -24,7 +24,7 @@ impl<A: Attribute> WeaponEffect<A> for HarbingerOfDawnEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         let value = (refine * 0.035 + 0.105) * self.rate;
-        attribute.set_value_by(AttributeName::CriticalBase, "黎明神剑被动等效", value);
+        attribute.set_combat_value_by(AttributeName::CriticalBase, "黎明神剑被动等效", value);
     }
 }
 
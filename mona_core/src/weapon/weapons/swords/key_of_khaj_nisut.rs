@@ -20,23 +20,25 @@ impl<A: Attribute> WeaponEffect<A> for KeyOfKhajNisutEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         attribute.add_hp_percentage("圣显之钥被动", 0.05 * refine + 0.15);
-        let em_bonus = (0.0003 * refine + 0.0009) * self.stack;
-        attribute.add_edge1(
-            AttributeName::HP,
-            AttributeName::ElementalMastery,
-            Box::new(move |hp, _| hp * em_bonus),
-            Box::new(move |hp, _, grad| (em_bonus, 0.0)),
-            "圣显之钥被动等效"
-        );
+        attribute.with_combat_sources(|attribute| {
+            let em_bonus = (0.0003 * refine + 0.0009) * self.stack;
+            attribute.add_edge1(
+                AttributeName::HP,
+                AttributeName::ElementalMastery,
+                Box::new(move |hp, _| hp * em_bonus),
+                Box::new(move |grad, _hp, _| (grad * em_bonus, 0.0)),
+                "圣显之钥被动等效"
+            );
 
-        let em_bonus2 = (0.0005 * refine + 0.0015) * self.rate;
-        attribute.add_edge1(
-            AttributeName::HP,
-            AttributeName::ElementalMasteryExtra,
-            Box::new(move |hp, _| hp * em_bonus2),
-            Box::new(move |hp, _, grad| (0.0, 0.0)),
-            "圣显之钥被动等效"
-        );
+            let em_bonus2 = (0.0005 * refine + 0.0015) * self.rate;
+            attribute.add_edge1(
+                AttributeName::HP,
+                AttributeName::ElementalMasteryExtra,
+                Box::new(move |hp, _| hp * em_bonus2),
+                Box::new(move |grad, _hp, _| (grad * em_bonus2, 0.0)),
+                "圣显之钥被动等效"
+            );
+        });
     }
 }
 
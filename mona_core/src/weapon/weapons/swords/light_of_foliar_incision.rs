@@ -26,7 +26,7 @@ impl<A: Attribute> WeaponEffect<A> for LightOfFoliarIncisionEffect {
                 AttributeName::ElementalMastery,
                 e,
                 Box::new(move |em, _| em * (0.3 * refine + 0.9) * rate),
-                Box::new(|grad, em, _| (0.0, 0.0)),
+                Box::new(move |grad, _em, _| (grad * (0.3 * refine + 0.9) * rate, 0.0)),
                 "裁叶萃光被动等效"
             );
         }
@@ -40,8 +40,9 @@ impl<T: Attribute> WeaponEffect<T> for MistsplitterReforgedEffect {
             0.0
         };
 
+        // emblem stacks do not show on the panel
         let attribute_name = AttributeName::bonus_name_by_element(self.element);
-        attribute.set_value_by(attribute_name, key, value2);
+        attribute.set_combat_value_by(attribute_name, "雾切之回光被动等效", value2);
     }
 }
 
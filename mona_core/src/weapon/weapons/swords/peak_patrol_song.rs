@@ -20,38 +20,46 @@ impl<A: Attribute> WeaponEffect<A> for PeakPatrolSongEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
 
-        let def_bonus = 0.06 + 0.02 * refine;
-        let ele_bonus = 0.075 + 0.025 * refine;
-        attribute.add_def_percentage("岩峰巡歌被动", def_bonus * self.stack);
-        attribute.add_elemental_bonus("岩峰巡歌被动", ele_bonus * self.stack);
+        attribute.with_combat_sources(|attribute| {
+            let def_bonus = 0.06 + 0.02 * refine;
+            let ele_bonus = 0.075 + 0.025 * refine;
+            attribute.add_def_percentage("岩峰巡歌被动", def_bonus * self.stack);
+            attribute.add_elemental_bonus("岩峰巡歌被动", ele_bonus * self.stack);
 
-        let ele_bonus2 = 0.06 + 0.02 * refine;
-        let max_bonus2 = 0.192 + 0.064 * refine;
-        // let bonus2 = (ele_bonus2 * (self.def / 1000.0).floor()).min(max_bonus2);
-        // let bonus2 = (ele_bonus2 * (self.def / 1000.0)).min(max_bonus2);
-        //
-        // attribute.add_elemental_bonus("岩峰巡歌被动", bonus2 * self.rate);
+            let ele_bonus2 = 0.06 + 0.02 * refine;
+            let max_bonus2 = 0.192 + 0.064 * refine;
+            // let bonus2 = (ele_bonus2 * (self.def / 1000.0).floor()).min(max_bonus2);
+            // let bonus2 = (ele_bonus2 * (self.def / 1000.0)).min(max_bonus2);
+            //
+            // attribute.add_elemental_bonus("岩峰巡歌被动", bonus2 * self.rate);
 
-        let keys = [
-            AttributeName::BonusPhysical,
-            AttributeName::BonusPyro,
-            AttributeName::BonusCryo,
-            AttributeName::BonusHydro,
-            AttributeName::BonusGeo,
-            AttributeName::BonusAnemo,
-            AttributeName::BonusDendro,
-            AttributeName::BonusElectro,
-        ];
-        for k in keys {
-            let rate = self.rate;
-            attribute.add_edge1(
-                AttributeName::DEF,
-                k,
-                Box::new(move |def, _| (ele_bonus2 * (def / 1000.0)).min(max_bonus2) * rate),
-                Box::new(|_x, _y, _grad| (0.0, 0.0)),
-                "岩峰巡歌被动"
-            );
-        }
+            let keys = [
+                AttributeName::BonusPhysical,
+                AttributeName::BonusPyro,
+                AttributeName::BonusCryo,
+                AttributeName::BonusHydro,
+                AttributeName::BonusGeo,
+                AttributeName::BonusAnemo,
+                AttributeName::BonusDendro,
+                AttributeName::BonusElectro,
+            ];
+            for k in keys {
+                let rate = self.rate;
+                attribute.add_edge1(
+                    AttributeName::DEF,
+                    k,
+                    Box::new(move |def, _| (ele_bonus2 * (def / 1000.0)).min(max_bonus2) * rate),
+                    Box::new(move |grad, def, _| {
+                        if ele_bonus2 * (def / 1000.0) < max_bonus2 {
+                            (grad * ele_bonus2 / 1000.0 * rate, 0.0)
+                        } else {
+                            (0.0, 0.0)
+                        }
+                    }),
+                    "岩峰巡歌被动"
+                );
+            }
+        });
     }
 }
 
@@ -30,8 +30,10 @@ impl PrototypeRancourEffect {
 impl<T: Attribute> WeaponEffect<T> for PrototypeRancourEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut T) {
         let value = (data.refine as f64 * 0.01 + 0.03) * self.stack;
-        attribute.add_atk_percentage("试作斩岩被动等效", value);
-        attribute.add_def_percentage("试作斩岩被动等效", value);
+        attribute.with_combat_sources(|attribute| {
+            attribute.add_atk_percentage("试作斩岩被动等效", value);
+            attribute.add_def_percentage("试作斩岩被动等效", value);
+        });
     }
 }
 
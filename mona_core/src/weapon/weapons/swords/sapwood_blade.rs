@@ -17,7 +17,7 @@ pub struct SapwoodBladeEffect {
 impl<A: Attribute> WeaponEffect<A> for SapwoodBladeEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
-        attribute.set_value_by(AttributeName::ElementalMastery, "原木刀被动等效", (15.0 * refine + 45.0) * self.rate);
+        attribute.set_combat_value_by(AttributeName::ElementalMastery, "原木刀被动等效", (15.0 * refine + 45.0) * self.rate);
     }
 }
 
@@ -24,7 +24,7 @@ impl<A: Attribute> WeaponEffect<A> for SkyriderSwordEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         let value = (refine * 0.03 + 0.09) * self.rate;
-        attribute.add_atk_percentage("飞天御剑被动等效", value);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("飞天御剑被动等效", value));
     }
 }
 
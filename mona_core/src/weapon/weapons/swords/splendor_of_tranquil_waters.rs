@@ -20,7 +20,7 @@ impl<A: Attribute> WeaponEffect<A> for SplendorOfTranquilWatersEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         attribute.set_value_by(AttributeName::BonusElementalSkill, "静水流涌之辉被动", ((0.02 * refine) + 0.06) * self.stack1);
-        attribute.add_hp_percentage("静水流涌之辉被动", (0.035 * refine + 0.105) * self.stack2);
+        attribute.with_combat_sources(|attribute| attribute.add_hp_percentage("静水流涌之辉被动", (0.035 * refine + 0.105) * self.stack2));
     }
 }
 
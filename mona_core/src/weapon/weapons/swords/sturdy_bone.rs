@@ -21,7 +21,7 @@ impl<A: Attribute> WeaponEffect<A> for SturdyBoneEffect {
 
         let bonus = 0.12 + 0.04 * refine;
 
-        attribute.set_value_by(AttributeName::ATKBase, "弥坚骨被动", bonus * self.rate);
+        attribute.set_combat_value_by(AttributeName::ATKBase, "弥坚骨被动", bonus * self.rate);
     }
 }
 
@@ -37,7 +37,7 @@ impl<T: Attribute> WeaponEffect<T> for SummitShaperEffect {
         attribute.set_value_by(AttributeName::ShieldStrength, "斫峰之刃被动", refine as f64 * 0.05 + 0.15);
 
         let atk_bonus = (refine as f64 * 0.01 + 0.03) * (1.0 + self.shield_rate) * self.stack;
-        attribute.add_atk_percentage("斫峰之刃被动等效", atk_bonus);
+        attribute.with_combat_sources(|attribute| attribute.add_atk_percentage("斫峰之刃被动等效", atk_bonus));
     }
 }
 
@@ -19,7 +19,7 @@ impl<A: Attribute> WeaponEffect<A> for TheDockhandsAssistantEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         let value = 30.0 + refine * 10.0;
-        attribute.set_value_by(AttributeName::ElementalMastery, "船坞长剑被动", value * self.stack);
+        attribute.set_combat_value_by(AttributeName::ElementalMastery, "船坞长剑被动", value * self.stack);
     }
 }
 
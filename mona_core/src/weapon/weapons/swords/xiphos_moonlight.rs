@@ -18,13 +18,15 @@ impl<A: Attribute> WeaponEffect<A> for XiphosMoonlightEffect {
     fn apply(&self, data: &WeaponCommonData, attribute: &mut A) {
         let refine = data.refine as f64;
         let value = (refine * 0.00009 + 0.00027) * self.rate;
-        attribute.add_edge1(
-            AttributeName::ElementalMastery,
-            AttributeName::Recharge,
-            Box::new(move |x, _| value * x),
-            Box::new(move |x, y, grad| (value * grad, 0.0)),
-            "西福斯的月光被动",
-        );
+        attribute.with_combat_sources(|attribute| {
+            attribute.add_edge1(
+                AttributeName::ElementalMastery,
+                AttributeName::Recharge,
+                Box::new(move |x, _| value * x),
+                Box::new(move |grad, _x1, _x2| (grad * value, 0.0)),
+                "西福斯的月光被动",
+            );
+        });
     }
 }
 
@@ -132,6 +132,8 @@ impl MonaObjectTrait for MonaObjectTransformativeDamage {
             "hyperbloom" => self.damage.hyperbloom,
             "crystallize" => self.damage.crystallize,
             "burning" => self.damage.burning,
+            "lunar_charged" => self.damage.lunar_charged,
+            "lunar_bloom" => self.damage.lunar_bloom,
             x => {
                 return Err(RuntimeError::new(RuntimeErrorEnum::NotSupported, &format!("`TransformativeDamage` doesn't have prop name `{}`", x)));
             }
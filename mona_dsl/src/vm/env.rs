@@ -80,7 +80,8 @@ impl MonaEnv {
                 DamageContext {
                     character_common_data: &*unsafe_context.character_common_data,
                     enemy: &*unsafe_context.enemy,
-                    attribute: &*unsafe_context.attribute
+                    attribute: &*unsafe_context.attribute,
+                    other_enemies: &[]
                 }
             };
 
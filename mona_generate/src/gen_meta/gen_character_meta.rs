@@ -26,6 +26,9 @@ struct CharacterMeta {
     skill_map3: Vec<SkillMapItem>,
     config: Vec<String>,
     config_skill: Vec<String>,
+    // talent indices boosted at C3 and C5
+    c3_talent: usize,
+    c5_talent: usize,
 }
 
 struct SkillMapItem {
@@ -114,7 +117,9 @@ pub fn gen_character_meta_as_js_file() -> String {
             skill_map2: s2,
             skill_map3: s3,
             config: config_data,
-            config_skill
+            config_skill,
+            c3_talent: meta.c3_talent.index(),
+            c5_talent: meta.c5_talent.index(),
         })
     }
 
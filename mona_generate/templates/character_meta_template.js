@@ -30,6 +30,8 @@ export default {
         skillName1: {{ c.skill1_name_index }},
         skillName2: {{ c.skill2_name_index }},
         skillName3: {{ c.skill3_name_index }},
+        c3Talent: {{ c.c3_talent }},
+        c5Talent: {{ c.c5_talent }},
         skillMap1: [
             {% for s in c.skill_map1 %}
             { index: {{ s.index }}, text: {{ s.locale_index }} },
@@ -1,8 +1,10 @@
 use std::collections::{BinaryHeap, HashSet};
-use mona::artifacts::{Artifact, ArtifactSetName};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use mona::artifacts::{Artifact, ArtifactGenerator, ArtifactSetName, ArtifactSlotName, ArtifactSource};
 use mona::artifacts::artifact_set_type::ArtifactSetType;
 use mona::artifacts::effect_config::ArtifactEffectConfig;
-use mona::attribute::SimpleAttributeGraph2;
+use mona::attribute::{AttributeGraphError, SimpleAttributeGraph2};
 use mona::buffs::Buff;
 use mona::character::Character;
 use mona::common::StatName;
@@ -10,19 +12,57 @@ use mona::enemies::Enemy;
 use mona::target_functions::TargetFunction;
 use mona::upgrade_predicate::artifact_upgrader::ArtifactUpgrader;
 use mona::weapon::Weapon;
+use rand::prelude::*;
+use rand::rngs::StdRng;
 use smallvec::SmallVec;
 use crate::applications::artifact_best_set::update_item::UpdateItem;
 use crate::applications::artifact_best_set::value_function::ValueFunction;
 
-pub fn generate_artifacts(set_type: ArtifactSetType, main_stats: &[StatName], upgrader: &ArtifactUpgrader) -> (usize, SmallVec<[Artifact; 5]>) {
+// pieces generated for one set at most, predicates accepting less often are treated as never accepting
+const MAX_TRIES: usize = 100000;
+
+#[derive(Debug)]
+pub enum ArtifactBestSetError {
+    // the upgrade predicate rejected `MAX_TRIES` pieces before a set was complete
+    PredicateNeverAccepts,
+    // the main stat is not a main stat of the slot
+    InvalidMainStat { slot: ArtifactSlotName, main_stat: StatName },
+    AttributeGraph(AttributeGraphError),
+}
+
+impl Display for ArtifactBestSetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArtifactBestSetError::PredicateNeverAccepts => write!(f, "Artifact Best Set Error: the upgrade predicate accepts no artifact within {} tries", MAX_TRIES),
+            ArtifactBestSetError::InvalidMainStat { slot, main_stat } => write!(f, "Artifact Best Set Error: {:?} is not a main stat of {:?}", main_stat, slot),
+            ArtifactBestSetError::AttributeGraph(e) => write!(f, "Artifact Best Set Error: {}", e),
+        }
+    }
+}
+
+impl Error for ArtifactBestSetError {}
+
+impl From<AttributeGraphError> for ArtifactBestSetError {
+    fn from(e: AttributeGraphError) -> Self {
+        ArtifactBestSetError::AttributeGraph(e)
+    }
+}
+
+/// pieces rejected by the upgrader count as tries, fails if the set is not complete after `MAX_TRIES` tries
+pub fn generate_artifacts<R: Rng>(set_type: ArtifactSetType, main_stats: &[StatName], upgrader: &ArtifactUpgrader, generator: &mut ArtifactGenerator<R>) -> Result<(usize, SmallVec<[Artifact; 5]>), ArtifactBestSetError> {
     let mut artifacts: SmallVec<[Artifact; 5]> = SmallVec::new();
     let mut tries = 0;
 
     let mut i = 0;
     while i < 5 {
+        if tries >= MAX_TRIES {
+            return Err(ArtifactBestSetError::PredicateNeverAccepts);
+        }
         tries += 1;
-        let mut a = Artifact::random0(ArtifactSetName::BlizzardStrayer, num::FromPrimitive::from_usize(i).unwrap(), main_stats[i]);
-        if upgrader.upgrade(&mut a) {
+        let slot: ArtifactSlotName = num::FromPrimitive::from_usize(i).unwrap();
+        let mut a = generator.generate_with_main_stat(ArtifactSetName::BlizzardStrayer, slot, main_stats[i], 5, ArtifactSource::Domain)
+            .ok_or(ArtifactBestSetError::InvalidMainStat { slot, main_stat: main_stats[i] })?;
+        if upgrader.upgrade_with_rng(&mut a, &mut generator.rng) {
             artifacts.push(a);
             i += 1;
         }
@@ -53,7 +93,7 @@ pub fn generate_artifacts(set_type: ArtifactSetType, main_stats: &[StatName], up
         ArtifactSetType::Misc => ()
     }
 
-    (tries, artifacts)
+    Ok((tries, artifacts))
 }
 
 pub fn calc_artifact_best_set(
@@ -63,7 +103,9 @@ pub fn calc_artifact_best_set(
     artifact_config: Option<&ArtifactEffectConfig>,
     buffs: &[Box<dyn Buff<SimpleAttributeGraph2>>],
     enemy: &Enemy,
-) -> BinaryHeap<UpdateItem> {
+    seed: Option<u64>,
+    upgrader: &ArtifactUpgrader,
+) -> Result<BinaryHeap<UpdateItem>, ArtifactBestSetError> {
     let mut default_effect_config: ArtifactEffectConfig;
     let effect_config_ref = if let Some(x) = artifact_config {
         x
@@ -82,7 +124,11 @@ pub fn calc_artifact_best_set(
     };
 
     use StatName::*;
-    let upgrader = ArtifactUpgrader::default();
+    // same seed, same result
+    let mut generator = ArtifactGenerator::new(match seed {
+        Some(x) => StdRng::seed_from_u64(x),
+        None => StdRng::from_entropy()
+    });
 
     let sim_count = 5000;
     let heap_size = 100;
@@ -91,9 +137,9 @@ pub fn calc_artifact_best_set(
     let mut heap: BinaryHeap<UpdateItem> = BinaryHeap::new();
     // let mut max_heap: BinaryHeap<UpdateItem> = BinaryHeap::new();
 
-    let effective_stat_name = vf.get_effective_stat_name();
+    let effective_stat_name = vf.get_effective_stat_name()?;
     // println!("effective stat name: {:?}", effective_stat_name);
-    let effective_set_type = vf.get_effective_artifact_set_type();
+    let effective_set_type = vf.get_effective_artifact_set_type()?;
     // println!("effective set type: {:?}", effective_set_type);
 
     use ArtifactSetName::*;
@@ -127,10 +173,10 @@ pub fn calc_artifact_best_set(
                     // let mut max_value = 0.0;
                     let mut sim_iter = 0;
                     while sim_iter < sim_count {
-                        let (tries, a) = generate_artifacts(set_type, &[HPFixed, ATKFixed, s1, s2, s3], &upgrader);
+                        let (tries, a) = generate_artifacts(set_type, &[HPFixed, ATKFixed, s1, s2, s3], upgrader, &mut generator)?;
                         sim_iter += tries;
                         let a2: SmallVec<[&Artifact; 5]> = a.iter().map(|x| x).collect();
-                        let value = vf.call(&a2);
+                        let value = vf.call(&a2)?;
 
                         total_value += value * (tries as f64);
                         // max_value = value.max(max_value);
@@ -192,10 +238,10 @@ pub fn calc_artifact_best_set(
                         // let mut max_value = 0.0;
                         let mut sim_iter = 0;
                         while sim_iter < sim_count {
-                            let (tries, a) = generate_artifacts(set_type, &[HPFixed, ATKFixed, s1, s2, s3], &upgrader);
+                            let (tries, a) = generate_artifacts(set_type, &[HPFixed, ATKFixed, s1, s2, s3], upgrader, &mut generator)?;
                             sim_iter += tries;
                             let a2: SmallVec<[&Artifact; 5]> = a.iter().map(|x| x).collect();
-                            let value = vf.call(&a2);
+                            let value = vf.call(&a2)?;
 
                             total_value += value * (tries as f64);
                             // max_value = value.max(max_value);
@@ -231,5 +277,5 @@ pub fn calc_artifact_best_set(
     //     println!("{:?}", heap.pop().unwrap());
     // }
 
-    heap
+    Ok(heap)
 }
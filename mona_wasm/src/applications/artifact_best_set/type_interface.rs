@@ -1,5 +1,6 @@
 use mona::artifacts::effect_config::ArtifactConfigInterface;
 use crate::applications::common::{BuffInterface, CharacterInterface, EnemyInterface, TargetFunctionInterface, WeaponInterface};
+use mona::upgrade_predicate::upgrade_predicate_config::UpgradePredicateConfig;
 use serde::{Serialize, Deserialize};
 
 #[derive(Serialize, Deserialize)]
@@ -10,4 +11,9 @@ pub struct CalcArtifactBestSetInterface {
     pub target_function: TargetFunctionInterface,
     pub buffs: Option<Vec<BuffInterface>>,
     pub enemy: Option<EnemyInterface>,
+    // fixed seed for reproducible simulation
+    pub seed: Option<u64>,
+    // leveling policy of the simulated player, levels everything to max by default
+    #[serde(default)]
+    pub upgrade_predicate: Option<UpgradePredicateConfig>,
 }
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 use mona::artifacts::{Artifact, ArtifactList, ArtifactSetName, ArtifactSlotName};
 use mona::artifacts::artifact_set_type::ArtifactSetType;
 use mona::artifacts::effect_config::ArtifactEffectConfig;
-use mona::attribute::{AttributeUtils, SimpleAttributeGraph2};
+use mona::attribute::{AttributeGraphError, AttributeUtils, SimpleAttributeGraph2};
 use mona::buffs::Buff;
 use mona::character::Character;
 use mona::common::StatName;
@@ -21,7 +21,7 @@ pub struct ValueFunction<'a> {
 }
 
 impl<'a> ValueFunction<'a> {
-    pub fn call(&self, artifacts: &[&Artifact]) -> f64 {
+    pub fn call(&self, artifacts: &[&Artifact]) -> Result<f64, AttributeGraphError> {
         let artifact_list = ArtifactList {
             artifacts,
         };
@@ -31,18 +31,19 @@ impl<'a> ValueFunction<'a> {
             &self.character,
             &self.weapon,
             &self.buffs
-        );
+        )?;
 
-        self.tf.target(
+        Ok(self.tf.target(
             &attribute,
             &self.character,
             &self.weapon,
             artifacts,
             &self.enemy,
-        )
+            &[],
+        ))
     }
 
-    pub fn call_with_stat(&self, stat_name: StatName, value: f64) -> f64 {
+    pub fn call_with_stat(&self, stat_name: StatName, value: f64) -> Result<f64, AttributeGraphError> {
         let artifact = Artifact::new(
             ArtifactSetName::Empty,
             ArtifactSlotName::Flower,
@@ -55,7 +56,7 @@ impl<'a> ValueFunction<'a> {
         self.call(&[&artifact])
     }
 
-    pub fn call_with_set_type(&self, set_type: ArtifactSetType) -> f64 {
+    pub fn call_with_set_type(&self, set_type: ArtifactSetType) -> Result<f64, AttributeGraphError> {
         let create_empty_artifact = |set_name: ArtifactSetName| {
             Artifact::new(
                 set_name,
@@ -87,42 +88,43 @@ impl<'a> ValueFunction<'a> {
         self.call(&a2)
     }
 
-    pub fn get_effective_stat_name(&self) -> HashSet<StatName> {
+    pub fn get_effective_stat_name(&self) -> Result<HashSet<StatName>, AttributeGraphError> {
         let mut result = HashSet::new();
-        let base_value = self.call(&[]);
+        let base_value = self.call(&[])?;
 
         for i in 0..StatName::COUNT {
             let stat_name: StatName = num::FromPrimitive::from_usize(i).unwrap();
-            let value = self.call_with_stat(stat_name, 10000.0);
+            let value = self.call_with_stat(stat_name, 10000.0)?;
             if value > base_value {
                 result.insert(stat_name);
             }
         }
 
-        result
+        Ok(result)
     }
 
-    pub fn get_effective_artifact_set_type(&self) -> HashSet<ArtifactSetType> {
+    /// sets whose effects give invalid edges are not effective
+    pub fn get_effective_artifact_set_type(&self) -> Result<HashSet<ArtifactSetType>, AttributeGraphError> {
         let mut result = HashSet::new();
-        let base_value = self.call(&[]);
+        let base_value = self.call(&[])?;
 
         let len: usize = ArtifactSetName::LEN;
 
         for i in 0..len {
             let set_name: ArtifactSetName = num::FromPrimitive::from_usize(i).unwrap();
             let t = ArtifactSetType::Set4(set_name);
-            let value = self.call_with_set_type(t);
+            let value = self.call_with_set_type(t).unwrap_or(f64::NEG_INFINITY);
             if value > base_value {
                 result.insert(t);
             }
 
             let t2 = ArtifactSetType::Set2(set_name);
-            let value = self.call_with_set_type(t2);
+            let value = self.call_with_set_type(t2).unwrap_or(f64::NEG_INFINITY);
             if value > base_value {
                 result.insert(t2);
             }
         }
 
-        result
+        Ok(result)
     }
 }
\ No newline at end of file
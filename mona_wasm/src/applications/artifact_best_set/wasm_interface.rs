@@ -3,6 +3,7 @@ use wasm_bindgen::JsValue;
 use crate::applications::artifact_best_set::artifact_best_set::calc_artifact_best_set;
 use crate::applications::artifact_best_set::type_interface::CalcArtifactBestSetInterface;
 use crate::utils::set_panic_hook;
+use mona::upgrade_predicate::artifact_upgrader::ArtifactUpgrader;
 use wasm_bindgen::prelude::*;
 use crate::utils;
 
@@ -10,7 +11,7 @@ pub struct CalcArtifactBestSet;
 
 #[wasm_bindgen]
 impl CalcArtifactBestSet {
-    pub fn calc_artifact_best_set(args: JsValue) -> JsValue {
+    pub fn calc_artifact_best_set(args: JsValue) -> Result<JsValue, JsValue> {
         set_panic_hook();
 
         let calc_best_set_interface: CalcArtifactBestSetInterface = serde_wasm_bindgen::from_value(args).unwrap();
@@ -25,12 +26,19 @@ impl CalcArtifactBestSet {
         let buffs = calc_best_set_interface.buffs.unwrap_or(vec![]).iter().map(|b| b.to_buff()).collect::<Vec<_>>();
         let artifact_config = calc_best_set_interface.artifact_config.clone().map(|x| x.to_config());
 
+        let upgrader = match calc_best_set_interface.upgrade_predicate {
+            Some(ref x) => ArtifactUpgrader::new(x.to_predicate()),
+            None => ArtifactUpgrader::default()
+        };
+
         let mut result = calc_artifact_best_set(
             &character, &weapon, &target_function,
             artifact_config.as_ref(),
             &buffs,
-            &enemy
-        );
+            &enemy,
+            calc_best_set_interface.seed,
+            &upgrader
+        ).map_err(|e| JsValue::from_str(&e.to_string()))?;
         // utils::log!("{:?}", result);
 
         let mut arr = Vec::new();
@@ -41,6 +49,6 @@ impl CalcArtifactBestSet {
         // utils::log!("{:?}", arr);
 
         let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
-        arr.serialize(&s).unwrap()
+        Ok(arr.serialize(&s).unwrap())
     }
 }
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use mona::artifacts::importer::{import_artifacts, ArtifactImportReport, ImportedArtifact};
+use mona::utils;
+
+#[derive(Serialize, Deserialize)]
+pub struct ArtifactImportInterface {
+    // name of the detected format
+    pub format: String,
+    pub artifacts: Vec<ImportedArtifact>,
+    pub report: ArtifactImportReport,
+}
+
+pub struct ArtifactImportWasm;
+
+#[wasm_bindgen]
+impl ArtifactImportWasm {
+    pub fn import(json: &str) -> JsValue {
+        utils::set_panic_hook();
+
+        let result = match import_artifacts(json) {
+            Ok((format, x)) => ArtifactImportInterface {
+                format: format.to_string(),
+                artifacts: x.artifacts,
+                report: x.report,
+            },
+            Err(e) => panic!("{}", e)
+        };
+
+        let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+        result.serialize(&s).unwrap()
+    }
+}
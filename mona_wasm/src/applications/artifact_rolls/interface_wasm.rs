@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use mona::artifacts::{Artifact, ArtifactRollAnalysis};
+use mona::utils;
+
+#[derive(Serialize, Deserialize)]
+pub struct SanitizeArtifactsResult {
+    pub artifacts: Vec<Artifact>,
+    // ids of artifacts whose sub stat values were replaced
+    pub fixed: Vec<u64>,
+    // ids of artifacts which can not be fixed, they are not in `artifacts`
+    pub rejected: Vec<u64>,
+}
+
+pub fn sanitize_artifacts(artifacts: &[Artifact]) -> SanitizeArtifactsResult {
+    let mut result = SanitizeArtifactsResult {
+        artifacts: Vec::new(),
+        fixed: Vec::new(),
+        rejected: Vec::new(),
+    };
+
+    for artifact in artifacts.iter() {
+        if artifact.analyze_rolls().is_valid() {
+            result.artifacts.push(artifact.clone());
+        } else if let Some(fixed) = artifact.fix_rolls() {
+            result.fixed.push(artifact.id);
+            result.artifacts.push(fixed);
+        } else {
+            result.rejected.push(artifact.id);
+        }
+    }
+
+    result
+}
+
+pub struct ArtifactRollWasm;
+
+#[wasm_bindgen]
+impl ArtifactRollWasm {
+    pub fn analyze(artifacts: JsValue) -> JsValue {
+        utils::set_panic_hook();
+
+        let artifacts: Vec<Artifact> = serde_wasm_bindgen::from_value(artifacts).unwrap();
+        let result: Vec<ArtifactRollAnalysis> = artifacts.iter().map(|x| x.analyze_rolls()).collect();
+
+        let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+        result.serialize(&s).unwrap()
+    }
+
+    pub fn sanitize(artifacts: JsValue) -> JsValue {
+        utils::set_panic_hook();
+
+        let artifacts: Vec<Artifact> = serde_wasm_bindgen::from_value(artifacts).unwrap();
+        let result = sanitize_artifacts(&artifacts);
+
+        let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+        result.serialize(&s).unwrap()
+    }
+}
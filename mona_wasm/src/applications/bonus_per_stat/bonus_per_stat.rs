@@ -4,7 +4,9 @@ use crate::applications::common::{CharacterInterface, WeaponInterface};
 use mona::artifacts::{Artifact, ArtifactList};
 use mona::artifacts::eff::ARTIFACT_EFF5;
 use mona::artifacts::effect_config::ArtifactEffectConfig;
-use mona::attribute::{Attribute, AttributeName, AttributeUtils, SimpleAttributeGraph2};
+use std::collections::HashMap;
+use mona::attribute::{Attribute, AttributeGraphError, AttributeName, AttributeUtils, SimpleAttributeGraph2, stat_gradient};
+use mona::common::StatName;
 use mona::buffs::Buff;
 use mona::character::Character;
 use mona::enemies::Enemy;
@@ -37,12 +39,14 @@ pub struct BonusPerStatOutput {
     pub critical_rate: Vec<f64>,
     pub critical_damage: Vec<f64>,
     pub recharge: Vec<f64>,
-    pub elemental_mastery: Vec<f64>
+    pub elemental_mastery: Vec<f64>,
+    // gain of one max roll of each sub stat from the gradient at the current stats, relative to the target value
+    pub roll_gains: HashMap<StatName, f64>,
 }
 
 fn get_atk(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec<&Artifact>, input: &BonusPerStatInput) -> Vec<f64> {
     attribute.set_value_by(AttributeName::ATKFixed, "temp", 10000.0);
-    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
     attribute.set_value_by(AttributeName::ATKFixed, "temp", -10000.0);
     if (test_value - baseline).abs() < 1e-6 {
         return Vec::new();
@@ -54,7 +58,7 @@ fn get_atk(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec
         let x = i as f64 / INTERVAL;
         let add_value = ARTIFACT_EFF5.atk[3] * x;
         attribute.set_value_by(AttributeName::ATKFixed, "temp", add_value);
-        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
         attribute.set_value_by(AttributeName::ATKFixed, "temp", -add_value);
 
         data.push((value - baseline) / baseline)
@@ -65,7 +69,7 @@ fn get_atk(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec
 
 fn get_atk_p(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec<&Artifact>, input: &BonusPerStatInput) -> Vec<f64> {
     attribute.set_value_by(AttributeName::ATKFixed, "temp", 10000.0);
-    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
     attribute.set_value_by(AttributeName::ATKFixed, "temp", -10000.0);
     if (test_value - baseline).abs() < 1e-6 {
         return Vec::new();
@@ -80,7 +84,7 @@ fn get_atk_p(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &V
         let add_value = p * base_atk;
 
         attribute.set_value_by(AttributeName::ATKFixed, "temp", add_value);
-        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
         attribute.set_value_by(AttributeName::ATKFixed, "temp", -add_value);
 
         data.push((value - baseline) / baseline)
@@ -91,7 +95,7 @@ fn get_atk_p(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &V
 
 fn get_def(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec<&Artifact>, input: &BonusPerStatInput) -> Vec<f64> {
     attribute.set_value_by(AttributeName::DEFFixed, "temp", 10000.0);
-    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
     attribute.set_value_by(AttributeName::DEFFixed, "temp", -10000.0);
     if (test_value - baseline).abs() < 1e-6 {
         return Vec::new();
@@ -103,7 +107,7 @@ fn get_def(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec
         let x = i as f64 / INTERVAL;
         let add_value = ARTIFACT_EFF5.def[3] * x;
         attribute.set_value_by(AttributeName::DEFFixed, "temp", add_value);
-        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
         attribute.set_value_by(AttributeName::DEFFixed, "temp", -add_value);
 
         data.push((value - baseline) / baseline)
@@ -114,7 +118,7 @@ fn get_def(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec
 
 fn get_def_p(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec<&Artifact>, input: &BonusPerStatInput) -> Vec<f64> {
     attribute.set_value_by(AttributeName::DEFFixed, "temp", 10000.0);
-    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
     attribute.set_value_by(AttributeName::DEFFixed, "temp", -10000.0);
     if (test_value - baseline).abs() < 1e-6 {
         return Vec::new();
@@ -129,7 +133,7 @@ fn get_def_p(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &V
         let add_value = p * base_def;
 
         attribute.set_value_by(AttributeName::DEFFixed, "temp", add_value);
-        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
         attribute.set_value_by(AttributeName::DEFFixed, "temp", -add_value);
 
         data.push((value - baseline) / baseline)
@@ -140,7 +144,7 @@ fn get_def_p(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &V
 
 fn get_hp(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec<&Artifact>, input: &BonusPerStatInput) -> Vec<f64> {
     attribute.set_value_by(AttributeName::HPFixed, "temp", 10000.0);
-    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
     attribute.set_value_by(AttributeName::HPFixed, "temp", -10000.0);
     if (test_value - baseline).abs() < 1e-6 {
         return Vec::new();
@@ -152,7 +156,7 @@ fn get_hp(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec<
         let x = i as f64 / INTERVAL;
         let add_value = ARTIFACT_EFF5.hp[3] * x;
         attribute.set_value_by(AttributeName::HPFixed, "temp", add_value);
-        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
         attribute.set_value_by(AttributeName::HPFixed, "temp", -add_value);
 
         data.push((value - baseline) / baseline)
@@ -163,7 +167,7 @@ fn get_hp(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec<
 
 fn get_hp_p(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec<&Artifact>, input: &BonusPerStatInput) -> Vec<f64> {
     attribute.set_value_by(AttributeName::HPFixed, "temp", 10000.0);
-    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
     attribute.set_value_by(AttributeName::HPFixed, "temp", -10000.0);
     if (test_value - baseline).abs() < 1e-6 {
         return Vec::new();
@@ -178,7 +182,7 @@ fn get_hp_p(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Ve
         let add_value = p * base_hp;
 
         attribute.set_value_by(AttributeName::HPFixed, "temp", add_value);
-        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
         attribute.set_value_by(AttributeName::HPFixed, "temp", -add_value);
 
         data.push((value - baseline) / baseline)
@@ -189,7 +193,7 @@ fn get_hp_p(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Ve
 
 fn get_critical_rate(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec<&Artifact>, input: &BonusPerStatInput) -> Vec<f64> {
     attribute.set_value_by(AttributeName::CriticalBase, "temp", 1.0);
-    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
     attribute.set_value_by(AttributeName::CriticalBase, "temp", -1.0);
     if (test_value - baseline).abs() < 1e-6 {
         return Vec::new();
@@ -200,7 +204,7 @@ fn get_critical_rate(attribute: &mut SimpleAttributeGraph2, baseline: f64, artif
         let x = i as f64 / INTERVAL;
         let p = ARTIFACT_EFF5.critical_rate[3] * x;
         attribute.set_value_by(AttributeName::CriticalBase, "temp", p);
-        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
         attribute.set_value_by(AttributeName::CriticalBase, "temp", -p);
         data.push((value - baseline) / baseline)
     }
@@ -210,7 +214,7 @@ fn get_critical_rate(attribute: &mut SimpleAttributeGraph2, baseline: f64, artif
 
 fn get_critical_damage(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec<&Artifact>, input: &BonusPerStatInput) -> Vec<f64> {
     attribute.set_value_by(AttributeName::CriticalDamageBase, "temp", 1.0);
-    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
     attribute.set_value_by(AttributeName::CriticalDamageBase, "temp", -1.0);
     if (test_value - baseline).abs() < 1e-6 {
         return Vec::new();
@@ -221,7 +225,7 @@ fn get_critical_damage(attribute: &mut SimpleAttributeGraph2, baseline: f64, art
         let x = i as f64 / INTERVAL;
         let p = ARTIFACT_EFF5.critical_damage[3] * x;
         attribute.set_value_by(AttributeName::CriticalDamageBase, "temp", p);
-        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
         attribute.set_value_by(AttributeName::CriticalDamageBase, "temp", -p);
         data.push((value - baseline) / baseline)
     }
@@ -231,7 +235,7 @@ fn get_critical_damage(attribute: &mut SimpleAttributeGraph2, baseline: f64, art
 
 fn get_recharge(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec<&Artifact>, input: &BonusPerStatInput) -> Vec<f64> {
     attribute.set_value_by(AttributeName::Recharge, "temp", 1.0);
-    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
     attribute.set_value_by(AttributeName::Recharge, "temp", -1.0);
     if (test_value - baseline).abs() < 1e-6 {
         return Vec::new();
@@ -242,7 +246,7 @@ fn get_recharge(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts:
         let x = i as f64 / INTERVAL;
         let p = ARTIFACT_EFF5.recharge[3] * x;
         attribute.set_value_by(AttributeName::Recharge, "temp", p);
-        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
         attribute.set_value_by(AttributeName::Recharge, "temp", -p);
         data.push((value - baseline) / baseline)
     }
@@ -252,7 +256,7 @@ fn get_recharge(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts:
 
 fn get_em(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec<&Artifact>, input: &BonusPerStatInput) -> Vec<f64> {
     attribute.set_value_by(AttributeName::ElementalMastery, "temp", 1000.0);
-    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+    let test_value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
     attribute.set_value_by(AttributeName::ElementalMastery, "temp", -1000.0);
     if (test_value - baseline).abs() < 1e-6 {
         return Vec::new();
@@ -263,7 +267,7 @@ fn get_em(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec<
         let x = i as f64 / INTERVAL;
         let p = ARTIFACT_EFF5.elemental_mastery[3] * x;
         attribute.set_value_by(AttributeName::ElementalMastery, "temp", p);
-        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy);
+        let value = input.tf.target(&attribute, &input.character, &input.weapon, artifacts, &input.enemy, &[]);
         attribute.set_value_by(AttributeName::ElementalMastery, "temp", -p);
         data.push((value - baseline) / baseline)
     }
@@ -271,10 +275,9 @@ fn get_em(attribute: &mut SimpleAttributeGraph2, baseline: f64, artifacts: &Vec<
     data
 }
 
-pub fn bonus_per_stat(input: BonusPerStatInput) -> BonusPerStatOutput {
-    let artifact_vec: Vec<&Artifact> = input.artifacts.iter().cloned().collect();
+fn create_attribute(input: &BonusPerStatInput, artifact_vec: &Vec<&Artifact>) -> Result<SimpleAttributeGraph2, AttributeGraphError> {
     let artifact_list = ArtifactList {
-        artifacts: &artifact_vec
+        artifacts: artifact_vec
     };
 
     let default_artifact_config = if input.artifacts_config.is_none() {
@@ -288,18 +291,52 @@ pub fn bonus_per_stat(input: BonusPerStatInput) -> BonusPerStatOutput {
         default_artifact_config.as_ref().unwrap()
     };
 
-    let mut attribute = AttributeUtils::create_attribute_from_big_config(
+    AttributeUtils::create_attribute_from_big_config(
         &artifact_list,
         &artifact_config,
         &input.character,
         &input.weapon,
         &input.buffs
-    );
+    )
+}
+
+/// d(target)/d(stat) relative to the target value, see `stat_gradient` for how it is computed
+pub fn stat_gradient_per_stat(input: BonusPerStatInput) -> Result<HashMap<StatName, f64>, AttributeGraphError> {
+    let artifact_vec: Vec<&Artifact> = input.artifacts.iter().cloned().collect();
+    let attribute = create_attribute(&input, &artifact_vec)?;
+    relative_gradient(&attribute, &artifact_vec, &input)
+}
+
+fn relative_gradient(attribute: &SimpleAttributeGraph2, artifact_vec: &Vec<&Artifact>, input: &BonusPerStatInput) -> Result<HashMap<StatName, f64>, AttributeGraphError> {
+    let target = |attribute: &SimpleAttributeGraph2| input.tf.target(attribute, &input.character, &input.weapon, artifact_vec, &input.enemy, &[]);
+    let baseline = target(attribute);
+    let mut gradient = stat_gradient(attribute, target)?;
+    if baseline.abs() > 1e-6 {
+        for value in gradient.values_mut() {
+            *value /= baseline;
+        }
+    }
+
+    Ok(gradient)
+}
+
+/// target value after adding 1 to 10 max rolls of each stat. this is not replaced by `stat_gradient_per_stat`,
+/// the gradient is the slope at the current stats and cannot show caps or thresholds reached after a few rolls
+pub fn bonus_per_stat(input: BonusPerStatInput) -> Result<BonusPerStatOutput, AttributeGraphError> {
+    let artifact_vec: Vec<&Artifact> = input.artifacts.iter().cloned().collect();
+    let mut attribute = create_attribute(&input, &artifact_vec)?;
+
+    let gradient = relative_gradient(&attribute, &artifact_vec, &input)?;
+    let roll_gains = [
+        StatName::ATKFixed, StatName::ATKPercentage, StatName::DEFFixed, StatName::DEFPercentage, StatName::HPFixed,
+        StatName::HPPercentage, StatName::CriticalRate, StatName::CriticalDamage, StatName::Recharge, StatName::ElementalMastery
+    ].iter().map(|&stat| (stat, gradient.get(&stat).cloned().unwrap_or(0.0) * ARTIFACT_EFF5.get_value(stat, 3))).collect();
+
     attribute.set_dirty_on_set_value = true;
 
-    let value_baseline = input.tf.target(&attribute, &input.character, &input.weapon, &artifact_vec, &input.enemy);
+    let value_baseline = input.tf.target(&attribute, &input.character, &input.weapon, &artifact_vec, &input.enemy, &[]);
 
-    BonusPerStatOutput {
+    Ok(BonusPerStatOutput {
         atk: get_atk(&mut attribute, value_baseline, &artifact_vec, &input),
         atk_percentage: get_atk_p(&mut attribute, value_baseline, &artifact_vec, &input),
         def: get_def(&mut attribute, value_baseline, &artifact_vec, &input),
@@ -309,6 +346,7 @@ pub fn bonus_per_stat(input: BonusPerStatInput) -> BonusPerStatOutput {
         critical_rate: get_critical_rate(&mut attribute, value_baseline, &artifact_vec, &input),
         critical_damage: get_critical_damage(&mut attribute, value_baseline, &artifact_vec, &input),
         recharge: get_recharge(&mut attribute, value_baseline, &artifact_vec, &input),
-        elemental_mastery: get_em(&mut attribute, value_baseline, &artifact_vec, &input)
-    }
+        elemental_mastery: get_em(&mut attribute, value_baseline, &artifact_vec, &input),
+        roll_gains,
+    })
 }
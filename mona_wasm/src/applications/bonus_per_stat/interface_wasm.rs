@@ -2,13 +2,13 @@ use serde::{Serialize, Deserialize};
 use wasm_bindgen::JsValue;
 use wasm_bindgen::prelude::*;
 use crate::applications::bonus_per_stat::bonus_per_stat::{BonusPerStatInput, BonusPerStatOutput};
-use crate::applications::common::{BuffInterface, CharacterInterface, TargetFunctionInterface, WeaponInterface};
+use crate::applications::common::{BuffInterface, CharacterInterface, EnemyInterface, TargetFunctionInterface, WeaponInterface};
 use mona::artifacts::Artifact;
 use mona::artifacts::effect_config::ArtifactEffectConfig;
 use mona::target_functions::TargetFunction;
 use mona::utils;
 use crate::target_function::dsl_tf::TargetFunctionDSL;
-use super::bonus_per_stat::bonus_per_stat;
+use super::bonus_per_stat::{bonus_per_stat, stat_gradient_per_stat};
 
 #[derive(Serialize, Deserialize)]
 pub struct WasmInput {
@@ -17,7 +17,9 @@ pub struct WasmInput {
     pub artifacts: Vec<Artifact>,
     pub tf: TargetFunctionInterface,
     pub buffs: Vec<BuffInterface>,
-    pub artifacts_config: Option<ArtifactEffectConfig>
+    pub artifacts_config: Option<ArtifactEffectConfig>,
+    #[serde(default)]
+    pub enemy: Option<EnemyInterface>,
 }
 
 // #[wasm_bindgen]
@@ -48,7 +50,7 @@ pub struct BonusPerStat;
 
 #[wasm_bindgen]
 impl BonusPerStat {
-    pub fn bonus_per_stat(val: JsValue) -> JsValue {
+    pub fn bonus_per_stat(val: JsValue) -> Result<JsValue, JsValue> {
         utils::set_panic_hook();
 
         let input: WasmInput = serde_wasm_bindgen::from_value(val).unwrap();
@@ -63,22 +65,26 @@ impl BonusPerStat {
         };
         let buffs: Vec<_> = input.buffs.iter().map(|b| b.to_buff()).collect();
         let config_ref = input.artifacts_config.as_ref();
+        let enemy = match input.enemy {
+            Some(ref x) => x.to_enemy(),
+            None => Default::default()
+        };
 
         let result = bonus_per_stat(BonusPerStatInput {
             character: &character,
             weapon: &weapon,
             artifacts: &artifacts_ref,
-            enemy: &Default::default(),
+            enemy: &enemy,
             tf: &tf,
             buffs: &buffs,
             artifacts_config: config_ref
-        });
+        }).map_err(|e| JsValue::from_str(&e.to_string()))?;
 
         // utils::log!("{:?}", result.atk);
         // utils::log!("{:?}", result.atk.as_ptr());
 
         let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
-        result.serialize(&s).unwrap()
+        Ok(result.serialize(&s).unwrap())
 
         // WasmOutput {
         //     atk_ptr: result.atk.as_ptr(),
@@ -103,4 +109,39 @@ impl BonusPerStat {
         //     elemental_mastery_len: result.elemental_mastery.len()
         // }
     }
+
+    /// d(target)/d(stat) / target for every stat at the current stats, see `stat_gradient`
+    pub fn gradient(val: JsValue) -> Result<JsValue, JsValue> {
+        utils::set_panic_hook();
+
+        let input: WasmInput = serde_wasm_bindgen::from_value(val).unwrap();
+
+        let character = input.character.to_character();
+        let weapon = input.weapon.to_weapon(&character);
+        let artifacts_ref: Vec<&Artifact> = input.artifacts.iter().collect();
+        let tf: Box<dyn TargetFunction> = if input.tf.use_dsl {
+            Box::new(TargetFunctionDSL::new(&input.tf.dsl_source.unwrap()))
+        } else {
+            input.tf.to_target_function(&character, &weapon)
+        };
+        let buffs: Vec<_> = input.buffs.iter().map(|b| b.to_buff()).collect();
+        let config_ref = input.artifacts_config.as_ref();
+        let enemy = match input.enemy {
+            Some(ref x) => x.to_enemy(),
+            None => Default::default()
+        };
+
+        let result = stat_gradient_per_stat(BonusPerStatInput {
+            character: &character,
+            weapon: &weapon,
+            artifacts: &artifacts_ref,
+            enemy: &enemy,
+            tf: &tf,
+            buffs: &buffs,
+            artifacts_config: config_ref
+        }).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+        Ok(result.serialize(&s).unwrap())
+    }
 }
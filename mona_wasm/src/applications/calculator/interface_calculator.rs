@@ -2,13 +2,14 @@ use std::collections::HashMap;
 
 use mona::artifacts::{Artifact, ArtifactList};
 use mona::artifacts::effect_config::ArtifactEffectConfig;
-use mona::attribute::{AttributeUtils, ComplicatedAttributeGraph, SimpleAttributeGraph2};
+use mona::attribute::{AttributeGraphError, AttributeUtils, ComplicatedAttributeGraph, SimpleAttributeGraph2};
 use mona::buffs::{Buff, BuffConfig};
 use mona::character::{Character, CharacterName};
 use mona::character::characters::damage;
 use mona::character::skill_config::CharacterSkillConfig;
 use mona::character::traits::CharacterTrait;
 use mona::common::Element;
+use mona::common::reaction_type::TransformativeType;
 use mona::damage::{ComplicatedDamageBuilder, DamageAnalysis, DamageContext, SimpleDamageBuilder};
 use mona::damage::damage_builder::DamageBuilder;
 use mona::damage::damage_result::SimpleDamageResult;
@@ -69,7 +70,7 @@ pub struct CalculatorConfigInterface {
 
 #[wasm_bindgen]
 impl CalculatorInterface {
-    pub fn get_damage_analysis(value: JsValue, fumo: JsValue) -> JsValue {
+    pub fn get_damage_analysis(value: JsValue, fumo: JsValue) -> Result<JsValue, JsValue> {
         utils::set_panic_hook();
         // utils::log!("start");
 
@@ -104,13 +105,13 @@ impl CalculatorInterface {
             &input.skill.config,
             &enemy,
             fumo,
-        );
+        ).map_err(|e| JsValue::from_str(&e.to_string()))?;
 
         let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
-        result.serialize(&s).unwrap()
+        Ok(result.serialize(&s).unwrap())
     }
 
-    pub fn get_transformative_damage(value: JsValue) -> TransformativeDamage {
+    pub fn get_transformative_damage(value: JsValue) -> Result<TransformativeDamage, JsValue> {
         utils::set_panic_hook();
 
         let input: CalculatorConfigInterface = serde_wasm_bindgen::from_value(value).unwrap();
@@ -140,17 +141,66 @@ impl CalculatorInterface {
             &character,
             &weapon,
             &buffs
-        );
+        ).map_err(|e| JsValue::from_str(&e.to_string()))?;
 
         let context: DamageContext<'_, SimpleAttributeGraph2> = DamageContext {
             character_common_data: &character.common_data,
             enemy: &enemy,
-            attribute: &attribute
+            attribute: &attribute,
+            other_enemies: &[]
         };
 
         let result = context.transformative();
 
-        result
+        Ok(result)
+    }
+
+    /// breakdown of every transformative and lunar reaction, keyed by reaction type
+    pub fn get_transformative_damage_analysis(value: JsValue) -> Result<JsValue, JsValue> {
+        utils::set_panic_hook();
+
+        let input: CalculatorConfigInterface = serde_wasm_bindgen::from_value(value).unwrap();
+
+        let character: Character<ComplicatedAttributeGraph> = input.character.to_character();
+        let weapon = input.weapon.to_weapon(&character);
+
+        let buffs: Vec<Box<dyn Buff<ComplicatedAttributeGraph>>> = input.buffs.iter().map(|x| x.to_buff()).collect();
+        let artifacts: Vec<&Artifact> = input.artifacts.iter().collect();
+
+        let artifact_config = match input.artifact_config {
+            Some(x) => x,
+            None => Default::default()
+        };
+
+        let enemy = if let Some(x) = input.enemy {
+            x.to_enemy()
+        } else {
+            Default::default()
+        };
+
+        let attribute = AttributeUtils::create_attribute_from_big_config(
+            &ArtifactList {
+                artifacts: &artifacts
+            },
+            &artifact_config,
+            &character,
+            &weapon,
+            &buffs
+        ).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        let context = DamageContext {
+            character_common_data: &character.common_data,
+            enemy: &enemy,
+            attribute: &attribute,
+            other_enemies: &[]
+        };
+
+        let result: HashMap<TransformativeType, DamageAnalysis> = TransformativeType::ALL.iter()
+            .map(|&t| (t, context.transformative_by::<ComplicatedDamageBuilder>(t)))
+            .collect();
+
+        let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+        Ok(result.serialize(&s).unwrap())
     }
 
     // pub fn damage_without_attribute(value: &JsValue) -> SimpleDamageResult {
@@ -185,7 +235,7 @@ impl CalculatorInterface {
         skill_config: &CharacterSkillConfig,
         enemy: &Enemy,
         fumo: Option<Element>,
-    ) -> DamageAnalysis {
+    ) -> Result<DamageAnalysis, AttributeGraphError> {
         // let mut ans: HashMap<String, DamageAnalysis> = HashMap::new();
 
         let artifact_list = ArtifactList {
@@ -198,15 +248,16 @@ impl CalculatorInterface {
             character,
             weapon,
             buffs
-        );
+        )?;
 
         let context = DamageContext {
             character_common_data: &character.common_data,
             attribute: &attribute,
-            enemy: &enemy
+            enemy: &enemy,
+            other_enemies: &[]
         };
 
         let damage: DamageAnalysis = damage::<ComplicatedDamageBuilder>(&context, skill_index, skill_config, fumo);
-        damage
+        Ok(damage)
     }
 }
\ No newline at end of file
@@ -6,13 +6,16 @@ use mona::buffs::{Buff, BuffConfig};
 use mona::character::{Character, CharacterConfig, CharacterName};
 use mona::character::skill_config::CharacterSkillConfig;
 use mona::common::StatName;
-use mona::enemies::Enemy;
+use mona::enemies::{Enemy, EnemyName, EnemyState};
+use mona::energy::EnergyDemandConfig;
 use mona::potential_function::potential_function::PotentialFunction;
 use mona::potential_function::potential_function_config::PotentialFunctionConfig;
 use mona::potential_function::potential_function_name::PotentialFunctionName;
 use mona::target_functions::{TargetFunction, TargetFunctionConfig, TargetFunctionName, TargetFunctionUtils};
 use mona::weapon::{Weapon, WeaponConfig, WeaponName};
 
+use crate::utils;
+
 #[derive(Serialize, Deserialize)]
 pub struct SkillInterface {
     pub index: usize,
@@ -25,10 +28,15 @@ pub struct CharacterInterface {
     pub level: usize,
     pub ascend: bool,
     pub constellation: i32,
+    // 0-based talent levels
     pub skill1: usize,
     pub skill2: usize,
     pub skill3: usize,
     pub params: CharacterConfig,
+    // true if the skill levels leave out the constellation bonus, which is then added by mona.
+    // configs saved before have no such field and hold levels including the bonus
+    #[serde(default = "default_false")]
+    pub base_skill_levels: bool,
 }
 
 fn default_false() -> bool {
@@ -36,15 +44,26 @@ fn default_false() -> bool {
 }
 
 impl CharacterInterface {
+    /// skill levels without the constellation bonus
+    pub fn base_skills(&self) -> [usize; 3] {
+        let skills = [self.skill1, self.skill2, self.skill3];
+        if self.base_skill_levels {
+            skills
+        } else {
+            self.name.get_static_data().base_talent_levels(self.constellation, skills)
+        }
+    }
+
     pub fn to_character<T: Attribute>(&self) -> Character<T> {
+        let skills = self.base_skills();
         Character::new(
             self.name,
             self.level,
             self.ascend,
             self.constellation,
-            self.skill1,
-            self.skill2,
-            self.skill3,
+            skills[0],
+            skills[1],
+            skills[2],
             &self.params
         )
     }
@@ -79,10 +98,26 @@ pub struct TargetFunctionInterface {
     #[serde(default = "default_false")]
     pub use_dsl: bool,
     pub dsl_source: Option<String>,
+    // derive recharge demand from team energy instead of the value in params
+    #[serde(default)]
+    pub energy: Option<EnergyDemandConfig>,
 }
 
 impl TargetFunctionInterface {
     pub fn to_target_function(&self, character: &Character<SimpleAttributeGraph2>, weapon: &Weapon<SimpleAttributeGraph2>) -> Box<dyn TargetFunction> {
+        if let Some(demand) = self.energy.as_ref().and_then(|x| x.recharge_demand()) {
+            let mut params = self.params.clone();
+            if params.set_recharge_demand(demand) {
+                return TargetFunctionUtils::new_target_function(
+                    self.name,
+                    character,
+                    weapon,
+                    &params
+                );
+            }
+            utils::log!("energy config ignored: target function {:?} has no recharge demand", self.name);
+        }
+
         TargetFunctionUtils::new_target_function(
             self.name,
             character,
@@ -107,18 +142,35 @@ impl BuffInterface {
 #[derive(Serialize, Deserialize)]
 pub struct EnemyInterface {
     pub level: usize,
+    // resistances are taken from the enemy database if name is given
+    #[serde(default)]
+    pub name: Option<EnemyName>,
+    #[serde(default)]
+    pub state: EnemyState,
+    #[serde(default)]
     pub electro_res: f64,
+    #[serde(default)]
     pub pyro_res: f64,
+    #[serde(default)]
     pub hydro_res: f64,
+    #[serde(default)]
     pub cryo_res: f64,
+    #[serde(default)]
     pub geo_res: f64,
+    #[serde(default)]
     pub anemo_res: f64,
+    #[serde(default)]
     pub dendro_res: f64,
-    pub physical_res: f64
+    #[serde(default)]
+    pub physical_res: f64,
 }
 
 impl EnemyInterface {
     pub fn to_enemy(&self) -> Enemy {
+        if let Some(name) = self.name {
+            return name.create(self.level as i32, self.state);
+        }
+
         Enemy {
             level: self.level as i32,
             electro_res: self.electro_res,
@@ -128,7 +180,7 @@ impl EnemyInterface {
             anemo_res: self.anemo_res,
             geo_res: self.geo_res,
             dendro_res: self.dendro_res,
-            physical_res: self.physical_res
+            physical_res: self.physical_res,
         }
     }
 }
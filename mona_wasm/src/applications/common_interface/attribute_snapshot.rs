@@ -0,0 +1,23 @@
+use wasm_bindgen::prelude::*;
+use serde::Serialize;
+use mona::attribute::AttributeSnapshot;
+use super::get_attribute::{create_attribute, GetAttributeInterface};
+
+pub fn get_attribute_snapshot(val: JsValue) -> Result<JsValue, JsValue> {
+    let input: GetAttributeInterface = serde_wasm_bindgen::from_value(val).unwrap();
+    let attribute = create_attribute(input).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let result = attribute.snapshot();
+    let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+    Ok(result.serialize(&s).unwrap())
+}
+
+/// snapshots with null values are rejected, non-finite values are the strings "inf", "-inf" and "NaN"
+pub fn diff_attribute_snapshot(before: JsValue, after: JsValue) -> Result<JsValue, JsValue> {
+    let before: AttributeSnapshot = serde_wasm_bindgen::from_value(before).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let after: AttributeSnapshot = serde_wasm_bindgen::from_value(after).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let result = before.diff(&after);
+    let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+    Ok(result.serialize(&s).unwrap())
+}
@@ -3,7 +3,7 @@ use serde::{Serialize, Deserialize};
 use crate::applications::common::{BuffInterface, CharacterInterface, TargetFunctionInterface, WeaponInterface};
 use mona::artifacts::{Artifact, ArtifactList};
 use mona::artifacts::effect_config::ArtifactEffectConfig;
-use mona::attribute::{AttributeNoReactive, AttributeUtils, ComplicatedAttributeGraph, SimpleAttributeGraph2};
+use mona::attribute::{AttributeGraphError, AttributeNoReactive, AttributeViews, AttributeUtils, ComplicatedAttributeGraph, SimpleAttributeGraph2};
 use mona::buffs::{Buff, BuffConfig};
 use mona::character::Character;
 use mona::weapon::Weapon;
@@ -17,9 +17,7 @@ pub struct GetAttributeInterface {
     artifact_config: Option<ArtifactEffectConfig>
 }
 
-pub fn get_attribute(val: JsValue) -> JsValue {
-    let input: GetAttributeInterface = serde_wasm_bindgen::from_value(val).unwrap();
-
+pub fn create_attribute(input: GetAttributeInterface) -> Result<ComplicatedAttributeGraph, AttributeGraphError> {
     let character: Character<ComplicatedAttributeGraph> = input.character.to_character();
     let weapon: Weapon<ComplicatedAttributeGraph> = input.weapon.to_weapon(&character);
 
@@ -35,15 +33,30 @@ pub fn get_attribute(val: JsValue) -> JsValue {
 
     let buffs: Vec<Box<dyn Buff<ComplicatedAttributeGraph>>> = input.buffs.iter().map(|x| x.to_buff()).collect();
 
-    let attribute: ComplicatedAttributeGraph = AttributeUtils::create_attribute_from_big_config(
+    AttributeUtils::create_attribute_from_big_config(
         &artifact_list,
         &artifact_config,
         &character,
         &weapon,
         &buffs
-    );
+    )
+}
+
+pub fn get_attribute(val: JsValue) -> Result<JsValue, JsValue> {
+    let input: GetAttributeInterface = serde_wasm_bindgen::from_value(val).unwrap();
+    let attribute = create_attribute(input).map_err(|e| JsValue::from_str(&e.to_string()))?;
 
     let result = AttributeNoReactive::from(&attribute);
     let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
-    result.serialize(&s).unwrap()
+    Ok(result.serialize(&s).unwrap())
+}
+
+/// panel and combat values, the panel view leaves out sources that only apply in combat
+pub fn get_attribute_views(val: JsValue) -> Result<JsValue, JsValue> {
+    let input: GetAttributeInterface = serde_wasm_bindgen::from_value(val).unwrap();
+    let attribute = create_attribute(input).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let result = AttributeViews::from(&attribute);
+    let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+    Ok(result.serialize(&s).unwrap())
 }
@@ -0,0 +1,21 @@
+use wasm_bindgen::prelude::*;
+use serde::{Serialize, Deserialize};
+use mona::enemies::{EnemyName, EnemyResistance, EnemyState};
+
+#[derive(Serialize, Deserialize)]
+pub struct EnemyStateOutput {
+    state: EnemyState,
+    resistance: EnemyResistance,
+}
+
+pub fn get_enemy_states(name: JsValue) -> JsValue {
+    let name: EnemyName = serde_wasm_bindgen::from_value(name).unwrap();
+
+    let result: Vec<EnemyStateOutput> = name.states().into_iter().map(|state| EnemyStateOutput {
+        state,
+        resistance: name.resistance(state),
+    }).collect();
+
+    let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+    result.serialize(&s).unwrap()
+}
@@ -0,0 +1,91 @@
+use wasm_bindgen::prelude::*;
+use serde::{Serialize, Deserialize};
+use crate::applications::common::{BuffInterface, CharacterInterface, EnemyInterface, TargetFunctionInterface, WeaponInterface};
+use mona::artifacts::{Artifact, ArtifactSetName};
+use mona::artifacts::effect_config::ArtifactConfigInterface;
+use mona::attribute::{AttributeGraphError, SimpleAttributeGraph2};
+use mona::buffs::Buff;
+use mona::inventory::{InventoryAnalysis, InventoryAnalysisConfig, InventoryCharacter};
+use mona::target_functions::target_function_opt_config::{TargetFunctionOptConfig, TargetGradientSetup};
+
+#[derive(Serialize, Deserialize)]
+pub struct InventoryCharacterInterface {
+    // shown in the result, defaults to the character name
+    #[serde(default)]
+    pub name: Option<String>,
+    pub character: CharacterInterface,
+    pub weapon: WeaponInterface,
+    pub target_function: TargetFunctionInterface,
+    #[serde(default)]
+    pub buffs: Vec<BuffInterface>,
+    #[serde(default)]
+    pub enemy: Option<EnemyInterface>,
+    #[serde(default)]
+    pub artifact_config: Option<ArtifactConfigInterface>,
+    // stat weights are taken at the current equipment
+    #[serde(default)]
+    pub equipped: Vec<Artifact>,
+    // sets the character is built around, each gets its own candidate pool
+    #[serde(default)]
+    pub set_names: Option<Vec<ArtifactSetName>>,
+}
+
+impl InventoryCharacterInterface {
+    pub fn name(&self) -> String {
+        self.name.clone().unwrap_or(self.character.name.to_string())
+    }
+
+    pub fn to_inventory_character(&self) -> Result<InventoryCharacter, AttributeGraphError> {
+        let character = self.character.to_character();
+        let weapon = self.weapon.to_weapon(&character);
+        let target_function = self.target_function.to_target_function(&character, &weapon);
+        let buffs: Vec<Box<dyn Buff<SimpleAttributeGraph2>>> = self.buffs.iter().map(|x| x.to_buff()).collect();
+        let enemy = match self.enemy {
+            Some(ref x) => x.to_enemy(),
+            None => Default::default()
+        };
+        let artifact_config = match self.artifact_config {
+            Some(ref x) => x.clone().to_config(),
+            None => target_function.get_default_artifact_config(&Default::default())
+        };
+
+        let equipped: Vec<&Artifact> = self.equipped.iter().collect();
+        let setup = TargetGradientSetup {
+            character: &character,
+            weapon: &weapon,
+            target_function: target_function.as_ref(),
+            enemy: &enemy,
+            other_enemies: &[],
+            buffs: &buffs,
+            artifact_config: &artifact_config,
+        };
+        let mut opt_config = TargetFunctionOptConfig::from_target_gradient(&setup, &equipped)?;
+        opt_config.set_names = self.set_names.clone();
+        Ok(InventoryCharacter {
+            name: self.name(),
+            opt_config,
+        })
+    }
+}
+
+pub fn get_inventory_analysis(characters: JsValue, artifacts: JsValue, config: JsValue) -> JsValue {
+    let characters: Vec<InventoryCharacterInterface> = serde_wasm_bindgen::from_value(characters).unwrap();
+    let artifacts: Vec<Artifact> = serde_wasm_bindgen::from_value(artifacts).unwrap();
+    let config: Option<InventoryAnalysisConfig> = serde_wasm_bindgen::from_value(config).unwrap();
+
+    // characters whose weights cannot be computed are reported as ignored, there is no trash list then
+    let mut failed = Vec::new();
+    let mut inventory_characters = Vec::new();
+    for character in characters.iter() {
+        match character.to_inventory_character() {
+            Ok(x) => inventory_characters.push(x),
+            Err(_) => failed.push(character.name())
+        }
+    }
+
+    let mut result = InventoryAnalysis::new(&inventory_characters, &artifacts, &config.unwrap_or_default());
+    result.add_ignored(failed);
+
+    let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+    result.serialize(&s).unwrap()
+}
@@ -4,15 +4,22 @@ use mona::artifacts::Artifact;
 use serde::Serialize;
 
 pub mod get_attribute;
+pub mod get_enemy_states;
+pub mod attribute_snapshot;
+pub mod inventory_analysis;
 
 pub struct CommonInterface {}
 
 #[wasm_bindgen]
 impl CommonInterface {
-    pub fn get_attribute(val: JsValue) -> JsValue {
+    pub fn get_attribute(val: JsValue) -> Result<JsValue, JsValue> {
         get_attribute::get_attribute(val)
     }
 
+    pub fn get_attribute_views(val: JsValue) -> Result<JsValue, JsValue> {
+        get_attribute::get_attribute_views(val)
+    }
+
     pub fn get_artifacts_rank_by_character(character: JsValue, weapon: JsValue, tf: JsValue, artifacts: JsValue) -> JsValue {
         let character_interface: CharacterInterface = serde_wasm_bindgen::from_value(character).unwrap();
         let weapon_interface: WeaponInterface = serde_wasm_bindgen::from_value(weapon).unwrap();
@@ -39,4 +46,21 @@ impl CommonInterface {
         let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
         scores.serialize(&s).unwrap()
     }
+
+    /// artifacts no character wants, now or after leveling, and the characters wanting each of the others
+    pub fn get_inventory_analysis(characters: JsValue, artifacts: JsValue, config: JsValue) -> JsValue {
+        inventory_analysis::get_inventory_analysis(characters, artifacts, config)
+    }
+
+    pub fn get_enemy_states(name: JsValue) -> JsValue {
+        get_enemy_states::get_enemy_states(name)
+    }
+
+    pub fn get_attribute_snapshot(val: JsValue) -> Result<JsValue, JsValue> {
+        attribute_snapshot::get_attribute_snapshot(val)
+    }
+
+    pub fn diff_attribute_snapshot(before: JsValue, after: JsValue) -> Result<JsValue, JsValue> {
+        attribute_snapshot::diff_attribute_snapshot(before, after)
+    }
 }
@@ -1,6 +1,6 @@
 use mona::artifacts::{Artifact, ArtifactList};
 use mona::artifacts::effect_config::ArtifactEffectConfig;
-use mona::attribute::{AttributeUtils, SimpleAttributeGraph2};
+use mona::attribute::{AttributeGraphError, AttributeUtils, SimpleAttributeGraph2};
 use mona::buffs::Buff;
 use mona_dsl::common::UnsafeDamageContext;
 use mona_dsl::compile_source_to_code_object;
@@ -40,6 +40,14 @@ impl RunResult {
         }
     }
 
+    pub fn from_attribute_graph_error(e: &AttributeGraphError) -> Self {
+        Self {
+            is_error: true,
+            error_msg: e.to_string(),
+            output: String::new()
+        }
+    }
+
     pub fn from_runtime_error(e: &RuntimeError) -> Self {
         Self {
             is_error: true,
@@ -86,13 +94,19 @@ impl DSLInterface {
         env.set_ostream(Box::new(os));
 
         // set damage context
-        let attribute = AttributeUtils::create_attribute_from_big_config(
+        let attribute = match AttributeUtils::create_attribute_from_big_config(
             &ArtifactList { artifacts: &artifacts_ref },
             &artifact_config,
             &character,
             &weapon,
             &buffs
-        );
+        ) {
+            Err(e) => {
+                let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+                return RunResult::from_attribute_graph_error(&e).serialize(&s).unwrap()
+            }
+            Ok(v) => v
+        };
         let unsafe_context = UnsafeDamageContext {
             character_common_data: &character.common_data,
             enemy: &enemy,
@@ -0,0 +1,20 @@
+use serde::Serialize;
+use wasm_bindgen::JsValue;
+use wasm_bindgen::prelude::*;
+use mona::energy::EnergyTeamConfig;
+use mona::utils;
+
+pub struct EnergyWasm;
+
+#[wasm_bindgen]
+impl EnergyWasm {
+    pub fn recharge_demand(val: JsValue) -> JsValue {
+        utils::set_panic_hook();
+
+        let input: EnergyTeamConfig = serde_wasm_bindgen::from_value(val).unwrap();
+        let result = input.calc();
+
+        let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+        result.serialize(&s).unwrap()
+    }
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use crate::applications::common::{BuffInterface, CharacterInterface, EnemyInterface, TargetFunctionInterface, WeaponInterface};
+use mona::artifacts::Artifact;
+use mona::artifacts::effect_config::ArtifactConfigInterface;
+use mona::attribute::SimpleAttributeGraph2;
+use mona::buffs::Buff;
+use mona::farming::{FarmingConfig, FarmingSetup, FarmingSimulator};
+use mona::upgrade_predicate::artifact_upgrader::ArtifactUpgrader;
+use mona::upgrade_predicate::upgrade_predicate_config::UpgradePredicateConfig;
+use mona::utils;
+
+#[derive(Serialize, Deserialize)]
+pub struct FarmingInterface {
+    pub character: CharacterInterface,
+    pub weapon: WeaponInterface,
+    pub target_function: TargetFunctionInterface,
+    pub buffs: Vec<BuffInterface>,
+    #[serde(default)]
+    pub enemy: Option<EnemyInterface>,
+    pub artifact_config: Option<ArtifactConfigInterface>,
+    // the currently equipped artifacts, empty slots are filled by the first useful drop
+    #[serde(default)]
+    pub equipped: Vec<Artifact>,
+    // leveling policy, levels everything to max by default
+    #[serde(default)]
+    pub upgrade_predicate: Option<UpgradePredicateConfig>,
+    pub farming: FarmingConfig,
+}
+
+impl FarmingInterface {
+    pub fn to_simulator(self) -> (FarmingSimulator, FarmingConfig) {
+        let character = self.character.to_character();
+        let weapon = self.weapon.to_weapon(&character);
+        let target_function = self.target_function.to_target_function(&character, &weapon);
+        let buffs: Vec<Box<dyn Buff<SimpleAttributeGraph2>>> = self.buffs.iter().map(|x| x.to_buff()).collect();
+        let enemy = match self.enemy {
+            Some(ref x) => x.to_enemy(),
+            None => Default::default()
+        };
+        let artifact_config = match self.artifact_config {
+            Some(x) => x.to_config(),
+            None => target_function.get_default_artifact_config(&Default::default())
+        };
+        let upgrader = match self.upgrade_predicate {
+            Some(ref x) => ArtifactUpgrader::new(x.to_predicate()),
+            None => ArtifactUpgrader::default()
+        };
+
+        let setup = FarmingSetup {
+            character,
+            weapon,
+            target_function,
+            enemy,
+            buffs,
+            artifact_config,
+            equipped: self.equipped,
+        };
+        let simulator = FarmingSimulator::new(setup, upgrader);
+        (simulator, self.farming)
+    }
+}
+
+pub struct FarmingWasm;
+
+#[wasm_bindgen]
+impl FarmingWasm {
+    /// days of farming a domain until the target value improves and until each slot is replaced
+    pub fn simulate(input: JsValue) -> Result<JsValue, JsValue> {
+        utils::set_panic_hook();
+
+        let input: FarmingInterface = serde_wasm_bindgen::from_value(input).unwrap();
+        let (simulator, config) = input.to_simulator();
+        let result = simulator.simulate(&config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+        Ok(result.serialize(&s).unwrap())
+    }
+}
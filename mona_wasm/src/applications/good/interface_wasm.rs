@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use mona::character::{CharacterConfig, CharacterName};
+use mona::good::{GoodArtifactEntry, GoodCharacterEntry, GoodDocument, GoodImport, GoodSkippedEntry, GoodWeaponEntry};
+use mona::utils;
+use mona::weapon::WeaponConfig;
+use crate::applications::common::{CharacterInterface, WeaponInterface};
+
+#[derive(Serialize, Deserialize)]
+pub struct GoodWeaponInterface {
+    pub weapon: WeaponInterface,
+    pub location: Option<CharacterName>,
+    pub lock: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct GoodInterface {
+    pub artifacts: Vec<GoodArtifactEntry>,
+    pub characters: Vec<CharacterInterface>,
+    pub weapons: Vec<GoodWeaponInterface>,
+    // entries left out of the import
+    #[serde(default)]
+    pub skipped: Vec<GoodSkippedEntry>,
+}
+
+impl From<GoodImport> for GoodInterface {
+    fn from(good: GoodImport) -> Self {
+        GoodInterface {
+            artifacts: good.artifacts,
+            characters: good.characters.into_iter().map(|x| CharacterInterface {
+                name: x.name,
+                level: x.level,
+                ascend: x.ascend,
+                constellation: x.constellation,
+                skill1: x.skill1,
+                skill2: x.skill2,
+                skill3: x.skill3,
+                params: CharacterConfig::NoConfig,
+                // GOOD levels leave out the constellation bonus
+                base_skill_levels: true,
+            }).collect(),
+            weapons: good.weapons.into_iter().map(|x| GoodWeaponInterface {
+                weapon: WeaponInterface {
+                    name: x.name,
+                    level: x.level,
+                    ascend: x.ascend,
+                    refine: x.refine,
+                    params: WeaponConfig::NoConfig,
+                },
+                location: x.location,
+                lock: x.lock,
+            }).collect(),
+            skipped: good.skipped,
+        }
+    }
+}
+
+impl GoodInterface {
+    // character and weapon params are not part of the format
+    pub fn to_good(self) -> GoodImport {
+        GoodImport {
+            artifacts: self.artifacts,
+            characters: self.characters.into_iter().map(|x| {
+                let skills = x.base_skills();
+                GoodCharacterEntry {
+                    name: x.name,
+                    level: x.level,
+                    ascend: x.ascend,
+                    constellation: x.constellation,
+                    skill1: skills[0],
+                    skill2: skills[1],
+                    skill3: skills[2],
+                }
+            }).collect(),
+            weapons: self.weapons.into_iter().map(|x| GoodWeaponEntry {
+                name: x.weapon.name,
+                level: x.weapon.level,
+                ascend: x.weapon.ascend,
+                refine: x.weapon.refine,
+                location: x.location,
+                lock: x.lock,
+            }).collect(),
+            skipped: self.skipped,
+        }
+    }
+}
+
+pub struct GoodWasm;
+
+#[wasm_bindgen]
+impl GoodWasm {
+    pub fn import(json: &str) -> Result<JsValue, JsValue> {
+        utils::set_panic_hook();
+
+        let document = GoodDocument::from_json(json).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let result: GoodInterface = document.import().into();
+
+        let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+        Ok(result.serialize(&s).unwrap())
+    }
+
+    pub fn export(val: JsValue, source: &str) -> String {
+        utils::set_panic_hook();
+
+        let input: GoodInterface = serde_wasm_bindgen::from_value(val).unwrap();
+        input.to_good().export(source).to_json()
+    }
+}
@@ -7,3 +7,10 @@ pub mod bonus_per_stat;
 pub mod potential;
 pub mod dsl;
 pub mod artifact_best_set;
+pub mod rotation;
+pub mod reaction_sim;
+pub mod energy;
+pub mod artifact_rolls;
+pub mod good;
+pub mod artifact_import;
+pub mod farming;
@@ -22,6 +22,8 @@ pub trait SingleOptimizeAlgorithm {
         weapon: &Weapon<SimpleAttributeGraph2>,
         target_function: &Box<dyn TargetFunction>,
         enemy: &Enemy,
+        // targets besides `enemy`, hit by aoe damage entries of the target function
+        other_enemies: &[Enemy],
         buffs: &[Box<dyn Buff<SimpleAttributeGraph2>>],
         constraint: &ConstraintConfig,
         count: usize
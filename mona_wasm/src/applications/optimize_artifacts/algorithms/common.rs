@@ -3,14 +3,17 @@ use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use mona::artifacts::{Artifact, ArtifactList, ArtifactSetName};
 use mona::artifacts::effect_config::ArtifactEffectConfig;
-use mona::attribute::{AttributeUtils, SimpleAttributeGraph2, AttributeCommon, Attribute, AttributeName};
+use mona::attribute::{AttributeUtils, SimpleAttributeGraph2, AttributeCommon, Attribute, AttributeName, ArtifactBatchEvaluator, AttributeGraphError};
 use mona::buffs::Buff;
 use mona::character::Character;
 use mona::common::StatName;
 use mona::enemies::Enemy;
 use mona::target_functions::TargetFunction;
+use mona::target_functions::target_function_opt_config::{TargetFunctionOptConfig, TargetGradientSetup};
+use mona::utils::artifact::get_per_slot_artifacts;
 use mona::weapon::Weapon;
 use rustc_hash::FxHashSet;
+use smallvec::SmallVec;
 use crate::applications::optimize_artifacts::inter::{ConstraintConfig, OptimizationResult};
 
 #[derive(Clone)]
@@ -61,11 +64,12 @@ pub struct ValueFunction<'a> {
     pub target_function: &'a Box<dyn TargetFunction>,
     pub buffs: &'a [Box<dyn Buff<SimpleAttributeGraph2>>],
     pub enemy: &'a Enemy,
+    pub other_enemies: &'a [Enemy],
     pub constraint: &'a ConstraintConfig,
 }
 
 impl<'a> ValueFunction<'a> {
-    pub fn get_attribute(&self, arts: &[&Artifact]) -> SimpleAttributeGraph2 {
+    pub fn get_attribute(&self, arts: &[&Artifact]) -> Result<SimpleAttributeGraph2, AttributeGraphError> {
         let art_list = ArtifactList {
             artifacts: arts,
         };
@@ -85,15 +89,17 @@ impl<'a> ValueFunction<'a> {
             &self.character,
             &self.weapon,
             &arts,
-            &self.enemy
+            &self.enemy,
+            &self.other_enemies
         );
         score
     }
 
-    pub fn score(&self, arts: &[&Artifact]) -> f64 {
-        let attribute = self.get_attribute(arts);
+    // None if the edges of the attribute are invalid
+    pub fn score(&self, arts: &[&Artifact]) -> Option<f64> {
+        let attribute = self.get_attribute(arts).ok()?;
 
-        self.score_attribute(&attribute, arts)
+        Some(self.score_attribute(&attribute, arts))
     }
 
     pub fn check_attribute_attribute(&self, attribute: &SimpleAttributeGraph2) -> bool {
@@ -124,9 +130,55 @@ impl<'a> ValueFunction<'a> {
     }
 
     pub fn check_attribute(&self, arts: &[&Artifact]) -> bool {
-        let attribute = self.get_attribute(arts);
+        match self.get_attribute(arts) {
+            Ok(attribute) => self.check_attribute_attribute(&attribute),
+            Err(_) => false
+        }
+    }
+}
+
+/// scores candidates through an `ArtifactBatchEvaluator`, which gives the same values as `ValueFunction`
+/// without rebuilding the attribute for every candidate
+pub struct BatchValueFunction<'a> {
+    pub value_fn: &'a ValueFunction<'a>,
+    evaluator: ArtifactBatchEvaluator<'a>,
+}
+
+impl<'a> BatchValueFunction<'a> {
+    pub fn new(value_fn: &'a ValueFunction<'a>) -> BatchValueFunction<'a> {
+        BatchValueFunction {
+            value_fn,
+            evaluator: ArtifactBatchEvaluator::new(value_fn.character, value_fn.weapon, value_fn.buffs, value_fn.artifact_effect_config),
+        }
+    }
+
+    // None if the constraints are not satisfied.
+    // combinations whose set effects give invalid edges are skipped the same way
+    fn score_attribute(&self, attribute: &SimpleAttributeGraph2, arts: &[&Artifact]) -> Option<f64> {
+        if !self.value_fn.check_attribute_attribute(attribute) {
+            return None;
+        }
+        Some(self.value_fn.score_attribute(attribute, arts))
+    }
 
-        self.check_attribute_attribute(&attribute)
+    pub fn score(&self, arts: &[&Artifact]) -> Option<f64> {
+        self.evaluator.evaluate_artifacts(arts, |attribute| self.score_attribute(attribute, arts)).unwrap_or(None)
+    }
+
+    /// scores of `prefix` completed by each of `candidates`
+    pub fn score_batch(&self, prefix: &[&Artifact], candidates: &[&Artifact]) -> Vec<Option<f64>> {
+        if candidates.is_empty() {
+            return Vec::new();
+        }
+
+        let mut arts: SmallVec<[&Artifact; 5]> = prefix.iter().cloned().collect();
+        arts.push(candidates[0]);
+        let last = arts.len() - 1;
+
+        self.evaluator.evaluate_batch(prefix, candidates, |index, attribute| {
+            arts[last] = candidates[index];
+            self.score_attribute(attribute, &arts)
+        }).unwrap_or_else(|_| vec![None; candidates.len()])
     }
 }
 
@@ -276,4 +328,34 @@ pub fn get_set_names(arts: &[&Artifact]) -> Vec<ArtifactSetName> {
     }
 
     set.into_iter().collect()
-}
\ No newline at end of file
+}
+
+/// opt config from the gradient of the target, for target functions without a hand written one
+///
+/// the gradient is taken at the best artifact of each slot under the weights at no artifacts,
+/// since at the base stats crit damage is worth little and would be filtered out.
+/// None if the edges of the attribute are invalid
+pub fn get_gradient_opt_config(value_fn: &ValueFunction, arts: &[&Artifact]) -> Option<TargetFunctionOptConfig> {
+    let setup = TargetGradientSetup {
+        character: value_fn.character,
+        weapon: value_fn.weapon,
+        target_function: value_fn.target_function.as_ref(),
+        enemy: value_fn.enemy,
+        other_enemies: value_fn.other_enemies,
+        buffs: value_fn.buffs,
+        artifact_config: value_fn.artifact_effect_config,
+    };
+    let config_at = |equipped: &[&Artifact]| TargetFunctionOptConfig::from_target_gradient(&setup, equipped).ok();
+
+    let base_config = config_at(&[])?;
+    let (flowers, feathers, sands, goblets, heads) = get_per_slot_artifacts(arts);
+    let mut equipped: Vec<&Artifact> = Vec::new();
+    for slot in [flowers, feathers, sands, goblets, heads] {
+        let best = slot.into_iter().max_by(|x, y| base_config.score(x).partial_cmp(&base_config.score(y)).unwrap());
+        if let Some(x) = best {
+            equipped.push(x);
+        }
+    }
+
+    config_at(&equipped)
+}
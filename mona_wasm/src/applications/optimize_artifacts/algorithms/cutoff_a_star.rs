@@ -9,7 +9,7 @@ use crate::applications::optimize_artifacts::algorithms::cutoff_heuristic::Cutof
 use crate::applications::optimize_artifacts::inter::{ConstraintConfig, ConstraintSetMode, OptimizationResult};
 use mona::artifacts::{Artifact, ArtifactList, ArtifactSetName, ArtifactSlotName};
 use mona::artifacts::effect_config::ArtifactEffectConfig;
-use mona::attribute::{SimpleAttributeGraph2, AttributeCommon, Attribute, AttributeName, AttributeUtils};
+use mona::attribute::{SimpleAttributeGraph2, AttributeCommon, Attribute, AttributeName, AttributeUtils, ArtifactBatchEvaluator};
 use mona::buffs::Buff;
 use mona::character::Character;
 use mona::common::StatName;
@@ -83,6 +83,8 @@ struct ResultRecorder<'a> {
     constraint: &'a ConstraintConfig,
     buffs: &'a [Box<dyn Buff<SimpleAttributeGraph2>>],
     enemy: Enemy,
+    other_enemies: Vec<Enemy>,
+    evaluator: ArtifactBatchEvaluator<'a>,
 }
 
 impl<'a> ResultRecorder<'a> {
@@ -92,15 +94,20 @@ impl<'a> ResultRecorder<'a> {
         weapon: &'a Weapon<SimpleAttributeGraph2>,
         target_function: &'a Box<dyn TargetFunction>,
         enemy: &'a Enemy,
+        other_enemies: &[Enemy],
         constraint: &'a ConstraintConfig,
         buffs: &'a [Box<dyn Buff<SimpleAttributeGraph2>>],
         result_count: usize
     ) -> Self {
         let mut enemy = enemy.clone();
+        let mut other_enemies = other_enemies.to_vec();
 
         // buff change enemy
         for b in buffs.iter() {
             b.change_enemy(&mut enemy);
+            for e in other_enemies.iter_mut() {
+                b.change_enemy(e);
+            }
         }
 
         let artifact_config = if let Some(x) = artifact_config {
@@ -109,6 +116,8 @@ impl<'a> ResultRecorder<'a> {
             target_function.get_default_artifact_config(&Default::default())
         };
 
+        let evaluator = ArtifactBatchEvaluator::new(character, weapon, buffs, &artifact_config);
+
         Self {
             result_heap: BinaryHeap::with_capacity(result_count),
             result_count,
@@ -121,28 +130,20 @@ impl<'a> ResultRecorder<'a> {
             constraint,
             buffs,
             enemy,
+            other_enemies,
+            evaluator,
         }
     }
 
     fn calc_value(&self, arts: &[&Artifact]) -> Option<f64> {
-        let artifact_list = ArtifactList {
-            artifacts: arts
-        };
-
-        let attribute = AttributeUtils::create_attribute_from_big_config(
-            &artifact_list,
-            &self.artifact_config,
-            self.character,
-            self.weapon,
-            &self.buffs
-        );
-
-        if !check_attribute(&attribute, &self.constraint) {
-            return None;
-        }
+        self.evaluator.evaluate_artifacts(arts, |attribute| {
+            if !check_attribute(attribute, &self.constraint) {
+                return None;
+            }
 
-        let value = self.target_function.target(&attribute, self.character, self.weapon, &arts, &self.enemy);
-        Some(value)
+            let value = self.target_function.target(attribute, self.character, self.weapon, &arts, &self.enemy, &self.other_enemies);
+            Some(value)
+        }).unwrap_or(None)
     }
 
     // arts id are sorted by slots
@@ -615,13 +616,13 @@ impl<'a> SingleOptimizer<'a> {
 pub struct AStarCutoff;
 
 impl SingleOptimizeAlgorithm for AStarCutoff {
-    fn optimize(&self, artifacts: &[&Artifact], artifact_config: Option<ArtifactEffectConfig>, character: &Character<SimpleAttributeGraph2>, weapon: &Weapon<SimpleAttributeGraph2>, target_function: &Box<dyn TargetFunction>, enemy: &Enemy, buffs: &[Box<dyn Buff<SimpleAttributeGraph2>>], constraint: &ConstraintConfig, count: usize) -> Vec<OptimizationResult> {
+    fn optimize(&self, artifacts: &[&Artifact], artifact_config: Option<ArtifactEffectConfig>, character: &Character<SimpleAttributeGraph2>, weapon: &Weapon<SimpleAttributeGraph2>, target_function: &Box<dyn TargetFunction>, enemy: &Enemy, other_enemies: &[Enemy], buffs: &[Box<dyn Buff<SimpleAttributeGraph2>>], constraint: &ConstraintConfig, count: usize) -> Vec<OptimizationResult> {
         let (flowers, feathers, sands, goblets, heads) = get_per_slot_artifacts(&artifacts);
 
         let any_zero = vec![flowers, feathers, sands, goblets, heads].iter().any(|x| x.len() == 0);
         if any_zero {
             let naive_algo = CutoffAlgorithmHeuristic { use_heuristic: false };
-            return naive_algo.optimize(artifacts, artifact_config, character, weapon, target_function, enemy, buffs, constraint, count);
+            return naive_algo.optimize(artifacts, artifact_config, character, weapon, target_function, enemy, other_enemies, buffs, constraint, count);
         }
 
         let single_optimizer = SingleOptimizer::new(artifacts, constraint);
@@ -631,6 +632,7 @@ impl SingleOptimizeAlgorithm for AStarCutoff {
             weapon,
             target_function,
             &enemy,
+            other_enemies,
             constraint,
             buffs,
             count,
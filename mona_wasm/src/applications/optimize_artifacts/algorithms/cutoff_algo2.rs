@@ -13,7 +13,7 @@ use mona::utils::artifact::get_per_slot_artifacts;
 use mona::weapon::Weapon;
 use smallvec::{SmallVec, smallvec};
 use crate::applications::optimize_artifacts::algorithm::SingleOptimizeAlgorithm;
-use crate::applications::optimize_artifacts::algorithms::common::{get_artifacts_group, get_artifacts_group_without_set, get_set_names, get_super_artifacts, get_super_artifacts_without_set, ResultRecorder, ValueFunction};
+use crate::applications::optimize_artifacts::algorithms::common::{get_artifacts_group, get_artifacts_group_without_set, get_set_names, get_super_artifacts, get_super_artifacts_without_set, BatchValueFunction, ResultRecorder, ValueFunction};
 use crate::applications::optimize_artifacts::algorithms::cutoff_heuristic::CutoffAlgorithmHeuristic;
 use crate::applications::optimize_artifacts::algorithms::weight_heuristic::{NaiveWeightHeuristic, WeightHeuristicAlgorithm};
 use crate::applications::optimize_artifacts::inter::{ConstraintConfig, ConstraintSetMode, OptimizationResult};
@@ -155,25 +155,20 @@ impl<'a> CutoffAlgo2Helper<'a> {
         }
     }
 
-    pub fn is_better_than_current_least(&self, arts: &[&Artifact], value_fn: &ValueFunction, rc: &ResultRecorder) -> bool {
-        let attribute = value_fn.get_attribute(arts);
-
-        if !value_fn.check_attribute_attribute(&attribute) {
-            return false;
+    pub fn is_better_than_current_least(&self, arts: &[&Artifact], value_fn: &BatchValueFunction, rc: &ResultRecorder) -> bool {
+        match value_fn.score(arts) {
+            Some(score) => score * self.factor_a > rc.current_least(),
+            None => false
         }
-
-        let score = value_fn.score_attribute(&attribute, arts);
-        let current_least = rc.current_least();
-        score * self.factor_a > current_least
     }
 
-    pub fn update_artifacts(&self, arts: &[&Artifact], value_fn: &ValueFunction, rc: &mut ResultRecorder) {
-        let attribute = value_fn.get_attribute(arts);
-        if !value_fn.check_attribute_attribute(&attribute) {
-            return;
+    pub fn update_artifacts(&self, arts: &[&Artifact], value_fn: &BatchValueFunction, rc: &mut ResultRecorder) {
+        if let Some(score) = value_fn.score(arts) {
+            self.push_result(arts, score, rc);
         }
-        let score = value_fn.score_attribute(&attribute, arts);
+    }
 
+    fn push_result(&self, arts: &[&Artifact], score: f64, rc: &mut ResultRecorder) {
         let art_ids = [
             arts[0].id,
             arts[1].id,
@@ -184,7 +179,7 @@ impl<'a> CutoffAlgo2Helper<'a> {
         rc.push_result(art_ids, score);
     }
 
-    pub fn do_iter(&self, set_names: &[SlotSetName], main_stats: &[StatName], value_fn: &ValueFunction, rc: &mut ResultRecorder) {
+    pub fn do_iter(&self, set_names: &[SlotSetName], main_stats: &[StatName], value_fn: &BatchValueFunction, rc: &mut ResultRecorder) {
         let arts: SmallVec<[&[&Artifact]; 5]> = {
             let mut temp: SmallVec<[&[&Artifact]; 5]> = SmallVec::new();
             for i in 0..5 {
@@ -280,16 +275,13 @@ impl<'a> CutoffAlgo2Helper<'a> {
                             }
                         }
 
-                        for i4 in 0..arts[4].len() {
-                            let artifacts: SmallVec<[&Artifact; 5]> = smallvec![
-                                arts[0][i0],
-                                arts[1][i1],
-                                arts[2][i2],
-                                arts[3][i3],
-                                arts[4][i4],
-                            ];
-
-                            self.update_artifacts(&artifacts, value_fn, rc);
+                        let prefix = [arts[0][i0], arts[1][i1], arts[2][i2], arts[3][i3]];
+                        let scores = value_fn.score_batch(&prefix, arts[4]);
+                        for (i4, score) in scores.into_iter().enumerate() {
+                            if let Some(score) = score {
+                                let artifacts = [arts[0][i0], arts[1][i1], arts[2][i2], arts[3][i3], arts[4][i4]];
+                                self.push_result(&artifacts, score, rc);
+                            }
                         }
                     }
                 }
@@ -297,7 +289,7 @@ impl<'a> CutoffAlgo2Helper<'a> {
         }
     }
 
-    pub fn iter_set(&self, set_mask: &[[i32; 5]], s1: ArtifactSetName, s2: ArtifactSetName, value_fn: &ValueFunction, rc: &mut ResultRecorder) {
+    pub fn iter_set(&self, set_mask: &[[i32; 5]], s1: ArtifactSetName, s2: ArtifactSetName, value_fn: &BatchValueFunction, rc: &mut ResultRecorder) {
         for &sand in self.sand_stats.iter() {
             for &head in self.head_stats.iter() {
                 for &goblet in self.goblet_stats.iter() {
@@ -351,7 +343,7 @@ impl<'a> CutoffAlgo2Helper<'a> {
 
     }
 
-    pub fn iter_set4(&self, set_name: ArtifactSetName, value_fn: &ValueFunction, rc: &mut ResultRecorder) {
+    pub fn iter_set4(&self, set_name: ArtifactSetName, value_fn: &BatchValueFunction, rc: &mut ResultRecorder) {
         let set_masks = [
             [0, 1, 1, 1, 1], [1, 0, 1, 1, 1], [1, 1, 0, 1, 1], [1, 1, 1, 0, 1], [1, 1, 1, 1, 0]
         ];
@@ -360,7 +352,7 @@ impl<'a> CutoffAlgo2Helper<'a> {
         self.iter_set(&set_masks, set_name, ArtifactSetName::Empty, value_fn, rc);
     }
 
-    pub fn iter_set22(&self, s1: ArtifactSetName, s2: ArtifactSetName, value_fn: &ValueFunction, rc: &mut ResultRecorder) {
+    pub fn iter_set22(&self, s1: ArtifactSetName, s2: ArtifactSetName, value_fn: &BatchValueFunction, rc: &mut ResultRecorder) {
         let set_mask = [
             [0, 1, 1, 2, 2], [0, 1, 2, 1, 2], [0, 1, 2, 2, 1], [0, 2, 1, 1, 2], [0, 2, 1, 2, 1], [0, 2, 2, 1, 1],
             [1, 0, 1, 2, 2], [1, 0, 2, 1, 2], [1, 0, 2, 2, 1], [2, 0, 1, 1, 2], [2, 0, 1, 2, 1], [2, 0, 2, 1, 1],
@@ -373,7 +365,7 @@ impl<'a> CutoffAlgo2Helper<'a> {
         self.iter_set(&set_mask, s1, s2, value_fn, rc);
     }
 
-    pub fn iter_set2(&self, set_name: ArtifactSetName, value_fn: &ValueFunction, rc: &mut ResultRecorder) {
+    pub fn iter_set2(&self, set_name: ArtifactSetName, value_fn: &BatchValueFunction, rc: &mut ResultRecorder) {
         let set_masks = [
             [1, 1, 0, 0, 0], [1, 0, 1, 0, 0], [1, 0, 0, 1, 0], [1, 0, 0, 0, 1],
             [0, 1, 1, 0, 0], [0, 1, 0, 1, 0], [0, 1, 0, 0, 1],
@@ -385,14 +377,14 @@ impl<'a> CutoffAlgo2Helper<'a> {
         self.iter_set(&set_masks, set_name, ArtifactSetName::Empty, value_fn, rc);
     }
 
-    pub fn iter_any(&self, value_fn: &ValueFunction, rc: &mut ResultRecorder) {
+    pub fn iter_any(&self, value_fn: &BatchValueFunction, rc: &mut ResultRecorder) {
         // let mut set_count = [0; ArtifactSetName::LEN];
 
         // self.iter_set(&[[0, 0, 0, 0, 0]], ArtifactSetName::Empty, ArtifactSetName::Empty, main_stats, value_fn, rc);
         self.iter_set(&[[0, 0, 0, 0, 0]], ArtifactSetName::Empty, ArtifactSetName::Empty, value_fn, rc);
     }
 
-    pub fn do_calculation(&self, value_fn: &ValueFunction, rc: &mut ResultRecorder) {
+    pub fn do_calculation(&self, value_fn: &BatchValueFunction, rc: &mut ResultRecorder) {
         let set_names = &self.artifact_sets;
 
         let do_set4 = |recorder: &mut ResultRecorder| {
@@ -434,7 +426,7 @@ impl<'a> CutoffAlgo2Helper<'a> {
             do_any(recorder);
         };
 
-        match &value_fn.constraint.set_mode {
+        match &value_fn.value_fn.constraint.set_mode {
             Some(set_mode) => {
                 match set_mode {
                     ConstraintSetMode::Any => do_no_constraint(rc),
@@ -457,13 +449,13 @@ pub struct CutoffAlgo2 {
 }
 
 impl SingleOptimizeAlgorithm for CutoffAlgo2 {
-    fn optimize(&self, artifacts: &[&Artifact], artifact_config: Option<ArtifactEffectConfig>, character: &Character<SimpleAttributeGraph2>, weapon: &Weapon<SimpleAttributeGraph2>, target_function: &Box<dyn TargetFunction>, enemy: &Enemy, buffs: &[Box<dyn Buff<SimpleAttributeGraph2>>], constraint: &ConstraintConfig, count: usize) -> Vec<OptimizationResult> {
+    fn optimize(&self, artifacts: &[&Artifact], artifact_config: Option<ArtifactEffectConfig>, character: &Character<SimpleAttributeGraph2>, weapon: &Weapon<SimpleAttributeGraph2>, target_function: &Box<dyn TargetFunction>, enemy: &Enemy, other_enemies: &[Enemy], buffs: &[Box<dyn Buff<SimpleAttributeGraph2>>], constraint: &ConstraintConfig, count: usize) -> Vec<OptimizationResult> {
         let (flowers, feathers, sands, goblets, heads) = get_per_slot_artifacts(&artifacts);
 
         let any_zero = vec![flowers, feathers, sands, goblets, heads].iter().any(|x| x.len() == 0);
         if any_zero {
             let naive_algo = CutoffAlgorithmHeuristic { use_heuristic: false };
-            return naive_algo.optimize(artifacts, artifact_config, character, weapon, target_function, enemy, buffs, constraint, count);
+            return naive_algo.optimize(artifacts, artifact_config, character, weapon, target_function, enemy, other_enemies, buffs, constraint, count);
         }
 
         let mut result_recorder = ResultRecorder::new(count);
@@ -483,6 +475,7 @@ impl SingleOptimizeAlgorithm for CutoffAlgo2 {
             target_function: &target_function,
             buffs: &buffs,
             enemy: &enemy,
+            other_enemies,
             constraint: &constraint
         };
 
@@ -501,7 +494,8 @@ impl SingleOptimizeAlgorithm for CutoffAlgo2 {
             // None,
             self.accuracy_factor
         );
-        algo.do_calculation(&value_function, &mut result_recorder);
+        let batch_value_function = BatchValueFunction::new(&value_function);
+        algo.do_calculation(&batch_value_function, &mut result_recorder);
 
         let intermediate_results = result_recorder.get_results_descend();
         let max_score = intermediate_results.iter().map(|x| x.score)
@@ -10,16 +10,30 @@ use mona::enemies::Enemy;
 use mona::target_functions::TargetFunction;
 use mona::weapon::Weapon;
 use crate::applications::optimize_artifacts::algorithms::cutoff_algo2::CutoffAlgo2;
+use crate::applications::optimize_artifacts::algorithms::common::{get_gradient_opt_config, ValueFunction};
 
 pub struct CutoffHeuristicPlusAStar;
 
 
 impl SingleOptimizeAlgorithm for CutoffHeuristicPlusAStar {
-    fn optimize(&self, artifacts: &[&Artifact], artifact_config: Option<ArtifactEffectConfig>, character: &Character<SimpleAttributeGraph2>, weapon: &Weapon<SimpleAttributeGraph2>, target_function: &Box<dyn TargetFunction>, enemy: &Enemy, buffs: &[Box<dyn Buff<SimpleAttributeGraph2>>], constraint: &ConstraintConfig, count: usize) -> Vec<OptimizationResult> {
-        let target_function_opt_config = target_function.get_target_function_opt_config();
+    fn optimize(&self, artifacts: &[&Artifact], artifact_config: Option<ArtifactEffectConfig>, character: &Character<SimpleAttributeGraph2>, weapon: &Weapon<SimpleAttributeGraph2>, target_function: &Box<dyn TargetFunction>, enemy: &Enemy, other_enemies: &[Enemy], buffs: &[Box<dyn Buff<SimpleAttributeGraph2>>], constraint: &ConstraintConfig, count: usize) -> Vec<OptimizationResult> {
+        let default_artifact_config = target_function.get_default_artifact_config(&Default::default());
+        let value_fn = ValueFunction {
+            artifact_effect_config: artifact_config.as_ref().unwrap_or(&default_artifact_config),
+            character,
+            weapon,
+            target_function,
+            buffs,
+            enemy,
+            other_enemies,
+            constraint,
+        };
 
         let artifacts_vec: Vec<&Artifact> = artifacts.iter().cloned().collect();
-        let filtered_artifacts = target_function_opt_config.filter(artifacts_vec);
+        let filtered_artifacts = match get_gradient_opt_config(&value_fn, &artifacts_vec) {
+            Some(target_function_opt_config) => target_function_opt_config.filter(artifacts_vec),
+            None => artifacts_vec
+        };
 
         let a_star_algo = CutoffAlgo2 { accuracy_factor: 1.0 };
 
@@ -30,6 +44,7 @@ impl SingleOptimizeAlgorithm for CutoffHeuristicPlusAStar {
             weapon,
             target_function,
             enemy,
+            other_enemies,
             buffs,
             constraint,
             count
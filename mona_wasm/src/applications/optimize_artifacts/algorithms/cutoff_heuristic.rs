@@ -3,6 +3,7 @@ use std::collections::{BinaryHeap, HashMap};
 use serde::__private::ser::constrain;
 use mona::attribute::{Attribute, AttributeCommon, AttributeName, AttributeUtils};
 use crate::applications::optimize_artifacts::algorithm::SingleOptimizeAlgorithm;
+use crate::applications::optimize_artifacts::algorithms::common::{get_gradient_opt_config, ValueFunction};
 use crate::applications::optimize_artifacts::inter::{ConstraintConfig, ConstraintSetMode, OptimizationResult};
 use mona::artifacts::{Artifact, ArtifactList, ArtifactSlotName};
 use mona::artifacts::effect_config::ArtifactEffectConfig;
@@ -126,13 +127,17 @@ pub struct CutoffAlgorithmHeuristic {
 }
 
 impl SingleOptimizeAlgorithm for CutoffAlgorithmHeuristic {
-    fn optimize(&self, artifacts: &[&Artifact], artifact_config: Option<ArtifactEffectConfig>, character: &Character<SimpleAttributeGraph2>, weapon: &Weapon<SimpleAttributeGraph2>, target_function: &Box<dyn TargetFunction>, enemy: &Enemy, buffs: &[Box<dyn Buff<SimpleAttributeGraph2>>], constraint: &ConstraintConfig, count: usize) -> Vec<OptimizationResult> {
+    fn optimize(&self, artifacts: &[&Artifact], artifact_config: Option<ArtifactEffectConfig>, character: &Character<SimpleAttributeGraph2>, weapon: &Weapon<SimpleAttributeGraph2>, target_function: &Box<dyn TargetFunction>, enemy: &Enemy, other_enemies: &[Enemy], buffs: &[Box<dyn Buff<SimpleAttributeGraph2>>], constraint: &ConstraintConfig, count: usize) -> Vec<OptimizationResult> {
         let need_constraint = !constraint.is_any();
 
         let mut enemy = enemy.clone();
+        let mut other_enemies = other_enemies.to_vec();
         // buff change enemy
         for b in buffs.iter() {
             b.change_enemy(&mut enemy);
+            for e in other_enemies.iter_mut() {
+                b.change_enemy(e);
+            }
         }
 
         let artifact_config = if let Some(x) = artifact_config {
@@ -143,8 +148,20 @@ impl SingleOptimizeAlgorithm for CutoffAlgorithmHeuristic {
 
         let mut artifacts: Vec<&Artifact> = artifacts.iter().map(|x| *x).collect();
         if get_iteration_count(&artifacts) > TOO_LARGE_ITER_COUNT && !need_constraint && self.use_heuristic {
-            let target_function_opt_config = target_function.get_target_function_opt_config();
-            artifacts = target_function_opt_config.filter(artifacts);
+            let value_fn = ValueFunction {
+                artifact_effect_config: &artifact_config,
+                character,
+                weapon,
+                target_function,
+                buffs,
+                enemy: &enemy,
+                other_enemies: &other_enemies,
+                constraint,
+            };
+            // without a valid opt config every artifact is kept
+            if let Some(target_function_opt_config) = get_gradient_opt_config(&value_fn, &artifacts) {
+                artifacts = target_function_opt_config.filter(artifacts);
+            }
         }
         let iter_count = get_iteration_count(&artifacts);
         // log!("iter count: {}", iter_count);
@@ -252,19 +269,23 @@ impl SingleOptimizeAlgorithm for CutoffAlgorithmHeuristic {
                                 artifacts: &buffer_artifacts
                             };
 
-                            let mut attribute = AttributeUtils::create_attribute_from_big_config(
+                            // combinations whose set effects give invalid edges are skipped
+                            let mut attribute = match AttributeUtils::create_attribute_from_big_config(
                                 &artifact_list,
                                 &artifact_config,
                                 character,
                                 weapon,
                                 &buffs
-                            );
+                            ) {
+                                Ok(x) => x,
+                                Err(_) => continue
+                            };
 
                             if !check_attribute(&attribute, constraint) {
                                 continue;
                             }
 
-                            let value = target_function.target(&attribute, character, weapon, &buffer_artifacts, &enemy);
+                            let value = target_function.target(&attribute, character, weapon, &buffer_artifacts, &enemy, &other_enemies);
 
                             let intermediate = OptimizationIntermediateResult {
                                 flower_index: flower_i,
@@ -1,7 +1,6 @@
 use std::collections::HashMap;
-use mona::artifacts::{Artifact, ArtifactList, ArtifactSetName, ArtifactSlotName};
-use mona::artifacts::eff::{ARTIFACT_EFF5, ArtifactEff};
-use mona::attribute::{AttributeUtils, SimpleAttributeGraph2};
+use mona::artifacts::ArtifactSetName;
+use mona::attribute::{stat_gradient, AttributeUtils, SimpleAttributeGraph2};
 use mona::character::Character;
 use mona::common::StatName;
 use mona::enemies::Enemy;
@@ -62,43 +61,22 @@ impl<'a> WeightHeuristicAlgorithm for NaiveWeightHeuristic<'a> {
             PhysicalBonus,
         ];
 
-        let base_value = {
-            let base_attribute = AttributeUtils::create_attribute_from_c_w_bs(&self.character, &self.weapon, &Vec::new());
-            let target_value = target_function.target(&base_attribute, &self.character, &self.weapon, &[], &Default::default());
-            target_value
+        let base_attribute = match AttributeUtils::create_attribute_from_c_w_bs(&self.character, &self.weapon, &Vec::new()) {
+            Ok(x) => x,
+            Err(_) => return HashMap::new()
+        };
+        let enemy: Enemy = Default::default();
+        // one gradient instead of a target evaluation per stat, stats the target only rewards past a threshold are missed
+        let gradient = match stat_gradient(&base_attribute, |x| target_function.target(x, &self.character, &self.weapon, &[], &enemy, &[])) {
+            Ok(x) => x,
+            Err(_) => return HashMap::new()
         };
-
-        if base_value == 0.0 {
-            return HashMap::new();
-        }
 
         let mut result = HashMap::new();
         for &stat in critical_stats.iter() {
-            let virtual_artifact = Artifact::new(
-                ArtifactSetName::Empty, ArtifactSlotName::Flower, 20, 5,
-                vec![(stat, ARTIFACT_EFF5.get_value(stat, 3) * 10.0)],
-                (StatName::HPFixed, 0.0)
-            );
-
-            let arts = vec![&virtual_artifact];
-            let art_list = ArtifactList {
-                artifacts: &arts
-            };
-
-            let attribute = AttributeUtils::create_attribute_from_big_config(
-                &art_list,
-                &Default::default(),
-                &self.character,
-                &self.weapon,
-                &[]
-            );
-
-            let value = target_function.target(&attribute, &self.character, &self.weapon, &[], &Default::default());
-            let ratio = (value - base_value) / base_value;
-            if ratio > 0.0 {
+            if gradient.get(&stat).cloned().unwrap_or(0.0) > 0.0 {
                 result.insert(stat, 1.0);
             }
-            // result.insert(stat, ratio);
         }
 
         result
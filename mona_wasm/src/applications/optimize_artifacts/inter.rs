@@ -1,11 +1,12 @@
 use serde::{Serialize, Deserialize};
-use crate::applications::common::{ArtifactFilterConfig, BuffInterface, CharacterInterface, TargetFunctionInterface, WeaponInterface};
+use crate::applications::common::{ArtifactFilterConfig, BuffInterface, CharacterInterface, EnemyInterface, TargetFunctionInterface, WeaponInterface};
 use crate::applications::optimize_artifacts::algorithm::SingleOptimizeAlgorithmName;
 use mona::artifacts::{Artifact, ArtifactSetName};
 use mona::artifacts::effect_config::{ArtifactConfigInterface, ArtifactEffectConfig};
 use mona::buffs::BuffConfig;
+use mona::energy::EnergyDemandConfig;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub enum ConstraintSetMode {
     Any,
     Set2(ArtifactSetName),
@@ -13,7 +14,7 @@ pub enum ConstraintSetMode {
     Set22(ArtifactSetName, ArtifactSetName),
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct ConstraintConfig {
     pub set_mode: Option<ConstraintSetMode>,
 
@@ -24,9 +25,19 @@ pub struct ConstraintConfig {
     pub em_min: Option<f64>,
     pub crit_min: Option<f64>,
     pub crit_dmg_min: Option<f64>,
+    // team energy used to derive recharge_min
+    #[serde(default)]
+    pub energy: Option<EnergyDemandConfig>,
 }
 
 impl ConstraintConfig {
+    /// raises recharge_min to the recharge demand computed from team energy
+    pub fn resolve_energy(&mut self) {
+        if let Some(demand) = self.energy.as_ref().and_then(|x| x.recharge_demand()) {
+            self.recharge_min = Some(self.recharge_min.map_or(demand, |x| x.max(demand)));
+        }
+    }
+
     pub fn is_any(&self) -> bool {
         if let Some(ref x) = self.set_mode {
             match x {
@@ -63,7 +74,8 @@ impl Default for ConstraintConfig {
             recharge_min: None,
             em_min: None,
             crit_min: None,
-            crit_dmg_min: None
+            crit_dmg_min: None,
+            energy: None,
         }
     }
 }
@@ -79,6 +91,25 @@ pub struct OptimizeArtifactInterface {
     pub buffs: Vec<BuffInterface>,
     #[serde(default)]
     pub algorithm: SingleOptimizeAlgorithmName,
+    #[serde(default)]
+    pub enemy: Option<EnemyInterface>,
+    // targets besides `enemy`, hit by aoe damage entries of the target function
+    #[serde(default)]
+    pub other_enemies: Vec<EnemyInterface>,
+    // optimize as if every artifact was upgraded to its max level, only the main stat grows
+    #[serde(default)]
+    pub assume_max_level: bool,
+}
+
+impl OptimizeArtifactInterface {
+    pub fn project_artifacts(&self, artifacts: &[&Artifact]) -> Option<Vec<Artifact>> {
+        if self.assume_max_level {
+            // pieces with an invalid main stat (flat def) are left out
+            Some(artifacts.iter().filter_map(|x| x.projected_to_max()).collect())
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Default, Debug)]
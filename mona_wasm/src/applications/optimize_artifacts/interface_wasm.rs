@@ -22,7 +22,7 @@ pub struct OptimizeSingleWasm;
 
 #[wasm_bindgen]
 impl OptimizeSingleWasm {
-    pub fn optimize(val: JsValue, artifacts: JsValue) -> JsValue {
+    pub fn optimize(val: JsValue, artifacts: JsValue) -> Result<JsValue, JsValue> {
         utils::set_panic_hook();
 
         let input: OptimizeArtifactInterface = match serde_wasm_bindgen::from_value(val) {
@@ -36,21 +36,37 @@ impl OptimizeSingleWasm {
         let weapon = input.weapon.to_weapon(&character);
         // let target_function = input.target_function.to_target_function(&character, &weapon);
         let target_function: Box<dyn TargetFunction> = if input.target_function.use_dsl {
-            Box::new(TargetFunctionDSL::new(&input.target_function.dsl_source.unwrap()))
+            Box::new(TargetFunctionDSL::new(&input.target_function.dsl_source.as_ref().unwrap()))
         } else {
             input.target_function.to_target_function(&character, &weapon)
         };
-        let constraint = input.constraint.unwrap_or(Default::default());
+        let mut constraint = input.constraint.clone().unwrap_or(Default::default());
+        constraint.resolve_energy();
         let buffs: Vec<Box<dyn Buff<SimpleAttributeGraph2>>> = input.buffs.iter().map(|x| x.to_buff()).collect();
         let artifact_config = input.artifact_config.as_ref().map(|x| x.clone().to_config());
 
+        // the algorithms skip combinations with invalid edges, edges invalid without artifacts would skip all of them
+        AttributeUtils::create_attribute_from_c_w_bs(&character, &weapon, &buffs)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
         let filtered_artifacts = input.filter.as_ref().map(|x| x.filter_artifact(&artifacts_ref));
         let artifacts = match filtered_artifacts {
             Some(ref a) => a.as_slice(),
             None => &artifacts_ref
         };
+        let projected_artifacts = input.project_artifacts(artifacts);
+        let projected_artifacts_ref: Option<Vec<&Artifact>> = projected_artifacts.as_ref().map(|x| x.iter().collect());
+        let artifacts = match projected_artifacts_ref {
+            Some(ref a) => a.as_slice(),
+            None => artifacts
+        };
 
         let algorithm = input.algorithm.get_algorithm();
+        let enemy = match input.enemy {
+            Some(ref x) => x.to_enemy(),
+            None => Default::default()
+        };
+        let other_enemies: Vec<Enemy> = input.other_enemies.iter().map(|x| x.to_enemy()).collect();
 
         let result = algorithm.optimize(
             &artifacts,
@@ -58,13 +74,14 @@ impl OptimizeSingleWasm {
             &character,
             &weapon,
             &target_function,
-            &Default::default(),
+            &enemy,
+            &other_enemies,
             &buffs,
             &constraint,
             100
         );
 
         let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
-        result.serialize(&s).unwrap()
+        Ok(result.serialize(&s).unwrap())
     }
 }
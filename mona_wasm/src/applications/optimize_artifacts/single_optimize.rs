@@ -21,14 +21,25 @@ pub fn optimize_single_interface_wasm(input: &OptimizeArtifactInterface, artifac
     let buffs: Vec<Box<dyn Buff<SimpleAttributeGraph2>>> = input.buffs.iter().map(|x| x.to_buff()).collect();
     let artifact_config = input.artifact_config.as_ref().map(|x| x.clone().to_config());
 
-    let default_constraint = ConstraintConfig::default();
-    let constraint = input.constraint.as_ref().unwrap_or(&default_constraint);
+    let mut constraint = input.constraint.clone().unwrap_or_default();
+    let enemy = match input.enemy {
+        Some(ref x) => x.to_enemy(),
+        None => Default::default()
+    };
+    let other_enemies: Vec<Enemy> = input.other_enemies.iter().map(|x| x.to_enemy()).collect();
+    constraint.resolve_energy();
 
     let filtered_artifacts = input.filter.as_ref().map(|x| x.filter_artifact(artifacts));
     let artifacts = match filtered_artifacts {
         Some(ref a) => a.as_slice(),
         None => artifacts
     };
+    let projected_artifacts = input.project_artifacts(artifacts);
+    let projected_artifacts_ref: Option<Vec<&Artifact>> = projected_artifacts.as_ref().map(|x| x.iter().collect());
+    let artifacts = match projected_artifacts_ref {
+        Some(ref a) => a.as_slice(),
+        None => artifacts
+    };
 
     let result = algo.optimize(
         &artifacts,
@@ -36,7 +47,8 @@ pub fn optimize_single_interface_wasm(input: &OptimizeArtifactInterface, artifac
         &character,
         &weapon,
         &target_function,
-        &Default::default(),
+        &enemy,
+        &other_enemies,
         &buffs,
         &constraint,
         count
@@ -1,9 +1,15 @@
 use wasm_bindgen::prelude::*;
-use crate::applications::common::PotentialFunctionInterface;
+use crate::applications::common::{BuffInterface, CharacterInterface, EnemyInterface, PotentialFunctionInterface, TargetFunctionInterface, WeaponInterface};
 use mona::artifacts::Artifact;
-use mona::potential_function::potential_function::calc_potential;
+use mona::artifacts::effect_config::ArtifactConfigInterface;
+use mona::attribute::{AttributeGraphError, SimpleAttributeGraph2};
+use mona::buffs::Buff;
+use mona::potential_function::potential_distribution::PotentialSummary;
+use mona::potential_function::potential_function::{calc_potential, PotentialFunction};
+use mona::potential_function::potential_function_engine::PotentialFunctionEngineName;
+use mona::potential_function::potential_functions::PotentialFunctionTargetFunction;
 use mona::utils::{set_panic_hook};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 pub struct PotentialInterface;
 
@@ -19,8 +25,117 @@ pub fn get_potential(artifacts: &[Artifact], pf_interface: &PotentialFunctionInt
     results
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct PotentialDistributionOptions {
+    #[serde(default)]
+    pub engine: PotentialFunctionEngineName,
+    // e.g. the potential of the current piece, reported as the probability of beating it
+    #[serde(default)]
+    pub threshold: Option<f64>,
+}
+
+pub fn get_potential_distribution(artifacts: &[Artifact], potential_function: &Box<dyn PotentialFunction>, options: &PotentialDistributionOptions) -> Vec<(u64, PotentialSummary)> {
+    let engine = options.engine.create();
+
+    let mut results = Vec::new();
+    // pieces with an invalid main stat (flat def) are left out
+    for artifact in artifacts.iter().filter(|x| x.has_valid_main_stat()) {
+        let distribution = engine.distribution(potential_function, artifact);
+        results.push((artifact.id, distribution.summary(options.threshold)));
+    }
+
+    results
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TargetPotentialInterface {
+    pub character: CharacterInterface,
+    pub weapon: WeaponInterface,
+    pub target_function: TargetFunctionInterface,
+    pub buffs: Vec<BuffInterface>,
+    #[serde(default)]
+    pub enemy: Option<EnemyInterface>,
+    pub artifact_config: Option<ArtifactConfigInterface>,
+    // the currently equipped artifacts, each candidate replaces the one of its slot
+    #[serde(default)]
+    pub equipped: Vec<Artifact>,
+}
+
+impl TargetPotentialInterface {
+    pub fn to_pf(self) -> Result<Box<dyn PotentialFunction>, AttributeGraphError> {
+        let character = self.character.to_character();
+        let weapon = self.weapon.to_weapon(&character);
+        let target_function = self.target_function.to_target_function(&character, &weapon);
+        let buffs: Vec<Box<dyn Buff<SimpleAttributeGraph2>>> = self.buffs.iter().map(|x| x.to_buff()).collect();
+        let enemy = match self.enemy {
+            Some(ref x) => x.to_enemy(),
+            None => Default::default()
+        };
+        let artifact_config = match self.artifact_config {
+            Some(x) => x.to_config(),
+            None => target_function.get_default_artifact_config(&Default::default())
+        };
+
+        Ok(Box::new(PotentialFunctionTargetFunction::new(
+            character,
+            weapon,
+            target_function,
+            enemy,
+            buffs,
+            artifact_config,
+            self.equipped
+        )?))
+    }
+}
+
 #[wasm_bindgen]
 impl PotentialInterface {
+    pub fn get_potential_distribution(artifacts: JsValue, pf_interface: JsValue, options: JsValue) -> JsValue {
+        set_panic_hook();
+
+        let artifacts: Vec<Artifact> = serde_wasm_bindgen::from_value(artifacts).unwrap();
+        let pf_interface: PotentialFunctionInterface = serde_wasm_bindgen::from_value(pf_interface).unwrap();
+        let options: PotentialDistributionOptions = serde_wasm_bindgen::from_value(options).unwrap();
+
+        let mut results = get_potential_distribution(&artifacts, &pf_interface.to_pf(), &options);
+        results.sort_by(|x, y| y.1.mean.total_cmp(&x.1.mean));
+
+        let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+        results.serialize(&s).unwrap()
+    }
+
+    pub fn get_target_potential_distribution(artifacts: JsValue, input: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+        set_panic_hook();
+
+        let artifacts: Vec<Artifact> = serde_wasm_bindgen::from_value(artifacts).unwrap();
+        let input: TargetPotentialInterface = serde_wasm_bindgen::from_value(input).unwrap();
+        let options: PotentialDistributionOptions = serde_wasm_bindgen::from_value(options).unwrap();
+
+        let potential_function = input.to_pf().map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let mut results = get_potential_distribution(&artifacts, &potential_function, &options);
+        results.sort_by(|x, y| y.1.mean.total_cmp(&x.1.mean));
+
+        let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+        Ok(results.serialize(&s).unwrap())
+    }
+
+    /// expected gain of the target value for each artifact if it is leveled to max and equipped
+    pub fn get_target_potential(artifacts: JsValue, input: JsValue) -> Result<JsValue, JsValue> {
+        set_panic_hook();
+
+        let artifacts: Vec<Artifact> = serde_wasm_bindgen::from_value(artifacts).unwrap();
+        let input: TargetPotentialInterface = serde_wasm_bindgen::from_value(input).unwrap();
+        let potential_function = input.to_pf().map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        let mut results: Vec<(u64, f64)> = artifacts.iter()
+            .map(|x| (x.id, calc_potential(&potential_function, x)))
+            .collect();
+        results.sort_by(|x, y| y.1.total_cmp(&x.1));
+
+        let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+        Ok(results.serialize(&s).unwrap())
+    }
+
     pub fn get_potential(artifacts: JsValue, pf_interface: JsValue) -> JsValue {
         set_panic_hook();
 
@@ -28,7 +143,7 @@ impl PotentialInterface {
         let pf_interface = serde_wasm_bindgen::from_value(pf_interface).unwrap();
 
         let mut results = get_potential(&artifacts, &pf_interface);
-        results.sort_by(|x, y| y.1.partial_cmp(&x.1).unwrap());
+        results.sort_by(|x, y| y.1.total_cmp(&x.1));
 
         let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
         results.serialize(&s).unwrap()
@@ -0,0 +1,48 @@
+use serde::{Serialize, Deserialize};
+use wasm_bindgen::JsValue;
+use wasm_bindgen::prelude::*;
+use mona::reaction_sim::{ElementalHit, ReactionRates, ReactionSimResult, ReactionSimulator};
+use mona::utils;
+
+#[derive(Serialize, Deserialize)]
+pub struct ReactionSimInput {
+    pub hits: Vec<ElementalHit>,
+    // electro-charged becomes lunar-charged
+    #[serde(default)]
+    pub moonsign: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReactionSimOutput {
+    pub result: ReactionSimResult,
+    // reaction rates of each character, indexed as `ElementalHit::character`
+    pub rates: Vec<ReactionRates>,
+}
+
+pub struct ReactionSimWasm;
+
+#[wasm_bindgen]
+impl ReactionSimWasm {
+    /// reactions of every hit in a single target aura simulation, and the resulting rates of each character,
+    /// which can be put into the reaction rate configs of target functions
+    pub fn simulate(val: JsValue) -> Result<JsValue, JsValue> {
+        utils::set_panic_hook();
+
+        let input: ReactionSimInput = serde_wasm_bindgen::from_value(val)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        let mut simulator = ReactionSimulator::new(input.moonsign);
+        let result = simulator.simulate(&input.hits);
+
+        let character_count = input.hits.iter().map(|x| x.character + 1).max().unwrap_or(0);
+        let rates = (0..character_count).map(|i| result.rates_of_character(i)).collect();
+
+        let output = ReactionSimOutput {
+            result,
+            rates,
+        };
+
+        let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+        Ok(output.serialize(&s).unwrap())
+    }
+}
@@ -0,0 +1,68 @@
+use serde::{Serialize, Deserialize};
+use wasm_bindgen::JsValue;
+use wasm_bindgen::prelude::*;
+use mona::artifacts::Artifact;
+use mona::artifacts::effect_config::ArtifactEffectConfig;
+use mona::attribute::SimpleAttributeGraph2;
+use mona::character::Character;
+use mona::rotation::{Rotation, RotationMember};
+use mona::utils;
+use mona::weapon::Weapon;
+use crate::applications::common::{CharacterInterface, EnemyInterface, WeaponInterface};
+
+#[derive(Serialize, Deserialize)]
+pub struct RotationMemberInterface {
+    pub character: CharacterInterface,
+    pub weapon: WeaponInterface,
+    pub artifacts: Vec<Artifact>,
+    pub artifact_config: Option<ArtifactEffectConfig>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RotationInput {
+    pub members: Vec<RotationMemberInterface>,
+    pub rotation: Rotation,
+    pub enemy: Option<EnemyInterface>,
+    // extra targets besides `enemy`, for aoe damage
+    #[serde(default)]
+    pub other_enemies: Vec<EnemyInterface>,
+}
+
+pub struct RotationWasm;
+
+#[wasm_bindgen]
+impl RotationWasm {
+    pub fn evaluate(val: JsValue) -> Result<JsValue, JsValue> {
+        utils::set_panic_hook();
+
+        let input: RotationInput = serde_wasm_bindgen::from_value(val)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        let characters: Vec<Character<SimpleAttributeGraph2>> = input.members.iter().map(|m| m.character.to_character()).collect();
+        let weapons: Vec<Weapon<SimpleAttributeGraph2>> = input.members.iter().zip(characters.iter()).map(|(m, c)| m.weapon.to_weapon(c)).collect();
+        let artifacts: Vec<Vec<&Artifact>> = input.members.iter().map(|m| m.artifacts.iter().collect()).collect();
+        let artifact_configs: Vec<ArtifactEffectConfig> = input.members.iter().map(|m| match &m.artifact_config {
+            Some(x) => x.clone(),
+            None => Default::default()
+        }).collect();
+
+        let members: Vec<RotationMember> = (0..input.members.len()).map(|i| RotationMember {
+            character: &characters[i],
+            weapon: &weapons[i],
+            artifacts: &artifacts[i],
+            artifact_config: &artifact_configs[i],
+        }).collect();
+
+        let mut enemies = vec![match input.enemy {
+            Some(x) => x.to_enemy(),
+            None => Default::default()
+        }];
+        enemies.extend(input.other_enemies.iter().map(|x| x.to_enemy()));
+
+        let result = input.rotation.evaluate_multi_target(&members, &enemies)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        let s = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+        Ok(result.serialize(&s).unwrap())
+    }
+}
@@ -84,7 +84,8 @@ fn main() {
         recharge_min: None,
         em_min: None,
         crit_min: None,
-        crit_dmg_min: None
+        crit_dmg_min: None,
+        energy: None,
     };
 
     let algo = CutoffAlgo2 { accuracy_factor: 1.0 };
@@ -100,6 +101,7 @@ fn main() {
         &target_function,
         &enemy,
         &[],
+        &[],
         &constraint,
         5
     );
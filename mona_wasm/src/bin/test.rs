@@ -27,7 +27,7 @@ fn main() {
     });
 
     let result = calc_artifact_best_set(
-        &c, &w, &tf, None, &[], &Default::default()
-    );
+        &c, &w, &tf, None, &[], &Default::default(), None, &Default::default()
+    ).unwrap();
     println!("{:?}", result);
 }
@@ -64,6 +64,7 @@ result=E.normal.e //E最大期望伤害
         &weapon,
         &target_function,
         &enemy,
+        &[],
         &Vec::new(),
         &constraint,
         5
@@ -45,3 +45,7 @@ pub use applications::bonus_per_stat::interface_wasm::BonusPerStat;
 pub use applications::dsl::dsl_interface::DSLInterface;
 // calc best artifact set and main stats
 pub use applications::artifact_best_set::wasm_interface::CalcArtifactBestSet;
+// evaluate a rotation of actions
+pub use applications::rotation::interface_wasm::RotationWasm;
+// energy recharge demand from team particles
+pub use applications::energy::interface_wasm::EnergyWasm;
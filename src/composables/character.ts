@@ -57,7 +57,8 @@ export function useCharacter() {
             skill1: characterSkill1.value - 1,
             skill2: characterSkill2.value - 1,
             skill3: characterSkill3.value - 1,
-            params: characterConfig.value
+            params: characterConfig.value,
+            base_skill_levels: true
         }
         return i
     })
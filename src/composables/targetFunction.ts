@@ -5,7 +5,7 @@ import {type Ref} from "vue"
 import type {CharacterName} from "@/types/character"
 import {useI18n} from "@/i18n/i18n";
 
-export function useTargetFunction(characterName: Ref<CharacterName>) {
+export function useTargetFunction(characterName: Ref<CharacterName>, characterSkillConfig?: Ref<any>) {
     const targetFunctionName = ref<TargetFunctionName>("AmberDefault")
     const targetFunctionConfig = ref<any>("NoConfig")
     const targetFunctionUseDSL = ref(false)
@@ -33,9 +33,19 @@ export function useTargetFunction(characterName: Ref<CharacterName>) {
 
     const targetFunctionInterface = computed(() => {
         const use_dsl = targetFunctionUseDSL.value
+        let params = targetFunctionConfig.value
+        // the percentile target uses the skill config chosen for the damage panel
+        if (characterSkillConfig && targetFunctionName.value === "DamagePercentile" && params.DamagePercentile) {
+            params = {
+                DamagePercentile: {
+                    ...params.DamagePercentile,
+                    skill_config: characterSkillConfig.value
+                }
+            }
+        }
         return {
             name: targetFunctionName.value,
-            params: targetFunctionConfig.value,
+            params,
             use_dsl,
             dsl_source: use_dsl ? targetFunctionDSLSource.value : ""
         }
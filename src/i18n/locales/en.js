@@ -278,6 +278,8 @@ export default {
         bloom: "Bloom",
         hyperbloom: "HyperBloom",
         burgeon: "Burgeon",
+        lunarCharged: "Lunar-Charged",
+        lunarBloom: "Lunar-Bloom",
     },
     res: {
         Pyro: "Pyro Res",
@@ -280,6 +280,8 @@ export default {
         bloom: "绽放",
         hyperbloom: "超绽放",
         burgeon: "烈绽放",
+        lunarCharged: "月感电",
+        lunarBloom: "月绽放",
     },
     res: {
         Pyro: "火抗",
@@ -6,7 +6,9 @@ export interface ICharacter {
     skill1: number,
     skill2: number,
     skill3: number,
-    params: any
+    params: any,
+    // false or missing for levels including the constellation bonus
+    base_skill_levels?: boolean
 }
 
 export type CharacterName = string
\ No newline at end of file
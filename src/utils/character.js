@@ -51,3 +51,29 @@ export function upgradeCharacterConfig(name, oldConfig) {
         [name]: newConfig
     }
 }
+
+// configs saved before mona added the constellation talent bonus itself hold levels including it
+export function upgradeCharacterSkill(character) {
+    if (character.base_skill_levels) {
+        return character
+    }
+
+    let temp = Object.assign({}, character, { base_skill_levels: true })
+    const data = characterData[character.name]
+    if (!data) {
+        return temp
+    }
+
+    let bonus = [0, 0, 0]
+    if ((character.constellation ?? 0) >= 3) {
+        bonus[data.c3Talent] += 3
+    }
+    if ((character.constellation ?? 0) >= 5) {
+        bonus[data.c5Talent] += 3
+    }
+    temp.skill1 = Math.max(0, character.skill1 - bonus[0])
+    temp.skill2 = Math.max(0, character.skill2 - bonus[1])
+    temp.skill3 = Math.max(0, character.skill3 - bonus[2])
+
+    return temp
+}
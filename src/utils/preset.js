@@ -1,5 +1,5 @@
 import { deepCopy } from "@util/common"
-import { upgradeCharacterConfig } from "@util/character"
+import { upgradeCharacterConfig, upgradeCharacterSkill } from "@util/character"
 import { upgradeWeaponConfig } from "@util/weapon"
 import { upgradeTargetFunctionConfig } from "@util/targetFunction"
 import { upgradeArtifactConfig } from "@util/artifacts"
@@ -17,6 +17,7 @@ export function upgradePresetItem(item) {
     let temp = deepCopy(item)
 
     const characterName = item.character.name
+    temp.character = upgradeCharacterSkill(temp.character)
     temp.character.params = upgradeCharacterConfig(characterName, temp.character.params)
 
     const weaponName = item.weapon.name